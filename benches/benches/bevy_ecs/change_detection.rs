@@ -3,7 +3,7 @@ use core::hint::black_box;
 use bevy_ecs::{
     component::{Component, Mutable},
     entity::Entity,
-    prelude::{Added, Changed, EntityWorldMut, QueryState},
+    prelude::{Added, Changed, EntityWorldMut, QueryState, With},
     query::QueryFilter,
     world::World,
 };
@@ -17,7 +17,8 @@ criterion_group!(
     all_changed_detection,
     few_changed_detection,
     none_changed_detection,
-    multiple_archetype_none_changed_detection
+    multiple_archetype_none_changed_detection,
+    mostly_static_changed_detection
 );
 
 macro_rules! modify {
@@ -376,3 +377,62 @@ fn multiple_archetype_none_changed_detection(criterion: &mut Criterion) {
         }
     }
 }
+
+fn mostly_static_changed_detection_generic<
+    T: Component<Mutability = Mutable> + Default + BenchModify,
+>(
+    group: &mut BenchGroup,
+    entity_count: u32,
+) {
+    // 64 archetypes, only the last of which has all of `Data<0>` to `Data<5>`.
+    let archetype_count = 64;
+    group.bench_function(
+        format!(
+            "{}_archetypes_{}_entities_{}",
+            archetype_count,
+            entity_count,
+            core::any::type_name::<T>()
+        ),
+        |bencher| {
+            bencher.iter_batched_ref(
+                || {
+                    let mut world = World::new();
+                    add_archetypes_entities::<T>(&mut world, archetype_count, entity_count);
+                    world.clear_trackers();
+                    let mut query = world.query_filtered::<&mut T, (
+                        With<Data<0>>,
+                        With<Data<1>>,
+                        With<Data<2>>,
+                        With<Data<3>>,
+                        With<Data<4>>,
+                        With<Data<5>>,
+                    )>();
+                    for mut component in query.iter_mut(&mut world) {
+                        black_box(component.bench_modify());
+                    }
+                    let query = generic_filter_query::<Changed<T>>(&mut world);
+                    (world, query)
+                },
+                |(world, query)| {
+                    let mut count = 0;
+                    for entity in query.iter(world) {
+                        black_box(entity);
+                        count += 1;
+                    }
+                    assert_eq!(entity_count, count);
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        },
+    );
+}
+
+fn mostly_static_changed_detection(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("mostly_static_changed_detection");
+    group.warm_up_time(core::time::Duration::from_millis(800));
+    group.measurement_time(core::time::Duration::from_secs(8));
+    for entity_count in [100, 1000, 8000] {
+        mostly_static_changed_detection_generic::<Table>(&mut group, entity_count);
+        mostly_static_changed_detection_generic::<Sparse>(&mut group, entity_count);
+    }
+}
@@ -1,19 +1,24 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    plugin::{plugin_type_id, sort_plugins},
+    First, Last, Main, MainSchedulePlugin, PendingPlugin, PlaceholderPlugin, Plugin, Plugins,
+    PluginsState, SubApp, SubApps,
 };
 use alloc::{
     boxed::Box,
+    format,
     string::{String, ToString},
     vec::Vec,
 };
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
-    component::RequiredComponentsError,
+    component::{Mutable, RequiredComponentsError},
+    derived::{derived_component, DerivedInputs},
     error::{DefaultErrorHandler, ErrorHandler},
-    event::{event_update_system, EventCursor},
+    event::{event_update_system, EventCursor, EventRetention},
     intern::Interned,
+    name::{update_name_path_index, NamePathIndex},
     prelude::*,
+    query::QueryItem,
     schedule::{InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
     system::{IntoObserverSystem, ScheduleSystem, SystemId, SystemInput},
 };
@@ -48,6 +53,18 @@ pub type InternedAppLabel = Interned<dyn AppLabel>;
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
     DuplicatePlugin { plugin_name: String },
+    #[error("plugin {plugin_name:?} requires plugin {dependency:?}, which has not been added; add it before or together with {plugin_name:?}")]
+    MissingDependency {
+        plugin_name: String,
+        dependency: String,
+    },
+    #[error(
+        "plugin {plugin_name:?} conflicts with plugin {conflict:?}, which has already been added"
+    )]
+    ConflictingPlugin {
+        plugin_name: String,
+        conflict: String,
+    },
 }
 
 /// [`App`] is the primary API for writing user applications. It automates the setup of a
@@ -310,6 +327,48 @@ impl App {
         self
     }
 
+    /// Adds the systems that keep the [derived component](bevy_ecs::derived) `T` up to date to the
+    /// given schedule, computing it from the input components `I` with `compute`.
+    ///
+    /// The systems are part of [`DerivedComponentSystems`](bevy_ecs::derived::DerivedComponentSystems),
+    /// which can be used to order them relative to other systems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #[derive(Component)]
+    /// struct Stats {
+    ///     speed: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Buffs {
+    ///     speed_multiplier: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct EffectiveSpeed(f32);
+    ///
+    /// app.add_derived_component::<EffectiveSpeed, (Stats, Buffs)>(Update, |(stats, buffs)| {
+    ///     EffectiveSpeed(stats.speed * buffs.speed_multiplier)
+    /// });
+    /// ```
+    pub fn add_derived_component<T, I>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        compute: impl Fn(QueryItem<'_, I::Data>) -> T + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        T: Component<Mutability = Mutable>,
+        I: DerivedInputs,
+    {
+        self.add_systems(schedule, derived_component::<T, I>(compute))
+    }
+
     /// Registers a system and returns a [`SystemId`] so it can later be called by [`World::run_system`].
     ///
     /// It's possible to register the same systems more than once, they'll be stored separately.
@@ -366,6 +425,33 @@ impl App {
         self
     }
 
+    /// Initializes `T` event handling like [`add_event`](Self::add_event),
+    /// keeping events around according to the given [`EventRetention`] policy
+    /// instead of the default double buffering.
+    ///
+    /// If the event was already added, only its retention policy is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{event::EventRetention, prelude::*};
+    /// #
+    /// # #[derive(Event)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// // Keep `MyEvent`s until every reader has seen them.
+    /// app.add_event_with_retention::<MyEvent>(EventRetention::UntilConsumed);
+    /// ```
+    pub fn add_event_with_retention<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.main_mut().add_event_with_retention::<T>(retention);
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -469,6 +555,38 @@ impl App {
             })?;
         }
 
+        let type_id = plugin_type_id(&*plugin);
+        let dependencies = plugin.dependencies();
+        let main = self.main_mut();
+        if let Some(dependency) = dependencies
+            .required()
+            .iter()
+            .find(|dependency| !main.plugin_types.contains(&dependency.type_id()))
+        {
+            Err(AppError::MissingDependency {
+                plugin_name: plugin.name().to_string(),
+                dependency: dependency.type_name().to_string(),
+            })?;
+        }
+        // Check both the conflicts of this plugin and the ones declared by plugins added before it.
+        let conflict = dependencies
+            .conflicts()
+            .iter()
+            .find(|conflict| main.plugin_types.contains(&conflict.type_id()))
+            .map(|conflict| conflict.type_name().to_string())
+            .or_else(|| main.plugin_conflicts.get(&type_id).cloned());
+        if let Some(conflict) = conflict {
+            Err(AppError::ConflictingPlugin {
+                plugin_name: plugin.name().to_string(),
+                conflict,
+            })?;
+        }
+        for conflict in dependencies.conflicts() {
+            main.plugin_conflicts
+                .entry(conflict.type_id())
+                .or_insert_with(|| plugin.name().to_string());
+        }
+
         // Reserve position in the plugin registry. If the plugin adds more plugins,
         // they'll all end up in insertion order.
         let index = self.main().plugin_registry.len();
@@ -489,6 +607,7 @@ impl App {
         self.main_mut()
             .plugin_names
             .insert(plugin.name().to_string());
+        self.main_mut().plugin_types.insert(type_id);
         self.main_mut().plugin_build_depth -= 1;
 
         #[cfg(feature = "std")]
@@ -567,9 +686,14 @@ impl App {
     ///     .add_plugins((MinimalPlugins, LogPlugin));
     /// ```
     ///
+    /// Plugins are built in the order they're listed in, except that each plugin is built after
+    /// the plugins it [depends on](Plugin::dependencies) that are added in the same call.
+    ///
     /// # Panics
     ///
-    /// Panics if one of the plugins had already been added to the application.
+    /// Panics if one of the plugins had already been added to the application, if a plugin is
+    /// missing a required dependency or conflicts with another plugin, or if the dependencies of
+    /// the plugins are cyclic.
     ///
     /// [`PluginGroup`]:super::PluginGroup
     #[track_caller]
@@ -582,10 +706,38 @@ impl App {
                 "Plugins cannot be added after App::cleanup() or App::finish() has been called."
             );
         }
-        plugins.add_to_app(self);
+        let mut pending = Vec::new();
+        plugins.collect(&mut pending);
+        self.add_pending_plugins(pending);
         self
     }
 
+    /// Builds `plugins` once they're sorted by their dependencies.
+    #[track_caller]
+    pub(crate) fn add_pending_plugins(&mut self, mut plugins: Vec<PendingPlugin>) {
+        if let Err(cycle) = sort_plugins(&mut plugins) {
+            panic!(
+                "Error adding plugins: cyclic dependencies between {}",
+                cycle.join(", ")
+            );
+        }
+        for PendingPlugin { plugin, group_name } in plugins {
+            let plugin_name = plugin.name().to_string();
+            let Err(error) = self.add_boxed_plugin(plugin) else {
+                continue;
+            };
+            let in_group = group_name
+                .map(|group_name| format!(" in group {group_name}"))
+                .unwrap_or_default();
+            match error {
+                AppError::DuplicatePlugin { .. } => panic!(
+                    "Error adding plugin {plugin_name}{in_group}: plugin was already added in application"
+                ),
+                error => panic!("Error adding plugin {plugin_name}{in_group}: {error}"),
+            }
+        }
+    }
+
     /// Registers the type `T` in the [`AppTypeRegistry`] resource,
     /// adding reflect data as specified in the [`Reflect`](bevy_reflect::Reflect) derive:
     /// ```ignore (No serde "derive" feature)
@@ -1047,6 +1199,21 @@ impl App {
         self.world_mut().register_disabling_component::<C>();
     }
 
+    /// Starts indexing the named entities of the main [`World`] by their parent and [`Name`].
+    ///
+    /// See [`World::register_name_path_index`]. This also adds [`update_name_path_index`] to the
+    /// [`Last`] schedule, so names changed in place are picked up by the end of every update.
+    /// Calling this again does nothing.
+    ///
+    /// [`Name`]: bevy_ecs::name::Name
+    pub fn register_name_path_index(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<NamePathIndex>() {
+            self.world_mut().register_name_path_index();
+            self.add_systems(Last, update_name_path_index);
+        }
+        self
+    }
+
     /// Returns a reference to the main [`SubApp`]'s [`World`]. This is the same as calling
     /// [`app.main().world()`].
     ///
@@ -1132,7 +1299,8 @@ impl App {
         self.sub_apps.sub_apps.remove(&label.intern())
     }
 
-    /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
+    /// Extract data from the main world into the [`SubApp`] with the given label, perform an update
+    /// and write data back to the main world if it exists.
     pub fn update_sub_app_by_label(&mut self, label: impl AppLabel) {
         self.sub_apps.update_subapp_by_label(label);
     }
@@ -1475,6 +1643,7 @@ impl Termination for AppExit {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use core::marker::PhantomData;
     use std::sync::Mutex;
 
@@ -1483,6 +1652,7 @@ mod tests {
         component::Component,
         entity::Entity,
         event::{Event, EventWriter, Events},
+        name::Name,
         query::With,
         removal_detection::RemovedComponents,
         resource::Resource,
@@ -1491,7 +1661,7 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Plugin, PluginDependencies, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1546,6 +1716,119 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    #[derive(Resource)]
+    struct BuiltBy(&'static str);
+
+    struct RequiresA;
+    impl Plugin for RequiresA {
+        fn build(&self, app: &mut App) {
+            assert!(app.is_plugin_added::<PluginA>());
+            app.insert_resource(BuiltBy("RequiresA"));
+        }
+        fn dependencies(&self) -> PluginDependencies {
+            PluginDependencies::new()
+                .requires::<PluginA>()
+                .optional::<PluginB>()
+        }
+    }
+
+    struct ConflictsWithB;
+    impl Plugin for ConflictsWithB {
+        fn build(&self, _app: &mut App) {}
+        fn dependencies(&self) -> PluginDependencies {
+            PluginDependencies::new().conflicts_with::<PluginB>()
+        }
+    }
+
+    struct Cyclic<const N: usize>;
+    impl<const N: usize> Plugin for Cyclic<N> {
+        fn build(&self, _app: &mut App) {}
+        fn dependencies(&self) -> PluginDependencies {
+            if N == 0 {
+                PluginDependencies::new().requires::<Cyclic<1>>()
+            } else {
+                PluginDependencies::new().optional::<Cyclic<0>>()
+            }
+        }
+    }
+
+    #[test]
+    fn plugins_are_built_after_dependencies() {
+        let mut app = App::new();
+        app.add_plugins((RequiresA, PluginB, PluginA));
+        let names: Vec<_> = app
+            .main()
+            .plugin_registry
+            .iter()
+            .map(|plugin| plugin.name())
+            .filter(|name| name.starts_with("bevy_app::app::tests"))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                core::any::type_name::<PluginB>(),
+                core::any::type_name::<PluginA>(),
+                core::any::type_name::<RequiresA>(),
+            ]
+        );
+
+        // Dependencies added by an earlier call are satisfied too.
+        let mut app = App::new();
+        app.add_plugins(PluginA).add_plugins(RequiresA);
+        assert_eq!(app.world().resource::<BuiltBy>().0, "RequiresA");
+    }
+
+    #[test]
+    fn plugin_dependencies_ignore_names() {
+        struct Renamed;
+        impl Plugin for Renamed {
+            fn build(&self, app: &mut App) {
+                app.insert_resource(BuiltBy("Renamed"));
+            }
+            fn name(&self) -> &str {
+                "renamed"
+            }
+        }
+
+        struct RequiresRenamed;
+        impl Plugin for RequiresRenamed {
+            fn build(&self, app: &mut App) {
+                assert_eq!(app.world().resource::<BuiltBy>().0, "Renamed");
+            }
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().requires::<Renamed>()
+            }
+        }
+
+        App::new().add_plugins((RequiresRenamed, Renamed));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "requires plugin \"bevy_app::app::tests::PluginA\", which has not been added"
+    )]
+    fn missing_plugin_dependency() {
+        App::new().add_plugins((RequiresA, PluginB));
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicts with plugin \"bevy_app::app::tests::PluginB\"")]
+    fn conflicting_plugin_added_before() {
+        App::new().add_plugins((PluginB, ConflictsWithB));
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicts with plugin \"bevy_app::app::tests::ConflictsWithB\"")]
+    fn conflicting_plugin_added_after() {
+        App::new().add_plugins(ConflictsWithB).add_plugins(PluginB);
+    }
+
+    #[test]
+    #[should_panic(expected = "cyclic dependencies")]
+    fn cyclic_plugin_dependencies() {
+        App::new().add_plugins((Cyclic::<0>, Cyclic::<1>));
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
@@ -1882,4 +2165,21 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn name_path_index_picks_up_renames() {
+        let mut app = App::new();
+        app.register_name_path_index();
+        let entity = app.world_mut().spawn(Name::new("Before")).id();
+
+        app.world_mut()
+            .get_mut::<Name>(entity)
+            .unwrap()
+            .set("After");
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(world.resolve_name_path("After"), Ok(entity));
+        assert!(world.resolve_name_path("Before").is_err());
+    }
 }
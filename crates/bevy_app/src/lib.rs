@@ -26,6 +26,8 @@ extern crate self as bevy_app;
 mod app;
 mod main_schedule;
 mod panic_handler;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+mod parallel_sub_app;
 mod plugin;
 mod plugin_group;
 mod propagate;
@@ -41,6 +43,8 @@ pub mod hotpatch;
 pub use app::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use parallel_sub_app::*;
 pub use plugin::*;
 pub use plugin_group::*;
 pub use propagate::*;
@@ -36,6 +36,11 @@ use bevy_ecs::{
 /// * [`PostUpdate`]
 /// * [`Last`]
 ///
+/// After each of these schedules, [`World::trigger_mutations`] is called so that
+/// [`OnMutate`](bevy_ecs::world::OnMutate) observers see the mutations made by that schedule,
+/// and [`World::apply_async_requests`] is called to apply the requests made through
+/// [`AsyncWorld`](bevy_ecs::world::AsyncWorld).
+///
 /// # Rendering
 ///
 /// Note rendering is not executed in the main schedule by default.
@@ -285,7 +290,10 @@ impl Main {
         if !*run_at_least_once {
             world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
                 for &label in &order.startup_labels {
-                    let _ = world.try_run_schedule(label);
+                    if world.try_run_schedule(label).is_ok() {
+                        world.apply_async_requests();
+                    }
+                    world.trigger_mutations();
                 }
             });
             *run_at_least_once = true;
@@ -293,7 +301,10 @@ impl Main {
 
         world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
             for &label in &order.labels {
-                let _ = world.try_run_schedule(label);
+                if world.try_run_schedule(label).is_ok() {
+                    world.apply_async_requests();
+                }
+                world.trigger_mutations();
             }
         });
     }
@@ -386,7 +397,10 @@ impl FixedMain {
     pub fn run_fixed_main(world: &mut World) {
         world.resource_scope(|world, order: Mut<FixedMainScheduleOrder>| {
             for &label in &order.labels {
-                let _ = world.try_run_schedule(label);
+                if world.try_run_schedule(label).is_ok() {
+                    world.apply_async_requests();
+                }
+                world.trigger_mutations();
             }
         });
     }
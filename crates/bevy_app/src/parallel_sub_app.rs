@@ -0,0 +1,383 @@
+use alloc::{format, sync::Arc};
+use bevy_ecs::{
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    world::World,
+};
+use bevy_platform::time::Instant;
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use std::{
+    sync::{Condvar, Mutex, PoisonError},
+    thread::{self, JoinHandle},
+};
+
+use crate::{App, AppExit, AppLabel, InternedAppLabel, Plugin, PluginsState, SubApp};
+
+/// How often a [`SubApp`] run by a [`ParallelSubAppPlugin`] updates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubAppRate {
+    /// Update once per frame of the main app, pipelined like rendering: the sub-app updates with
+    /// the data of frame N while the main app runs frame N + 1.
+    #[default]
+    EveryFrame,
+    /// Update at a fixed rate, independently of the frames of the main app.
+    ///
+    /// Ticks that are missed because an update took too long are skipped rather than run back to
+    /// back.
+    Fixed(Duration),
+}
+
+/// Runs a [`SubApp`] on its own thread, in parallel with the main app.
+///
+/// The sub-app with the given label is moved to a dedicated thread when the plugins of the app are
+/// [cleaned up](Plugin::cleanup), after which [`App::get_sub_app`] no longer returns it. From then on,
+/// it updates at its [`SubAppRate`], and is synchronized with the main world once per frame of the
+/// main app: its [`extract`](SubApp::extract) method copies data from the main world, then its
+/// [`write_back`](SubApp::write_back) method copies its results back. The sub-app doesn't update
+/// while it is synchronized, so the sync point waits for an ongoing update to finish.
+///
+/// By default, the sync point is right after the main schedule, before the other sub-apps are
+/// extracted. Use [`sync_in`](Self::sync_in) to synchronize in a schedule of the main app instead.
+///
+/// ```
+/// # use bevy_app::{App, AppLabel, ParallelSubAppPlugin, SubApp, SubAppRate, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use core::time::Duration;
+/// #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+/// struct SimulationApp;
+///
+/// #[derive(Resource, Default, Clone)]
+/// struct Orders(Vec<u32>);
+///
+/// #[derive(Resource, Default, Clone)]
+/// struct Score(u32);
+///
+/// let mut sub_app = SubApp::new();
+/// sub_app.update_schedule = Some(Update.intern());
+/// sub_app
+///     .init_resource::<Orders>()
+///     .init_resource::<Score>()
+///     .add_systems(Update, |orders: Res<Orders>, mut score: ResMut<Score>| {
+///         score.0 += orders.0.len() as u32;
+///     })
+///     .set_extract(|main_world, sub_world| {
+///         sub_world.insert_resource(main_world.resource::<Orders>().clone());
+///     })
+///     .set_write_back(|sub_world, main_world| {
+///         main_world.insert_resource(sub_world.resource::<Score>().clone());
+///     });
+///
+/// let mut app = App::new();
+/// app.init_resource::<Orders>()
+///     .init_resource::<Score>()
+///     .insert_sub_app(SimulationApp, sub_app);
+/// app.add_plugins(ParallelSubAppPlugin::new(
+///     SimulationApp,
+///     SubAppRate::Fixed(Duration::from_secs_f64(1.0 / 30.0)),
+/// ));
+/// ```
+///
+/// The thread is stopped, and the sub-app dropped, when the main app is dropped. If the sub-app
+/// panics, the next sync point sends [`AppExit::error`].
+///
+/// Resources that are not [`Send`] must not be used in the sub-app, as it's updated on another
+/// thread than the one it was built on.
+///
+/// This plugin is not available on `wasm32`, which can't spawn threads.
+pub struct ParallelSubAppPlugin {
+    label: InternedAppLabel,
+    rate: SubAppRate,
+    sync_schedule: Option<InternedScheduleLabel>,
+}
+
+impl ParallelSubAppPlugin {
+    /// Creates a plugin running the sub-app with the given `label` in parallel, at `rate`.
+    pub fn new(label: impl AppLabel, rate: SubAppRate) -> Self {
+        Self {
+            label: label.intern(),
+            rate,
+            sync_schedule: None,
+        }
+    }
+
+    /// Synchronizes the sub-app with the main world in `schedule` of the main app, rather than
+    /// after the main schedule.
+    pub fn sync_in(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.sync_schedule = Some(schedule.intern());
+        self
+    }
+}
+
+impl Plugin for ParallelSubAppPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    fn cleanup(&self, app: &mut App) {
+        let Some(mut sub_app) = app.remove_sub_app(self.label) else {
+            log::warn!(
+                "ParallelSubAppPlugin did nothing, as there is no sub-app {:?}",
+                self.label
+            );
+            return;
+        };
+        // The sub-apps are cleaned up after the plugins of the main app, so it must be done here.
+        if sub_app.plugins_state() == PluginsState::Finished {
+            sub_app.cleanup();
+        }
+
+        let parallel = Arc::new(ParallelSubApp::spawn(self.label, sub_app, self.rate));
+        let schedule = self.sync_schedule.unwrap_or(ParallelSubAppSync.intern());
+        app.add_systems(schedule, move |world: &mut World| parallel.sync(world));
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// The schedule synchronizing the parallel sub-apps without a [`sync_in`] schedule, which
+/// [`SubApps::update`](crate::SubApps::update) runs after the main schedule.
+///
+/// [`sync_in`]: ParallelSubAppPlugin::sync_in
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, ScheduleLabel)]
+pub(crate) struct ParallelSubAppSync;
+
+/// A [`SubApp`] running on its own thread.
+struct ParallelSubApp {
+    label: InternedAppLabel,
+    rate: SubAppRate,
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    /// Notified when an update is requested, when it's done, when a sync is done, and when the
+    /// thread must stop.
+    condvar: Condvar,
+    /// Whether the main app is waiting for the lock, to sync or to stop the thread, so that a sub-app
+    /// updating at a fixed rate lets it take its turn instead of updating again.
+    main_waiting: AtomicBool,
+}
+
+struct State {
+    sub_app: SubApp,
+    /// Whether the main app is waiting for the sub-app to update, when it updates every frame.
+    update_requested: bool,
+    /// The number of syncs so far, which the sub-app waits on to change when it yields to a sync.
+    syncs: u64,
+    stopped: bool,
+}
+
+impl ParallelSubApp {
+    fn spawn(label: InternedAppLabel, sub_app: SubApp, rate: SubAppRate) -> Self {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                sub_app,
+                update_requested: false,
+                syncs: 0,
+                stopped: false,
+            }),
+            condvar: Condvar::new(),
+            main_waiting: AtomicBool::new(false),
+        });
+
+        let thread_shared = shared.clone();
+        let thread = thread::Builder::new()
+            .name(format!("{label:?}"))
+            .spawn(move || {
+                #[cfg(feature = "trace")]
+                let _span = tracing::info_span!("sub app thread", name = ?label).entered();
+
+                // Returns once stopped, or if the sub-app panicked and poisoned the lock.
+                let _ = match rate {
+                    SubAppRate::EveryFrame => thread_shared.run_every_frame(),
+                    SubAppRate::Fixed(period) => thread_shared.run_fixed(period),
+                };
+            })
+            .expect("failed to spawn the thread of a parallel sub-app");
+
+        Self {
+            label,
+            rate,
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// Extracts data from `world` into the sub-app, and writes its results back.
+    fn sync(&self, world: &mut World) {
+        self.shared.main_waiting.store(true, Ordering::Relaxed);
+        let state = self.shared.state.lock().and_then(|state| {
+            self.shared
+                .condvar
+                .wait_while(state, |s| s.update_requested)
+        });
+        let Ok(mut state) = state else {
+            log::error!("sub-app {:?} panicked", self.label);
+            world.send_event(AppExit::error());
+            return;
+        };
+
+        state.sub_app.extract(world);
+        state.sub_app.write_back(world);
+        state.syncs = state.syncs.wrapping_add(1);
+        self.shared.main_waiting.store(false, Ordering::Relaxed);
+        if self.rate == SubAppRate::EveryFrame {
+            state.update_requested = true;
+        }
+        self.shared.condvar.notify_all();
+    }
+}
+
+impl Drop for ParallelSubApp {
+    fn drop(&mut self) {
+        self.shared.main_waiting.store(true, Ordering::Relaxed);
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .stopped = true;
+        self.shared.condvar.notify_all();
+        if let Some(thread) = self.thread.take() {
+            // Wait for the thread to let go of the sub-app, so it's dropped on this thread.
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Shared {
+    fn run_every_frame(&self) -> Option<()> {
+        let mut state = self.state.lock().ok()?;
+        loop {
+            state = self
+                .condvar
+                .wait_while(state, |s| !s.update_requested && !s.stopped)
+                .ok()?;
+            if state.stopped {
+                return Some(());
+            }
+            state.sub_app.update();
+            state.update_requested = false;
+            self.condvar.notify_all();
+        }
+    }
+
+    fn run_fixed(&self, period: Duration) -> Option<()> {
+        let mut next_tick = Instant::now();
+        let mut state = self.state.lock().ok()?;
+        loop {
+            // The lock is released while waiting, which is when the main app can sync.
+            loop {
+                if state.stopped {
+                    return Some(());
+                }
+                let now = Instant::now();
+                if now >= next_tick {
+                    break;
+                }
+                state = self.condvar.wait_timeout(state, next_tick - now).ok()?.0;
+            }
+            state.sub_app.update();
+            next_tick = (next_tick + period).max(Instant::now());
+
+            // The lock isn't fair, so the main app may not get it before the next update.
+            if self.main_waiting.load(Ordering::Relaxed) {
+                let syncs = state.syncs;
+                state = self
+                    .condvar
+                    .wait_while(state, |s| s.syncs == syncs && !s.stopped)
+                    .ok()?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{First, Update};
+    use bevy_ecs::prelude::*;
+
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+    struct ParallelApp;
+
+    #[derive(Resource, Default)]
+    struct Input(u32);
+
+    #[derive(Resource, Default)]
+    struct Output(u32);
+
+    fn app(rate: SubAppRate, sync_schedule: Option<InternedScheduleLabel>) -> App {
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Update.intern());
+        sub_app
+            .init_resource::<Input>()
+            .init_resource::<Output>()
+            .add_systems(Update, |input: Res<Input>, mut output: ResMut<Output>| {
+                output.0 += input.0;
+            })
+            .set_extract(|main_world, sub_world| {
+                sub_world.resource_mut::<Input>().0 = main_world.resource::<Input>().0;
+            })
+            .set_write_back(|sub_world, main_world| {
+                main_world.resource_mut::<Output>().0 = sub_world.resource::<Output>().0;
+            });
+
+        let mut plugin = ParallelSubAppPlugin::new(ParallelApp, rate);
+        plugin.sync_schedule = sync_schedule;
+        let mut app = App::new();
+        app.insert_resource(Input(1))
+            .init_resource::<Output>()
+            .insert_sub_app(ParallelApp, sub_app);
+        app.add_plugins(plugin);
+        app.finish();
+        app.cleanup();
+        app
+    }
+
+    #[test]
+    fn pipelined_sub_app() {
+        let mut app = app(SubAppRate::EveryFrame, None);
+        assert!(app.get_sub_app(ParallelApp).is_none());
+        // Only the main app is left.
+        assert_eq!(app.sub_apps.iter().count(), 1);
+
+        for frame in 0..5 {
+            app.world_mut().resource_mut::<Input>().0 = 1 << frame;
+            app.update();
+            // The sub-app updates after the sync point, so results lag a frame behind.
+            assert_eq!(app.world().resource::<Output>().0, (1 << frame) - 1);
+        }
+    }
+
+    #[test]
+    fn fixed_rate_sub_app() {
+        let mut app = app(
+            SubAppRate::Fixed(Duration::from_millis(1)),
+            Some(First.intern()),
+        );
+
+        let start = Instant::now();
+        while app.world().resource::<Output>().0 < 3 {
+            assert!(start.elapsed() < Duration::from_secs(10));
+            thread::sleep(Duration::from_millis(1));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn fixed_rate_sub_app_yields_to_sync() {
+        let mut app = app(SubAppRate::Fixed(Duration::ZERO), Some(First.intern()));
+
+        // The sub-app updates back to back without releasing the lock in between, but the sync
+        // points still get their turn.
+        let start = Instant::now();
+        while app.world().resource::<Output>().0 < 100 {
+            assert!(start.elapsed() < Duration::from_secs(10));
+            app.update();
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::App;
-use core::any::Any;
+use alloc::{string::String, vec::Vec};
+use core::any::{Any, TypeId};
 use downcast_rs::{impl_downcast, Downcast};
 
 /// A collection of Bevy app logic and configuration.
@@ -16,7 +17,8 @@ use downcast_rs::{impl_downcast, Downcast};
 /// ## Lifecycle of a plugin
 ///
 /// When adding a plugin to an [`App`]:
-/// * the app calls [`Plugin::build`] immediately, and register the plugin
+/// * the app calls [`Plugin::build`] immediately, and register the plugin, after building the
+///   plugins it [depends on](Plugin::dependencies) that are added at the same time
 /// * once the app started, it will wait for all registered [`Plugin::ready`] to return `true`
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
@@ -89,6 +91,45 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Declares the plugins this plugin requires, optionally depends on, or conflicts with.
+    ///
+    /// Dependencies that are added in the same [`App::add_plugins`] call or
+    /// [`PluginGroup`](crate::PluginGroup) as this plugin are built before it, whatever order
+    /// they're listed in. Adding this plugin panics, naming the other plugin, if a required
+    /// dependency hasn't been added by the time it is built, or if a conflicting plugin is added
+    /// either before or after it.
+    ///
+    /// ```
+    /// # use bevy_app::*;
+    /// # struct AssetPlugin;
+    /// # impl Plugin for AssetPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// # struct LogPlugin;
+    /// # impl Plugin for LogPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// struct AudioPlugin;
+    ///
+    /// impl Plugin for AudioPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         // Safe to use resources added by `AssetPlugin` here.
+    ///     }
+    ///
+    ///     fn dependencies(&self) -> PluginDependencies {
+    ///         PluginDependencies::new()
+    ///             .requires::<AssetPlugin>()
+    ///             .optional::<LogPlugin>()
+    ///     }
+    /// }
+    ///
+    /// // `AssetPlugin` is built first.
+    /// App::new().add_plugins((AudioPlugin, AssetPlugin));
+    /// ```
+    fn dependencies(&self) -> PluginDependencies {
+        PluginDependencies::default()
+    }
 }
 
 impl_downcast!(Plugin);
@@ -99,6 +140,144 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// Returns the [`TypeId`] of the concrete type of `plugin`.
+pub(crate) fn plugin_type_id(plugin: &dyn Plugin) -> TypeId {
+    plugin.as_any().type_id()
+}
+
+/// A plugin type listed in [`PluginDependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginDependency {
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+impl PluginDependency {
+    /// Refers to the plugin type `T`.
+    pub fn of<T: Plugin>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: core::any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the [`TypeId`] of the plugin type.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the name of the plugin type.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// The other plugins a [`Plugin`] depends on or conflicts with, as declared by
+/// [`Plugin::dependencies`].
+///
+/// Plugins are identified by their type, so overriding [`Plugin::name`] doesn't affect them.
+#[derive(Debug, Default, Clone)]
+pub struct PluginDependencies {
+    required: Vec<PluginDependency>,
+    optional: Vec<PluginDependency>,
+    conflicts: Vec<PluginDependency>,
+}
+
+impl PluginDependencies {
+    /// Creates a set of dependencies without any plugins.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the plugin `T` to be added, and built before the declaring plugin.
+    pub fn requires<T: Plugin>(mut self) -> Self {
+        self.required.push(PluginDependency::of::<T>());
+        self
+    }
+
+    /// Builds the plugin `T` before the declaring plugin if it is added with it,
+    /// without requiring it to be added at all.
+    pub fn optional<T: Plugin>(mut self) -> Self {
+        self.optional.push(PluginDependency::of::<T>());
+        self
+    }
+
+    /// Forbids the plugin `T` from being added to the same [`App`] as the declaring plugin.
+    pub fn conflicts_with<T: Plugin>(mut self) -> Self {
+        self.conflicts.push(PluginDependency::of::<T>());
+        self
+    }
+
+    /// Returns the required plugins.
+    pub fn required(&self) -> &[PluginDependency] {
+        &self.required
+    }
+
+    /// Returns the optional dependencies.
+    pub fn optional_plugins(&self) -> &[PluginDependency] {
+        &self.optional
+    }
+
+    /// Returns the conflicting plugins.
+    pub fn conflicts(&self) -> &[PluginDependency] {
+        &self.conflicts
+    }
+}
+
+/// Sorts `plugins` so that each plugin comes after the required and optional dependencies it has
+/// among them, keeping the original order wherever dependencies allow.
+///
+/// Returns the names of the plugins in a dependency cycle if there is one.
+pub(crate) fn sort_plugins(plugins: &mut Vec<PendingPlugin>) -> Result<(), Vec<String>> {
+    let names: Vec<&str> = plugins.iter().map(|entry| entry.plugin.name()).collect();
+    let type_ids: Vec<TypeId> = plugins
+        .iter()
+        .map(|entry| plugin_type_id(&*entry.plugin))
+        .collect();
+    // The indices of the plugins each plugin has to be built after.
+    let dependencies: Vec<Vec<usize>> = plugins
+        .iter()
+        .map(|entry| {
+            let dependencies = entry.plugin.dependencies();
+            dependencies
+                .required()
+                .iter()
+                .chain(dependencies.optional_plugins())
+                .flat_map(|dependency| {
+                    type_ids
+                        .iter()
+                        .enumerate()
+                        .filter(move |(_, type_id)| **type_id == dependency.type_id())
+                        .map(|(index, _)| index)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut placed = alloc::vec![false; plugins.len()];
+    let mut order = Vec::with_capacity(plugins.len());
+    while order.len() < plugins.len() {
+        // Take the first plugin whose dependencies have all been placed.
+        let Some(next) = (0..plugins.len()).find(|&index| {
+            !placed[index]
+                && dependencies[index]
+                    .iter()
+                    .all(|&dependency| placed[dependency])
+        }) else {
+            return Err((0..plugins.len())
+                .filter(|&index| !placed[index])
+                .map(|index| names[index].into())
+                .collect());
+        };
+        placed[next] = true;
+        order.push(next);
+    }
+
+    let mut entries: Vec<Option<PendingPlugin>> = plugins.drain(..).map(Some).collect();
+    plugins.extend(order.into_iter().filter_map(|index| entries[index].take()));
+    Ok(())
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
@@ -127,14 +306,24 @@ pub trait Plugins<Marker>: sealed::Plugins<Marker> {}
 
 impl<Marker, T> Plugins<Marker> for T where T: sealed::Plugins<Marker> {}
 
-mod sealed {
-    use alloc::boxed::Box;
+pub(crate) use sealed::PendingPlugin;
+
+pub(crate) mod sealed {
+    use alloc::{boxed::Box, string::String, vec::Vec};
     use variadics_please::all_tuples;
 
-    use crate::{App, AppError, Plugin, PluginGroup};
+    use crate::{Plugin, PluginGroup};
+
+    /// A plugin waiting to be built by [`App::add_plugins`](crate::App::add_plugins), along with
+    /// the name of its group.
+    pub struct PendingPlugin {
+        pub plugin: Box<dyn Plugin>,
+        pub group_name: Option<String>,
+    }
 
     pub trait Plugins<Marker> {
-        fn add_to_app(self, app: &mut App);
+        /// Appends the plugins to `plugins`, in the order they're listed in.
+        fn collect(self, plugins: &mut Vec<PendingPlugin>);
     }
 
     pub struct PluginMarker;
@@ -143,21 +332,18 @@ mod sealed {
 
     impl<P: Plugin> Plugins<PluginMarker> for P {
         #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
-            {
-                panic!(
-                    "Error adding plugin {plugin_name}: : plugin was already added in application"
-                )
-            }
+        fn collect(self, plugins: &mut Vec<PendingPlugin>) {
+            plugins.push(PendingPlugin {
+                plugin: Box::new(self),
+                group_name: None,
+            });
         }
     }
 
     impl<P: PluginGroup> Plugins<PluginGroupMarker> for P {
         #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            self.build().finish(app);
+        fn collect(self, plugins: &mut Vec<PendingPlugin>) {
+            self.build().collect(plugins);
         }
     }
 
@@ -173,11 +359,11 @@ mod sealed {
                     reason = "This is inside a macro, and as such, may not trigger in all cases."
                 )]
                 #[allow(non_snake_case, reason = "`all_tuples!()` generates non-snake-case variable names.")]
-                #[allow(unused_variables, reason = "`app` is unused when implemented for the unit type `()`.")]
+                #[allow(unused_variables, reason = "`plugins` is unused when implemented for the unit type `()`.")]
                 #[track_caller]
-                fn add_to_app(self, app: &mut App) {
+                fn collect(self, plugins: &mut Vec<PendingPlugin>) {
                     let ($($plugins,)*) = self;
-                    $($plugins.add_to_app(app);)*
+                    $($plugins.collect(plugins);)*
                 }
             }
         }
@@ -1,13 +1,13 @@
 use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
-    event::EventRegistry,
+    event::{EventRegistry, EventRetention},
     prelude::*,
     schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
     system::{ScheduleSystem, SystemId, SystemInput},
 };
 use bevy_platform::collections::{HashMap, HashSet};
-use core::fmt::Debug;
+use core::{any::TypeId, fmt::Debug};
 
 #[cfg(feature = "trace")]
 use tracing::info_span;
@@ -66,6 +66,10 @@ pub struct SubApp {
     /// The names of plugins that have been added to this app. (used to track duplicates and
     /// already-registered plugins)
     pub(crate) plugin_names: HashSet<String>,
+    /// The types of plugins that have been added to this app, to check the dependencies of plugins.
+    pub(crate) plugin_types: HashSet<TypeId>,
+    /// The types of plugins that may not be added, and the name of the plugin that conflicts with each.
+    pub(crate) plugin_conflicts: HashMap<TypeId, String>,
     /// Panics if an update is attempted while plugins are building.
     pub(crate) plugin_build_depth: usize,
     pub(crate) plugins_state: PluginsState,
@@ -74,6 +78,9 @@ pub struct SubApp {
     /// A function that gives mutable access to two app worlds. This is primarily
     /// intended for copying data from the main world to secondary worlds.
     extract: Option<ExtractFn>,
+    /// A function that gives mutable access to two app worlds after an update. This is primarily
+    /// intended for copying results from secondary worlds back to the main world.
+    write_back: Option<ExtractFn>,
 }
 
 impl Debug for SubApp {
@@ -90,10 +97,13 @@ impl Default for SubApp {
             world,
             plugin_registry: Vec::default(),
             plugin_names: HashSet::default(),
+            plugin_types: HashSet::default(),
+            plugin_conflicts: HashMap::default(),
             plugin_build_depth: 0,
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
+            write_back: None,
         }
     }
 }
@@ -195,6 +205,33 @@ impl SubApp {
         self.extract.take()
     }
 
+    /// Writes data from the app's world back into `world` using the registered write-back method.
+    ///
+    /// **Note:** There is no default write-back method. Calling `write_back` does nothing if
+    /// [`set_write_back`](Self::set_write_back) has not been called.
+    pub fn write_back(&mut self, world: &mut World) {
+        if let Some(f) = self.write_back.as_mut() {
+            f(&mut self.world, world);
+        }
+    }
+
+    /// Sets the method that will be called by [`write_back`](Self::write_back), after each update.
+    ///
+    /// The first argument is the app `World`, the second argument is the `World` to write data to.
+    pub fn set_write_back<F>(&mut self, write_back: F) -> &mut Self
+    where
+        F: Fn(&mut World, &mut World) + Send + 'static,
+    {
+        self.write_back = Some(Box::new(write_back));
+        self
+    }
+
+    /// Take the function that will be called by [`write_back`](Self::write_back) out of the app,
+    /// if any was set, and replace it with `None`.
+    pub fn take_write_back(&mut self) -> Option<ExtractFn> {
+        self.write_back.take()
+    }
+
     /// See [`App::insert_resource`].
     pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
         self.world.insert_resource(resource);
@@ -347,9 +384,23 @@ impl SubApp {
         self
     }
 
+    /// See [`App::add_event_with_retention`].
+    pub fn add_event_with_retention<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.add_event::<T>();
+        self.world
+            .resource_mut::<Events<T>>()
+            .set_retention(retention);
+        self
+    }
+
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
-        self.run_as_app(|app| plugins.add_to_app(app));
+        let mut pending = Vec::new();
+        plugins.collect(&mut pending);
+        self.run_as_app(|app| app.add_pending_plugins(pending));
         self
     }
 
@@ -481,8 +532,10 @@ pub struct SubApps {
 }
 
 impl SubApps {
-    /// Calls [`update`](SubApp::update) for the main sub-app, and then calls
-    /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest.
+    /// Calls [`update`](SubApp::update) for the main sub-app, synchronizes the sub-apps run by a
+    /// [`ParallelSubAppPlugin`](crate::ParallelSubAppPlugin), and then calls
+    /// [`extract`](SubApp::extract), [`update`](SubApp::update) and
+    /// [`write_back`](SubApp::write_back) for the rest.
     pub fn update(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_update_span = info_span!("update").entered();
@@ -491,11 +544,17 @@ impl SubApps {
             let _bevy_frame_update_span = info_span!("main app").entered();
             self.main.run_default_schedule();
         }
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        let _ = self
+            .main
+            .world
+            .try_run_schedule(crate::parallel_sub_app::ParallelSubAppSync);
         for (_label, sub_app) in self.sub_apps.iter_mut() {
             #[cfg(feature = "trace")]
             let _sub_app_span = info_span!("sub app", name = ?_label).entered();
             sub_app.extract(&mut self.main.world);
             sub_app.update();
+            sub_app.write_back(&mut self.main.world);
         }
 
         self.main.world.clear_trackers();
@@ -511,11 +570,13 @@ impl SubApps {
         core::iter::once(&mut self.main).chain(self.sub_apps.values_mut())
     }
 
-    /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
+    /// Extract data from the main world into the [`SubApp`] with the given label, perform an update
+    /// and write data back to the main world if it exists.
     pub fn update_subapp_by_label(&mut self, label: impl AppLabel) {
         if let Some(sub_app) = self.sub_apps.get_mut(&label.intern()) {
             sub_app.extract(&mut self.main.world);
             sub_app.update();
+            sub_app.write_back(&mut self.main.world);
         }
     }
 }
@@ -19,6 +19,8 @@ pub mod picking_debug;
 
 pub mod states;
 
+pub mod test_harness;
+
 /// Enables developer tools in an [`App`]. This plugin is added automatically with `bevy_dev_tools`
 /// feature.
 ///
@@ -0,0 +1,522 @@
+//! A headless, deterministic [`App`] for testing gameplay logic.
+//!
+//! [`TestHarness`] runs an app without a window or renderer, and advances it one frame at a time
+//! with a fixed frame time, so the same test always sees the same [`Time`](bevy_time::Time) values.
+//! Between frames, tests can inject input and window events, collect the events the app emitted,
+//! and compare components against expected values. Mismatches are reported as a line-by-line diff
+//! of the reflected values.
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::{keyboard::KeyCode, ButtonInput, InputPlugin};
+//! # use bevy_reflect::Reflect;
+//! # use bevy_time::Time;
+//! # use bevy_dev_tools::test_harness::TestHarness;
+//! # use core::time::Duration;
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component)]
+//! struct Position(f32);
+//!
+//! fn walk(keys: Res<ButtonInput<KeyCode>>, time: Res<Time>, mut query: Query<&mut Position>) {
+//!     if keys.pressed(KeyCode::ArrowRight) {
+//!         for mut position in &mut query {
+//!             position.0 += 10.0 * time.delta_secs();
+//!         }
+//!     }
+//! }
+//!
+//! let mut harness = TestHarness::with_frame_time(Duration::from_millis(250));
+//! harness
+//!     .app_mut()
+//!     .add_plugins(InputPlugin)
+//!     .register_type::<Position>()
+//!     .add_systems(Update, walk);
+//! let player = harness.world_mut().spawn(Position(0.0)).id();
+//!
+//! harness.press_key(KeyCode::ArrowRight).advance_frames(4);
+//! harness.assert_component(player, &Position(10.0));
+//! ```
+
+use core::{fmt::Write, time::Duration};
+
+use bevy_app::{prelude::*, PluginsState, ScheduleRunnerPlugin, TaskPoolPlugin};
+use bevy_diagnostic::FrameCountPlugin;
+use bevy_ecs::{
+    event::{EventCursor, Events},
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey},
+    mouse::{MouseButton, MouseButtonInput},
+    ButtonState,
+};
+use bevy_reflect::Reflect;
+use bevy_time::{Real, Time, TimePlugin, TimeUpdateStrategy, Virtual};
+use bevy_window::PrimaryWindow;
+
+/// A headless [`App`] that advances by a fixed frame time.
+///
+/// The app starts with [`TaskPoolPlugin`], [`FrameCountPlugin`], [`TimePlugin`] and a
+/// [`ScheduleRunnerPlugin`] that runs once, and uses [`TimeUpdateStrategy::ManualDuration`] so each
+/// frame, including the first one, advances [`Time`] by exactly the
+/// [frame time](Self::with_frame_time). Add the plugins and
+/// systems under test with [`app_mut`](Self::app_mut), then drive the app with
+/// [`advance_frames`](Self::advance_frames) instead of [`App::run`].
+///
+/// See the [module docs](self) for an example.
+pub struct TestHarness {
+    app: App,
+    frame_time: Duration,
+    collectors: Vec<fn(&mut World)>,
+}
+
+impl Default for TestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestHarness {
+    /// The frame time used by [`TestHarness::new`], a sixtieth of a second.
+    pub const DEFAULT_FRAME_TIME: Duration = Duration::from_nanos(16_666_667);
+
+    /// Creates a harness advancing by [`DEFAULT_FRAME_TIME`](Self::DEFAULT_FRAME_TIME) each frame.
+    pub fn new() -> Self {
+        Self::with_frame_time(Self::DEFAULT_FRAME_TIME)
+    }
+
+    /// Creates a harness advancing by `frame_time` each frame.
+    ///
+    /// The maximum delta of [`Time<Virtual>`] is raised to `frame_time` if needed, so frames are
+    /// never clamped.
+    pub fn with_frame_time(frame_time: Duration) -> Self {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            FrameCountPlugin,
+            TimePlugin,
+            ScheduleRunnerPlugin::run_once(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+
+        let world = app.world_mut();
+        // Start the clock now, so the first frame advances time like every other one instead of
+        // having a zero delta.
+        world
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(Duration::ZERO);
+        let mut virtual_time = world.resource_mut::<Time<Virtual>>();
+        if virtual_time.max_delta() < frame_time {
+            virtual_time.set_max_delta(frame_time);
+        }
+
+        Self {
+            app,
+            frame_time,
+            collectors: Vec::new(),
+        }
+    }
+
+    /// Returns the time each frame advances by.
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// Returns the [`App`] under test.
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the [`App`] under test, to add plugins and systems.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns the [`World`] of the app.
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Returns the [`World`] of the app.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Runs `frames` updates of the app.
+    ///
+    /// Before the first update, this finishes building the plugins, as [`App::run`] would.
+    pub fn advance_frames(&mut self, frames: u32) -> &mut Self {
+        if self.app.plugins_state() != PluginsState::Cleaned {
+            while self.app.plugins_state() == PluginsState::Adding {
+                bevy_tasks::tick_global_task_pools_on_main_thread();
+            }
+            self.app.finish();
+            self.app.cleanup();
+        }
+
+        for _ in 0..frames {
+            self.app.update();
+            self.collect();
+        }
+        self
+    }
+
+    /// Runs as many updates as it takes for at least `duration` to pass.
+    pub fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        let frames = duration
+            .as_nanos()
+            .div_ceil(self.frame_time.as_nanos().max(1));
+        self.advance_frames(frames.try_into().unwrap_or(u32::MAX))
+    }
+
+    /// Sends `event` to the app, to be read during the next frame.
+    pub fn send_event<E: Event>(&mut self, event: E) -> &mut Self {
+        self.world_mut().send_event(event);
+        self
+    }
+
+    /// Returns the entity of the [`PrimaryWindow`], if any, to send window events to.
+    pub fn primary_window(&mut self) -> Option<Entity> {
+        self.world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .iter(self.world())
+            .next()
+    }
+
+    /// Sends a [`KeyboardInput`] pressing `key_code` in the primary window.
+    ///
+    /// The key stays pressed in [`ButtonInput<KeyCode>`](bevy_input::ButtonInput) until it is
+    /// [released](Self::release_key). This requires the [`InputPlugin`](bevy_input::InputPlugin).
+    pub fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.send_key(key_code, ButtonState::Pressed)
+    }
+
+    /// Sends a [`KeyboardInput`] releasing `key_code` in the primary window.
+    pub fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.send_key(key_code, ButtonState::Released)
+    }
+
+    /// Sends a [`MouseButtonInput`] pressing `button` in the primary window.
+    pub fn press_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.send_mouse_button(button, ButtonState::Pressed)
+    }
+
+    /// Sends a [`MouseButtonInput`] releasing `button` in the primary window.
+    pub fn release_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.send_mouse_button(button, ButtonState::Released)
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) -> &mut Self {
+        let window = self.primary_window().unwrap_or(Entity::PLACEHOLDER);
+        self.send_event(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state,
+            text: None,
+            repeat: false,
+            window,
+        })
+    }
+
+    fn send_mouse_button(&mut self, button: MouseButton, state: ButtonState) -> &mut Self {
+        let window = self.primary_window().unwrap_or(Entity::PLACEHOLDER);
+        self.send_event(MouseButtonInput {
+            button,
+            state,
+            window,
+        })
+    }
+
+    /// Starts collecting every event of type `E` sent from now on, including the ones sent by the
+    /// harness.
+    ///
+    /// Events are collected after each frame, so they're kept even once the app has dropped them,
+    /// and can be read with [`collected_events`](Self::collected_events).
+    pub fn collect_events<E: Event + Clone>(&mut self) -> &mut Self {
+        let world = self.world_mut();
+        if !world.contains_resource::<CollectedEvents<E>>() {
+            let cursor = world
+                .get_resource::<Events<E>>()
+                .map(Events::get_cursor_current)
+                .unwrap_or_default();
+            world.insert_resource(CollectedEvents {
+                cursor,
+                events: Vec::new(),
+            });
+            self.collectors.push(collect_events::<E>);
+        }
+        self
+    }
+
+    /// Returns the events of type `E` collected so far, in the order they were sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`collect_events`](Self::collect_events) wasn't called for `E`.
+    pub fn collected_events<E: Event + Clone>(&mut self) -> &[E] {
+        self.collect();
+        &self
+            .world()
+            .get_resource::<CollectedEvents<E>>()
+            .unwrap_or_else(|| {
+                panic!(
+                    "events of type {} are not collected, call `collect_events` first",
+                    core::any::type_name::<E>()
+                )
+            })
+            .events
+    }
+
+    /// Removes and returns the events of type `E` collected so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`collect_events`](Self::collect_events) wasn't called for `E`.
+    pub fn take_collected_events<E: Event + Clone>(&mut self) -> Vec<E> {
+        self.collected_events::<E>();
+        core::mem::take(&mut self.world_mut().resource_mut::<CollectedEvents<E>>().events)
+    }
+
+    fn collect(&mut self) {
+        for collector in &self.collectors {
+            collector(self.app.world_mut());
+        }
+    }
+
+    /// Returns a snapshot of every reflected component of `entity`.
+    ///
+    /// Each component registered with [`ReflectComponent`] in the [`AppTypeRegistry`] is
+    /// pretty-printed with its reflected [`Debug`] representation, and components are sorted by
+    /// type path so snapshots are stable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` doesn't exist.
+    pub fn snapshot(&self, entity: Entity) -> String {
+        let world = self.world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let entity = world.entity(entity);
+
+        let mut components: Vec<(&str, String)> = entity
+            .archetype()
+            .components()
+            .filter_map(|id| {
+                let type_id = world.components().get_info(id)?.type_id()?;
+                let registration = registry.get(type_id)?;
+                let component = registration.data::<ReflectComponent>()?.reflect(entity)?;
+                Some((
+                    registration.type_info().type_path(),
+                    format!("{:#?}", component.as_partial_reflect()),
+                ))
+            })
+            .collect();
+        components.sort_unstable_by_key(|(type_path, _)| *type_path);
+
+        let mut snapshot = String::new();
+        for (_, component) in components {
+            snapshot.push_str(&component);
+            snapshot.push('\n');
+        }
+        snapshot
+    }
+
+    /// Asserts that the [snapshot](Self::snapshot) of `entity` is `expected`, ignoring leading and
+    /// trailing whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a diff of the snapshots if they differ.
+    #[track_caller]
+    pub fn assert_snapshot(&self, entity: Entity, expected: &str) {
+        let actual = self.snapshot(entity);
+        if actual.trim() != expected.trim() {
+            panic!(
+                "snapshot of {entity} differs (-expected +actual):\n{}",
+                diff(expected.trim(), actual.trim())
+            );
+        }
+    }
+
+    /// Asserts that the component `C` of `entity` is equal to `expected`, comparing them through
+    /// reflection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` doesn't have a `C`, or with a diff of the values if they differ.
+    #[track_caller]
+    pub fn assert_component<C: Component + Reflect>(&self, entity: Entity, expected: &C) {
+        let Some(actual) = self.world().get::<C>(entity) else {
+            panic!("{entity} has no component {}", core::any::type_name::<C>());
+        };
+        if actual.reflect_partial_eq(expected.as_partial_reflect()) != Some(true) {
+            let expected = format!("{:#?}", expected.as_partial_reflect());
+            let actual = format!("{:#?}", actual.as_partial_reflect());
+            panic!(
+                "component {} of {entity} differs (-expected +actual):\n{}",
+                core::any::type_name::<C>(),
+                diff(&expected, &actual)
+            );
+        }
+    }
+}
+
+/// The events of type `E` collected by a [`TestHarness`].
+#[derive(Resource)]
+struct CollectedEvents<E: Event> {
+    cursor: EventCursor<E>,
+    events: Vec<E>,
+}
+
+fn collect_events<E: Event + Clone>(world: &mut World) {
+    world.resource_scope(|world, mut collected: Mut<CollectedEvents<E>>| {
+        let Some(events) = world.get_resource::<Events<E>>() else {
+            return;
+        };
+        let CollectedEvents {
+            cursor,
+            events: collected,
+        } = &mut *collected;
+        collected.extend(cursor.read(events).cloned());
+    });
+}
+
+/// Returns a line diff turning `expected` into `actual`, with removed lines prefixed by `-` and
+/// added lines prefixed by `+`.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // The length of the longest common subsequence of each pair of suffixes.
+    let width = actual.len() + 1;
+    let mut common = vec![0usize; (expected.len() + 1) * width];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i * width + j] = if expected[i] == actual[j] {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut output = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        let line = if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            i += 1;
+            j += 1;
+            (' ', actual[j - 1])
+        } else if i < expected.len()
+            && (j == actual.len() || common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            i += 1;
+            ('-', expected[i - 1])
+        } else {
+            j += 1;
+            ('+', actual[j - 1])
+        };
+        let _ = writeln!(output, "{} {}", line.0, line.1);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_input::{ButtonInput, InputPlugin};
+
+    #[derive(Component, Reflect, Default, Clone, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Event, Clone, Debug, PartialEq)]
+    struct Jumped(u32);
+
+    fn walk(keys: Res<ButtonInput<KeyCode>>, time: Res<Time>, mut query: Query<&mut Position>) {
+        if keys.pressed(KeyCode::ArrowRight) {
+            for mut position in &mut query {
+                position.x += 60.0 * time.delta_secs();
+            }
+        }
+    }
+
+    fn jump(
+        keys: Res<ButtonInput<KeyCode>>,
+        mut frame: Local<u32>,
+        mut jumped: EventWriter<Jumped>,
+    ) {
+        *frame += 1;
+        if keys.just_pressed(KeyCode::Space) {
+            jumped.write(Jumped(*frame));
+        }
+    }
+
+    fn harness() -> (TestHarness, Entity) {
+        let mut harness = TestHarness::with_frame_time(Duration::from_millis(500));
+        harness
+            .app_mut()
+            .add_plugins(InputPlugin)
+            .add_event::<Jumped>()
+            .register_type::<Position>()
+            .add_systems(Update, (walk, jump));
+        let player = harness.world_mut().spawn(Position::default()).id();
+        (harness, player)
+    }
+
+    #[test]
+    fn deterministic_frames() {
+        let (mut harness, player) = harness();
+        harness.collect_events::<Jumped>();
+
+        harness.press_key(KeyCode::ArrowRight).advance_frames(2);
+        assert_eq!(
+            harness.world().resource::<Time>().elapsed(),
+            Duration::from_secs(1)
+        );
+        harness.assert_component(player, &Position { x: 60.0, y: 0.0 });
+
+        harness
+            .release_key(KeyCode::ArrowRight)
+            .press_key(KeyCode::Space);
+        harness.advance_time(Duration::from_millis(1200));
+        harness
+            .release_key(KeyCode::Space)
+            .press_key(KeyCode::Space);
+        harness.advance_frames(1);
+        assert_eq!(harness.world().resource::<Time>().elapsed().as_secs(), 3);
+        assert_eq!(
+            harness.take_collected_events::<Jumped>(),
+            [Jumped(3), Jumped(6)]
+        );
+        assert!(harness.collected_events::<Jumped>().is_empty());
+
+        harness.assert_snapshot(
+            player,
+            "
+bevy_dev_tools::test_harness::tests::Position {
+    x: 60.0,
+    y: 0.0,
+}",
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "differs (-expected +actual):\n  bevy_dev_tools::test_harness::tests::Position {\n-     x: 1.0,\n+     x: 0.0,\n      y: 0.0,\n  }\n"
+    )]
+    fn component_diff() {
+        let (harness, player) = harness();
+        harness.assert_component(player, &Position { x: 1.0, y: 0.0 });
+    }
+
+    #[test]
+    fn line_diff() {
+        assert_eq!(diff("a\nb\nc", "a\nc\nd"), "  a\n- b\n  c\n+ d\n");
+        assert_eq!(diff("", "a"), "+ a\n");
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::{borrow::Cow, collections::VecDeque, string::String};
+use alloc::{borrow::Cow, collections::VecDeque, string::String, vec::Vec};
 use core::{
     hash::{Hash, Hasher},
     time::Duration,
@@ -262,6 +262,36 @@ impl Diagnostic {
         }
     }
 
+    /// Return the smallest of this diagnostic's recent values.
+    pub fn min(&self) -> Option<f64> {
+        self.finite_values().reduce(f64::min)
+    }
+
+    /// Return the largest of this diagnostic's recent values.
+    pub fn max(&self) -> Option<f64> {
+        self.finite_values().reduce(f64::max)
+    }
+
+    /// Return the value below which `percentile` percent of this diagnostic's recent values fall,
+    /// using the nearest-rank method. `percentile` is clamped to `0.0..=100.0`.
+    ///
+    /// N.B. unlike [`average`](Self::average), this sorts a copy of the history.
+    pub fn percentile(&self, percentile: f64) -> Option<f64> {
+        let mut values: Vec<f64> = self.finite_values().collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable_by(f64::total_cmp);
+        // Rounds up without `f64::ceil`, which isn't available in `no_std`.
+        let exact = percentile.clamp(0.0, 100.0) / 100.0 * values.len() as f64;
+        let rank = exact as usize + usize::from((exact as usize as f64) < exact);
+        Some(values[rank.saturating_sub(1).min(values.len() - 1)])
+    }
+
+    fn finite_values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.values().copied().filter(|value| value.is_finite())
+    }
+
     /// Return the number of elements for this diagnostic.
     pub fn history_len(&self) -> usize {
         self.history.len()
@@ -458,4 +488,21 @@ mod tests {
             diagnostic.clear_history();
         }
     }
+
+    #[test]
+    fn test_min_max_percentile() {
+        let mut diagnostic = Diagnostic::new(DiagnosticPath::new("test"));
+        assert_eq!(diagnostic.percentile(50.0), None);
+
+        let now = Instant::now();
+        for value in [4.0, f64::NAN, 1.0, 3.0, 2.0] {
+            diagnostic.add_measurement(DiagnosticMeasurement { time: now, value });
+        }
+        assert_eq!(diagnostic.min(), Some(1.0));
+        assert_eq!(diagnostic.max(), Some(4.0));
+        assert_eq!(diagnostic.percentile(0.0), Some(1.0));
+        assert_eq!(diagnostic.percentile(50.0), Some(2.0));
+        assert_eq!(diagnostic.percentile(75.0), Some(3.0));
+        assert_eq!(diagnostic.percentile(100.0), Some(4.0));
+    }
 }
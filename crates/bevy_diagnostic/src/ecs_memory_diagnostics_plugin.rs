@@ -0,0 +1,66 @@
+use bevy_app::prelude::*;
+use bevy_ecs::world::World;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds diagnostics for the memory used by the storages of the [`World`] to an App.
+///
+/// The measurements are totals taken from [`World::memory_stats`], which can be used directly
+/// for a per-component breakdown.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct EcsMemoryDiagnosticsPlugin;
+
+impl Plugin for EcsMemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ALLOCATED_BYTES).with_suffix(" B"))
+            .register_diagnostic(Diagnostic::new(Self::USED_BYTES).with_suffix(" B"))
+            .register_diagnostic(Diagnostic::new(Self::RESOURCE_BYTES).with_suffix(" B"))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_FRAGMENTATION).with_suffix("%"))
+            .register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_ARCHETYPE_COUNT))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl EcsMemoryDiagnosticsPlugin {
+    /// Total number of bytes allocated by component and resource storages.
+    pub const ALLOCATED_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/allocated_bytes");
+    /// Number of allocated bytes that hold live component and resource values.
+    pub const USED_BYTES: DiagnosticPath = DiagnosticPath::const_new("ecs_memory/used_bytes");
+    /// Number of bytes used by resources.
+    pub const RESOURCE_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/resource_bytes");
+    /// Number of tables.
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("ecs_memory/table_count");
+    /// Percentage of the memory allocated by tables that is unused.
+    pub const TABLE_FRAGMENTATION: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/table_fragmentation");
+    /// Number of archetypes.
+    pub const ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/archetype_count");
+    /// Number of archetypes without any entities.
+    pub const EMPTY_ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/empty_archetype_count");
+
+    /// Updates ECS memory measurements.
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, world: &World) {
+        let stats = world.memory_stats();
+        diagnostics.add_measurement(&Self::ALLOCATED_BYTES, || stats.allocated_bytes() as f64);
+        diagnostics.add_measurement(&Self::USED_BYTES, || stats.used_bytes() as f64);
+        diagnostics.add_measurement(&Self::RESOURCE_BYTES, || stats.resource_bytes() as f64);
+        diagnostics.add_measurement(&Self::TABLE_COUNT, || stats.tables.len() as f64);
+        diagnostics.add_measurement(&Self::TABLE_FRAGMENTATION, || {
+            stats.table_fragmentation() * 100.0
+        });
+        diagnostics.add_measurement(&Self::ARCHETYPE_COUNT, || stats.archetypes.len() as f64);
+        diagnostics.add_measurement(&Self::EMPTY_ARCHETYPE_COUNT, || {
+            stats.empty_archetype_count() as f64
+        });
+    }
+}
@@ -16,21 +16,25 @@ extern crate std;
 extern crate alloc;
 
 mod diagnostic;
+mod ecs_memory_diagnostics_plugin;
 mod entity_count_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod system_time_diagnostics_plugin;
 
 pub use diagnostic::*;
 
+pub use ecs_memory_diagnostics_plugin::EcsMemoryDiagnosticsPlugin;
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_count_diagnostics_plugin::{update_frame_count, FrameCount, FrameCountPlugin};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::{LogDiagnosticsPlugin, LogDiagnosticsState};
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+pub use system_time_diagnostics_plugin::SystemTimeDiagnosticsPlugin;
 
 use bevy_app::prelude::*;
 
@@ -0,0 +1,165 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ScheduleLabel, SystemTiming, SystemTimings},
+};
+use bevy_platform::{collections::HashMap, time::Instant};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds the wall time spent in each system and named system set of the main world to an App,
+/// without requiring the `trace` feature.
+///
+/// The time of a system is recorded by its executor every time its schedule runs, and reported once
+/// per frame in milliseconds, as a diagnostic at [`system_path`](Self::system_path) for systems and
+/// [`set_path`](Self::set_path) for sets. A system that runs several times per frame, for example
+/// in `FixedUpdate`, reports the sum of its runs. Diagnostics are registered the first time a system runs, and use
+/// [`Diagnostic::min`], [`Diagnostic::average`], [`Diagnostic::max`] and
+/// [`Diagnostic::percentile`] to summarize the history.
+///
+/// Measurements are taken from [`SystemTimings`], which can be used directly as well.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemTimeDiagnosticsPlugin {
+    /// The total number of values to keep for each system.
+    pub max_history_length: usize,
+}
+
+impl Default for SystemTimeDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_history_length: DEFAULT_MAX_HISTORY_LENGTH,
+        }
+    }
+}
+
+impl Plugin for SystemTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let max_history_length = self.max_history_length;
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<SystemTimings>()
+            // Running first, the measurements cover every schedule of the previous frame.
+            .add_systems(
+                First,
+                move |timings: ResMut<SystemTimings>,
+                      store: ResMut<DiagnosticsStore>,
+                      paths: Local<DiagnosticPaths>| {
+                    Self::diagnostic_system(timings, store, paths, max_history_length);
+                },
+            );
+    }
+}
+
+impl SystemTimeDiagnosticsPlugin {
+    /// Returns the path of the diagnostic of the system called `name` in `schedule`.
+    pub fn system_path(schedule: impl ScheduleLabel, name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("{SYSTEM_PREFIX}/{schedule:?}/{name}"))
+    }
+
+    /// Returns the path of the diagnostic of `set` in `schedule`.
+    pub fn set_path(schedule: impl ScheduleLabel, set: impl SystemSet) -> DiagnosticPath {
+        DiagnosticPath::new(format!("{SET_PREFIX}/{schedule:?}/{set:?}"))
+    }
+
+    /// Moves the measurements from [`SystemTimings`] to the [`DiagnosticsStore`].
+    fn diagnostic_system(
+        mut timings: ResMut<SystemTimings>,
+        mut store: ResMut<DiagnosticsStore>,
+        mut paths: Local<DiagnosticPaths>,
+        max_history_length: usize,
+    ) {
+        let time = Instant::now();
+        let mut record = |path: &DiagnosticPath, timing: SystemTiming| {
+            if store.get(path).is_none() {
+                store.add(
+                    Diagnostic::new(path.clone())
+                        .with_suffix("ms")
+                        .with_max_history_length(max_history_length),
+                );
+            }
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: timing.total.as_secs_f64() * 1000.0,
+                });
+            }
+        };
+        for (key, timing) in timings.systems() {
+            record(cached_path(&mut paths.systems, SYSTEM_PREFIX, key), timing);
+        }
+        for (key, timing) in timings.sets() {
+            record(cached_path(&mut paths.sets, SET_PREFIX, key), timing);
+        }
+        timings.clear();
+    }
+}
+
+const SYSTEM_PREFIX: &str = "system_time";
+const SET_PREFIX: &str = "system_set_time";
+
+/// The diagnostic paths of the keys in [`SystemTimings`], so they're only built once.
+#[derive(Default)]
+struct DiagnosticPaths {
+    systems: HashMap<String, DiagnosticPath>,
+    sets: HashMap<String, DiagnosticPath>,
+}
+
+fn cached_path<'a>(
+    paths: &'a mut HashMap<String, DiagnosticPath>,
+    prefix: &str,
+    key: &str,
+) -> &'a DiagnosticPath {
+    if !paths.contains_key(key) {
+        paths.insert(
+            key.to_string(),
+            DiagnosticPath::new(format!("{prefix}/{key}")),
+        );
+    }
+    &paths[key]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticsPlugin;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Timed;
+
+    fn timed_system() {}
+
+    #[test]
+    fn system_time_diagnostics() {
+        let mut app = App::new();
+        app.add_plugins((DiagnosticsPlugin, SystemTimeDiagnosticsPlugin::default()))
+            .add_systems(Update, timed_system.in_set(Timed));
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let system = store
+            .get(&SystemTimeDiagnosticsPlugin::system_path(
+                Update,
+                "bevy_diagnostic::system_time_diagnostics_plugin::tests::timed_system",
+            ))
+            .unwrap();
+        // The first frame is measured from the start of the second one.
+        assert_eq!(system.history_len(), 2);
+        assert!(system.max().unwrap() >= system.min().unwrap());
+
+        let set = store
+            .get(&SystemTimeDiagnosticsPlugin::set_path(Update, Timed))
+            .unwrap();
+        assert_eq!(set.history_len(), 2);
+    }
+}
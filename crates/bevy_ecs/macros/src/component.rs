@@ -91,12 +91,39 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Ok(value) => value,
         Err(err) => err.into_compile_error().into(),
     };
+    let many_to_many = match derive_many_to_many(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => err.into_compile_error().into(),
+    };
+    let many_to_many_target = match derive_many_to_many_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => err.into_compile_error().into(),
+    };
+
+    if [
+        relationship.is_some(),
+        relationship_target.is_some(),
+        many_to_many.is_some(),
+        many_to_many_target.is_some(),
+    ]
+    .into_iter()
+    .filter(|is_some| *is_some)
+    .count()
+        > 1
+    {
+        return syn::Error::new(
+            ast.span(),
+            "A component can only be one of a Relationship, RelationshipTarget, ManyToManyRelationship or ManyToManyTarget",
+        )
+        .into_compile_error()
+        .into();
+    }
 
     let map_entities = map_entities(
         &ast.data,
         Ident::new("this", Span::call_site()),
-        relationship.is_some(),
-        relationship_target.is_some(),
+        relationship.is_some() || many_to_many.is_some(),
+        relationship_target.is_some() || many_to_many_target.is_some(),
     ).map(|map_entities_impl| quote! {
         fn map_entities<M: #bevy_ecs_path::entity::EntityMapper>(this: &mut Self, mapper: &mut M) {
             use #bevy_ecs_path::entity::MapEntities;
@@ -124,6 +151,17 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         }
 
         Some(quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert))
+    } else if many_to_many.is_some() {
+        if attrs.on_insert.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_insert hooks are not supported as ManyToManyRelationships already define an on_insert hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        Some(quote!(<Self as #bevy_ecs_path::relationship::ManyToManyRelationship>::on_insert))
     } else {
         attrs
             .on_insert
@@ -152,6 +190,28 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         }
 
         Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace))
+    } else if many_to_many.is_some() {
+        if attrs.on_replace.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_replace hooks are not supported as ManyToManyRelationships already define an on_replace hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        Some(quote!(<Self as #bevy_ecs_path::relationship::ManyToManyRelationship>::on_replace))
+    } else if many_to_many_target.is_some() {
+        if attrs.on_replace.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_replace hooks are not supported as ManyToManyTarget already defines an on_replace hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        Some(quote!(<Self as #bevy_ecs_path::relationship::ManyToManyTarget>::on_replace))
     } else {
         attrs
             .on_replace
@@ -172,6 +232,21 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         }
 
         Some(quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_despawn))
+    } else if attrs
+        .many_to_many_target
+        .as_ref()
+        .is_some_and(|target| target.linked_spawn)
+    {
+        if attrs.on_despawn.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_despawn hooks are not supported as this ManyToManyTarget already defines an on_despawn hook, via the 'linked_spawn' attribute",
+            )
+            .into_compile_error()
+            .into();
+        }
+
+        Some(quote!(<Self as #bevy_ecs_path::relationship::ManyToManyTarget>::on_despawn))
     } else {
         attrs
             .on_despawn
@@ -245,12 +320,14 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         }
     });
 
-    let mutable_type = (attrs.immutable || relationship.is_some())
+    let mutable_type = (attrs.immutable || relationship.is_some() || many_to_many.is_some())
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
     let clone_behavior = if relationship_target.is_some() {
         quote!(#bevy_ecs_path::component::ComponentCloneBehavior::Custom(#bevy_ecs_path::relationship::clone_relationship_target::<Self>))
+    } else if many_to_many_target.is_some() {
+        quote!(#bevy_ecs_path::component::ComponentCloneBehavior::Custom(#bevy_ecs_path::relationship::clone_many_to_many_target::<Self>))
     } else if let Some(behavior) = attrs.clone_behavior {
         quote!(#bevy_ecs_path::component::ComponentCloneBehavior::#behavior)
     } else {
@@ -298,6 +375,10 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         #relationship
 
         #relationship_target
+
+        #many_to_many
+
+        #many_to_many_target
     })
 }
 
@@ -390,6 +471,8 @@ pub const STORAGE: &str = "storage";
 pub const REQUIRE: &str = "require";
 pub const RELATIONSHIP: &str = "relationship";
 pub const RELATIONSHIP_TARGET: &str = "relationship_target";
+pub const MANY_TO_MANY: &str = "many_to_many";
+pub const MANY_TO_MANY_TARGET: &str = "many_to_many_target";
 
 pub const ON_ADD: &str = "on_add";
 pub const ON_INSERT: &str = "on_insert";
@@ -460,6 +543,8 @@ struct Attrs {
     on_despawn: Option<HookAttributeKind>,
     relationship: Option<Relationship>,
     relationship_target: Option<RelationshipTarget>,
+    many_to_many: Option<Relationship>,
+    many_to_many_target: Option<RelationshipTarget>,
     immutable: bool,
     clone_behavior: Option<Expr>,
 }
@@ -499,6 +584,8 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         requires: None,
         relationship: None,
         relationship_target: None,
+        many_to_many: None,
+        many_to_many_target: None,
         immutable: false,
         clone_behavior: None,
     };
@@ -565,10 +652,18 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
             let relationship_target = attr.parse_args::<RelationshipTarget>()?;
             attrs.relationship_target = Some(relationship_target);
+        } else if attr.path().is_ident(MANY_TO_MANY) {
+            let many_to_many = attr.parse_args::<Relationship>()?;
+            attrs.many_to_many = Some(many_to_many);
+        } else if attr.path().is_ident(MANY_TO_MANY_TARGET) {
+            let many_to_many_target = attr.parse_args::<RelationshipTarget>()?;
+            attrs.many_to_many_target = Some(many_to_many_target);
         }
     }
 
-    if attrs.relationship_target.is_some() && attrs.clone_behavior.is_some() {
+    if (attrs.relationship_target.is_some() || attrs.many_to_many_target.is_some())
+        && attrs.clone_behavior.is_some()
+    {
         return Err(syn::Error::new(
                 attrs.clone_behavior.span(),
                 "A Relationship Target already has its own clone behavior, please remove `clone_behavior = ...`",
@@ -825,6 +920,127 @@ fn derive_relationship_target(
     }))
 }
 
+fn derive_many_to_many(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(many_to_many) = &attrs.many_to_many else {
+        return Ok(None);
+    };
+    let Data::Struct(DataStruct {
+        fields,
+        struct_token,
+        ..
+    }) = &ast.data
+    else {
+        return Err(syn::Error::new(
+            ast.span(),
+            "ManyToManyRelationship can only be derived for structs.",
+        ));
+    };
+    let field = relationship_field(fields, "ManyToManyRelationship", struct_token.span())?;
+
+    let collection = &field.ty;
+    let relationship_member = field.ident.clone().map_or(Member::from(0), Member::Named);
+    let members = fields
+        .members()
+        .filter(|member| member != &relationship_member);
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let relationship_target = &many_to_many.relationship_target;
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::ManyToManyRelationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+            type Collection = #collection;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#relationship_member
+            }
+
+            #[inline]
+            fn collection_mut(&mut self) -> &mut Self::Collection {
+                &mut self.#relationship_member
+            }
+
+            #[inline]
+            fn from_collection(collection: Self::Collection) -> Self {
+                Self {
+                    #(#members: core::default::Default::default(),)*
+                    #relationship_member: collection
+                }
+            }
+        }
+    }))
+}
+
+fn derive_many_to_many_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(many_to_many_target) = &attrs.many_to_many_target else {
+        return Ok(None);
+    };
+
+    let Data::Struct(DataStruct {
+        fields,
+        struct_token,
+        ..
+    }) = &ast.data
+    else {
+        return Err(syn::Error::new(
+            ast.span(),
+            "ManyToManyTarget can only be derived for structs.",
+        ));
+    };
+    let field = relationship_field(fields, "ManyToManyTarget", struct_token.span())?;
+
+    if field.vis != Visibility::Inherited {
+        return Err(syn::Error::new(field.span(), "The collection in ManyToManyTarget must be private to prevent users from directly mutating it, which could invalidate the correctness of relationships."));
+    }
+    let collection = &field.ty;
+    let relationship_member = field.ident.clone().map_or(Member::from(0), Member::Named);
+
+    let members = fields
+        .members()
+        .filter(|member| member != &relationship_member);
+
+    let relationship = &many_to_many_target.relationship;
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let linked_spawn = many_to_many_target.linked_spawn;
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::ManyToManyTarget for #struct_name #type_generics #where_clause {
+            const LINKED_SPAWN: bool = #linked_spawn;
+            type Relationship = #relationship;
+            type Collection = #collection;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#relationship_member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#relationship_member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self {
+                    #(#members: core::default::Default::default(),)*
+                    #relationship_member: collection
+                }
+            }
+        }
+    }))
+}
+
 /// Returns the field with the `#[relationship]` attribute, the only field if unnamed,
 /// or the only field in a [`Fields::Named`] with one field, otherwise `Err`.
 fn relationship_field<'a>(
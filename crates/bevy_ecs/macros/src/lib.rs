@@ -540,7 +540,15 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 /// Implement the `Component` trait.
 #[proc_macro_derive(
     Component,
-    attributes(component, require, relationship, relationship_target, entities)
+    attributes(
+        component,
+        require,
+        relationship,
+        relationship_target,
+        many_to_many,
+        many_to_many_target,
+        entities
+    )
 )]
 pub fn derive_component(input: TokenStream) -> TokenStream {
     component::derive_component(input)
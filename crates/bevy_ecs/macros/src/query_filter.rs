@@ -108,6 +108,13 @@ pub fn derive_query_filter_impl(input: TokenStream) -> TokenStream {
             ) -> bool {
                 true #(&& <#field_types>::filter_fetch(&mut _fetch.#named_field_idents, _entity, _table_row))*
             }
+
+            #[inline(always)]
+            unsafe fn filter_storage<'__w>(
+                _fetch: &<Self as #path::query::WorldQuery>::Fetch<'__w>,
+            ) -> bool {
+                true #(&& <#field_types>::filter_storage(&_fetch.#named_field_idents))*
+            }
         }
     };
 
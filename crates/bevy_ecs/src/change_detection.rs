@@ -1,7 +1,7 @@
 //! Types that detect when their internal data mutate.
 
 use crate::{
-    component::{Tick, TickCells},
+    component::{StorageTicks, Tick, TickCells},
     ptr::PtrMut,
     resource::Resource,
 };
@@ -395,30 +395,28 @@ macro_rules! change_detection_mut_impl {
             #[inline]
             #[track_caller]
             fn set_changed(&mut self) {
-                *self.ticks.changed = self.ticks.this_run;
+                self.ticks.set_changed(self.ticks.this_run);
                 self.changed_by.assign(MaybeLocation::caller());
             }
 
             #[inline]
             #[track_caller]
             fn set_added(&mut self) {
-                *self.ticks.changed = self.ticks.this_run;
-                *self.ticks.added = self.ticks.this_run;
+                self.ticks.set_added(self.ticks.this_run);
                 self.changed_by.assign(MaybeLocation::caller());
             }
 
             #[inline]
             #[track_caller]
             fn set_last_changed(&mut self, last_changed: Tick) {
-                *self.ticks.changed = last_changed;
+                self.ticks.set_changed(last_changed);
                 self.changed_by.assign(MaybeLocation::caller());
             }
 
             #[inline]
             #[track_caller]
             fn set_last_added(&mut self, last_added: Tick) {
-                *self.ticks.added = last_added;
-                *self.ticks.changed = last_added;
+                self.ticks.set_added(last_added);
                 self.changed_by.assign(MaybeLocation::caller());
             }
 
@@ -468,6 +466,7 @@ macro_rules! impl_methods {
                     ticks: TicksMut {
                         added: self.ticks.added,
                         changed: self.ticks.changed,
+                        storage: self.ticks.storage,
                         last_run: self.ticks.last_run,
                         this_run: self.ticks.this_run,
                     },
@@ -589,6 +588,7 @@ impl<'w> Ticks<'w> {
 pub(crate) struct TicksMut<'w> {
     pub(crate) added: &'w mut Tick,
     pub(crate) changed: &'w mut Tick,
+    pub(crate) storage: Option<&'w StorageTicks>,
     pub(crate) last_run: Tick,
     pub(crate) this_run: Tick,
 }
@@ -607,10 +607,30 @@ impl<'w> TicksMut<'w> {
             added: unsafe { cells.added.deref_mut() },
             // SAFETY: Caller ensures there is no alias to the cell.
             changed: unsafe { cells.changed.deref_mut() },
+            storage: cells.storage,
             last_run,
             this_run,
         }
     }
+
+    /// Sets the changed tick of the value, and records the change in its storage.
+    #[inline]
+    pub(crate) fn set_changed(&mut self, changed: Tick) {
+        *self.changed = changed;
+        if let Some(storage) = self.storage {
+            storage.record_changed(changed);
+        }
+    }
+
+    /// Sets the added and changed ticks of the value, and records the addition in its storage.
+    #[inline]
+    pub(crate) fn set_added(&mut self, added: Tick) {
+        *self.added = added;
+        *self.changed = added;
+        if let Some(storage) = self.storage {
+            storage.record_added(added);
+        }
+    }
 }
 
 impl<'w> From<TicksMut<'w>> for Ticks<'w> {
@@ -978,6 +998,12 @@ impl<'w, T: ?Sized> Mut<'w, T> {
     /// - `last_run` - A [`Tick`], occurring before `this_run`, which is used
     ///   as a reference to determine whether the wrapped value is newly added or changed.
     /// - `this_run` - A [`Tick`] corresponding to the current point in time -- "now".
+    ///
+    /// The returned `Mut` doesn't record changes in the [`StorageTicks`] of a component storage.
+    /// If `value` is a component, pass the ticks of its storage to
+    /// [`with_storage_ticks`](Self::with_storage_ticks), or the [`Added`](crate::query::Added) and
+    /// [`Changed`](crate::query::Changed) filters may skip its table or sparse set without seeing
+    /// the change.
     pub fn new(
         value: &'w mut T,
         added: &'w mut Tick,
@@ -991,6 +1017,7 @@ impl<'w, T: ?Sized> Mut<'w, T> {
             ticks: TicksMut {
                 added,
                 changed: last_changed,
+                storage: None,
                 last_run,
                 this_run,
             },
@@ -998,6 +1025,15 @@ impl<'w, T: ?Sized> Mut<'w, T> {
         }
     }
 
+    /// Records the changes made through this `Mut` in the [`StorageTicks`] of the storage the
+    /// value is in, as returned by [`TickCells::storage`](crate::component::TickCells::storage).
+    ///
+    /// This is an advanced feature, only needed for `Mut`s created with [`Mut::new`].
+    pub fn with_storage_ticks(mut self, storage: &'w StorageTicks) -> Self {
+        self.ticks.storage = Some(storage);
+        self
+    }
+
     /// Overwrite the `last_run` and `this_run` tick that are used for change detection.
     ///
     /// This is an advanced feature. `Mut`s are usually _created_ by engine-internal code and
@@ -1081,6 +1117,7 @@ impl<'w> MutUntyped<'w> {
             ticks: TicksMut {
                 added: self.ticks.added,
                 changed: self.ticks.changed,
+                storage: self.ticks.storage,
                 last_run: self.ticks.last_run,
                 this_run: self.ticks.this_run,
             },
@@ -1191,30 +1228,28 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
     #[inline]
     #[track_caller]
     fn set_changed(&mut self) {
-        *self.ticks.changed = self.ticks.this_run;
+        self.ticks.set_changed(self.ticks.this_run);
         self.changed_by.assign(MaybeLocation::caller());
     }
 
     #[inline]
     #[track_caller]
     fn set_added(&mut self) {
-        *self.ticks.changed = self.ticks.this_run;
-        *self.ticks.added = self.ticks.this_run;
+        self.ticks.set_added(self.ticks.this_run);
         self.changed_by.assign(MaybeLocation::caller());
     }
 
     #[inline]
     #[track_caller]
     fn set_last_changed(&mut self, last_changed: Tick) {
-        *self.ticks.changed = last_changed;
+        self.ticks.set_changed(last_changed);
         self.changed_by.assign(MaybeLocation::caller());
     }
 
     #[inline]
     #[track_caller]
     fn set_last_added(&mut self, last_added: Tick) {
-        *self.ticks.added = last_added;
-        *self.ticks.changed = last_added;
+        self.ticks.set_added(last_added);
         self.changed_by.assign(MaybeLocation::caller());
     }
 
@@ -1539,7 +1574,7 @@ mod tests {
             MaybeLocation, Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD,
             MAX_CHANGE_AGE,
         },
-        component::{Component, ComponentTicks, Tick},
+        component::{Component, ComponentTicks, StorageTicks, Tick},
         system::{IntoSystem, Single, System},
         world::World,
     };
@@ -1659,6 +1694,7 @@ mod tests {
         let ticks = TicksMut {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
+            storage: None,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
         };
@@ -1698,6 +1734,11 @@ mod tests {
 
         assert!(!val.is_added());
         assert!(val.is_changed());
+
+        let storage = StorageTicks::default();
+        let mut val = val.with_storage_ticks(&storage);
+        val.set_changed();
+        assert!(storage.is_changed(Tick::new(2), Tick::new(4)));
     }
 
     #[test]
@@ -1709,6 +1750,7 @@ mod tests {
         let ticks = TicksMut {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
+            storage: None,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
         };
@@ -1742,6 +1784,7 @@ mod tests {
         let ticks = TicksMut {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
+            storage: None,
             last_run,
             this_run,
         };
@@ -1830,6 +1873,7 @@ mod tests {
         let ticks = TicksMut {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
+            storage: None,
             last_run,
             this_run,
         };
@@ -1866,6 +1910,7 @@ mod tests {
         let ticks = TicksMut {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
+            storage: None,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
         };
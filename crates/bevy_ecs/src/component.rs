@@ -3,7 +3,7 @@
 use crate::{
     archetype::ArchetypeFlags,
     bundle::BundleInfo,
-    change_detection::{MaybeLocation, MAX_CHANGE_AGE},
+    change_detection::{MaybeLocation, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE},
     entity::{ComponentCloneCtx, Entity, EntityMapper, SourceComponent},
     query::DebugCheckedUnwrap,
     relationship::RelationshipHookMode,
@@ -18,7 +18,10 @@ pub use bevy_ecs_macros::Component;
 use bevy_platform::sync::Arc;
 use bevy_platform::{
     collections::{HashMap, HashSet},
-    sync::PoisonError,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        PoisonError,
+    },
 };
 use bevy_ptr::{OwningPtr, UnsafeCellDeref};
 #[cfg(feature = "bevy_reflect")]
@@ -923,6 +926,13 @@ impl ComponentInfo {
         self.descriptor.mutable
     }
 
+    /// Registers the Rust type of the current component in `world`, returning its [`ComponentId`] there.
+    ///
+    /// Returns `None` if the component was not registered from a Rust type.
+    pub(crate) fn register_in(&self, world: &mut World) -> Option<ComponentId> {
+        self.descriptor.register.map(|register| register(world))
+    }
+
     /// Returns [`ComponentCloneBehavior`] of the current component.
     #[inline]
     pub fn clone_behavior(&self) -> &ComponentCloneBehavior {
@@ -1086,9 +1096,12 @@ pub struct ComponentDescriptor {
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
     mutable: bool,
     clone_behavior: ComponentCloneBehavior,
+    // Registers the underlying Rust component type in a world.
+    // None if the descriptor does not describe a Rust component type.
+    register: Option<fn(&mut World) -> ComponentId>,
 }
 
-// We need to ignore the `drop` field in our `Debug` impl
+// We need to ignore the `drop` and `register` fields in our `Debug` impl
 impl Debug for ComponentDescriptor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ComponentDescriptor")
@@ -1125,9 +1138,14 @@ impl ComponentDescriptor {
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: T::Mutability::MUTABLE,
             clone_behavior: T::clone_behavior(),
+            register: Some(Self::register_component::<T>),
         }
     }
 
+    fn register_component<T: Component>(world: &mut World) -> ComponentId {
+        world.register_component::<T>()
+    }
+
     /// Create a new `ComponentDescriptor`.
     ///
     /// # Safety
@@ -1150,6 +1168,7 @@ impl ComponentDescriptor {
             drop,
             mutable,
             clone_behavior,
+            register: None,
         }
     }
 
@@ -1168,6 +1187,7 @@ impl ComponentDescriptor {
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
             clone_behavior: ComponentCloneBehavior::Default,
+            register: None,
         }
     }
 
@@ -1181,6 +1201,7 @@ impl ComponentDescriptor {
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
             clone_behavior: ComponentCloneBehavior::Default,
+            register: None,
         }
     }
 
@@ -1328,18 +1349,12 @@ pub struct ComponentIds {
 impl ComponentIds {
     /// Peeks the next [`ComponentId`] to be generated without generating it.
     pub fn peek(&self) -> ComponentId {
-        ComponentId(
-            self.next
-                .load(bevy_platform::sync::atomic::Ordering::Relaxed),
-        )
+        ComponentId(self.next.load(Ordering::Relaxed))
     }
 
     /// Generates and returns the next [`ComponentId`].
     pub fn next(&self) -> ComponentId {
-        ComponentId(
-            self.next
-                .fetch_add(1, bevy_platform::sync::atomic::Ordering::Relaxed),
-        )
+        ComponentId(self.next.fetch_add(1, Ordering::Relaxed))
     }
 
     /// Peeks the next [`ComponentId`] to be generated without generating it.
@@ -2636,6 +2651,10 @@ pub struct TickCells<'a> {
     pub added: &'a UnsafeCell<Tick>,
     /// The tick indicating the last time the value was modified.
     pub changed: &'a UnsafeCell<Tick>,
+    /// The ticks of the storage the value is in, updated alongside the value's ticks when they change.
+    ///
+    /// This is `None` for values that aren't stored in a component storage, such as resources.
+    pub storage: Option<&'a StorageTicks>,
 }
 
 impl<'a> TickCells<'a> {
@@ -2706,6 +2725,115 @@ impl ComponentTicks {
     }
 }
 
+/// The newest [`Tick`]s at which a value in a component storage was added or changed.
+///
+/// Each [`Table`] column and each [`ComponentSparseSet`](crate::storage::ComponentSparseSet) keeps one of these,
+/// so that the [`Added`](crate::query::Added) and [`Changed`](crate::query::Changed) filters can skip whole
+/// storages in which nothing happened since a system last ran, instead of checking the ticks of every value.
+/// A sparse set has a single `StorageTicks` for all of its values, so it is only skipped if none of them
+/// were added or changed.
+///
+/// The ticks are an upper bound: no value in the storage was added or changed more recently,
+/// but the value that was may since have been removed.
+#[derive(Debug, Default)]
+pub struct StorageTicks {
+    added: AtomicU32,
+    changed: AtomicU32,
+}
+
+impl StorageTicks {
+    /// Returns the newest tick at which a value was added to the storage.
+    #[inline]
+    pub fn added(&self) -> Tick {
+        Tick::new(self.added.load(Ordering::Relaxed))
+    }
+
+    /// Returns the newest tick at which a value in the storage was added or changed.
+    #[inline]
+    pub fn changed(&self) -> Tick {
+        Tick::new(self.changed.load(Ordering::Relaxed))
+    }
+
+    /// Returns `true` if a value may have been added to the storage after the system last ran.
+    #[inline]
+    pub fn is_added(&self, last_run: Tick, this_run: Tick) -> bool {
+        Self::is_newer_than(self.added(), last_run, this_run)
+    }
+
+    /// Returns `true` if a value in the storage may have been added or changed after the system last ran.
+    #[inline]
+    pub fn is_changed(&self, last_run: Tick, this_run: Tick) -> bool {
+        Self::is_newer_than(self.changed(), last_run, this_run)
+    }
+
+    #[inline]
+    fn is_newer_than(newest: Tick, last_run: Tick, this_run: Tick) -> bool {
+        // Systems with disjoint queries can write to the same table concurrently (e.g. `With<A>` and
+        // `Without<A>` for a sparse set component `A`), so the tick may be newer than `this_run`.
+        newest.is_newer_than(last_run, this_run) || newest.relative_to(this_run).get() as i32 > 0
+    }
+
+    /// Records that a value was added at `tick`, while the storage may be shared with other threads.
+    #[inline]
+    pub(crate) fn record_added(&self, tick: Tick) {
+        Self::record(&self.added, tick);
+        Self::record(&self.changed, tick);
+    }
+
+    /// Records that a value was changed at `tick`, while the storage may be shared with other threads.
+    #[inline]
+    pub(crate) fn record_changed(&self, tick: Tick) {
+        Self::record(&self.changed, tick);
+    }
+
+    #[inline]
+    fn record(newest: &AtomicU32, tick: Tick) {
+        // `check_change_ticks` keeps the stored tick close enough to the current tick
+        // for the wrapping difference to tell which one is newer.
+        let _ = newest.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+            (tick.get().wrapping_sub(current) as i32 > 0).then_some(tick.get())
+        });
+    }
+
+    /// Records that a value was added or changed at the given ticks.
+    ///
+    /// `change_tick` is the current tick of the world, which must not be older than any recorded tick.
+    #[inline]
+    pub(crate) fn merge(&mut self, ticks: ComponentTicks, change_tick: Tick) {
+        Self::merge_tick(&mut self.added, ticks.added, change_tick);
+        Self::merge_tick(&mut self.changed, ticks.changed, change_tick);
+    }
+
+    /// Records that a value was changed at `tick`.
+    ///
+    /// `change_tick` is the current tick of the world, which must not be older than any recorded tick.
+    #[inline]
+    pub(crate) fn merge_changed(&mut self, tick: Tick, change_tick: Tick) {
+        Self::merge_tick(&mut self.changed, tick, change_tick);
+    }
+
+    #[inline]
+    fn merge_tick(newest: &mut AtomicU32, tick: Tick, change_tick: Tick) {
+        let newest = newest.get_mut();
+        if tick.is_newer_than(Tick::new(*newest), change_tick) {
+            *newest = tick.get();
+        }
+    }
+
+    /// Moves ticks that are too old towards `change_tick`, so that [`record_changed`](Self::record_changed)
+    /// can keep comparing them. This only makes the storage less likely to be skipped.
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+        for newest in [&mut self.added, &mut self.changed] {
+            let newest = newest.get_mut();
+            if change_tick.relative_to(Tick::new(*newest)).get() > CHECK_TICK_THRESHOLD {
+                *newest = change_tick
+                    .relative_to(Tick::new(CHECK_TICK_THRESHOLD))
+                    .get();
+            }
+        }
+    }
+}
+
 /// A [`SystemParam`] that provides access to the [`ComponentId`] for a specific component type.
 ///
 /// # Example
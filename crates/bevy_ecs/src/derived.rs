@@ -0,0 +1,273 @@
+//! Components that are automatically recomputed from other components.
+//!
+//! A derived component is a pure function of some input components on the same entity,
+//! like a world-space bounding box computed from a local bounding box and a global transform.
+//! Rather than writing a system that keeps it up to date by hand, add the system returned by
+//! [`derived_component`] to a schedule:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::derived::derived_component;
+//! #[derive(Component)]
+//! struct Stats {
+//!     speed: f32,
+//! }
+//!
+//! #[derive(Component)]
+//! struct Buffs {
+//!     speed_multiplier: f32,
+//! }
+//!
+//! #[derive(Component, Debug, PartialEq)]
+//! struct EffectiveSpeed(f32);
+//!
+//! let mut world = World::new();
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(derived_component::<EffectiveSpeed, (Stats, Buffs)>(
+//!     |(stats, buffs)| EffectiveSpeed(stats.speed * buffs.speed_multiplier),
+//! ));
+//!
+//! let entity = world
+//!     .spawn((Stats { speed: 2.0 }, Buffs { speed_multiplier: 1.5 }))
+//!     .id();
+//! schedule.run(&mut world);
+//! assert_eq!(world.get::<EffectiveSpeed>(entity), Some(&EffectiveSpeed(3.0)));
+//! ```
+//!
+//! The derived component is recomputed only for entities where at least one input was added or
+//! changed since the system last ran, or where the derived component is missing.
+//! It is inserted on every entity that has all of the inputs, and removed from entities that lose
+//! one of them. Values of the derived component inserted by other means are only overwritten when
+//! the inputs change, and are never removed.
+//!
+//! The system is part of [`DerivedComponentSystems`], and of the [`DerivedComponentSet`] of the
+//! derived component. Derived components whose inputs are themselves derived are always computed
+//! after their inputs.
+
+use alloc::{vec, vec::Vec};
+use core::{any::TypeId, marker::PhantomData};
+use variadics_please::all_tuples;
+
+use crate::{
+    component::{Component, Mutable},
+    entity::Entity,
+    query::{Changed, Has, Or, QueryFilter, QueryItem, ReadOnlyQueryData, With, Without},
+    schedule::{IntoScheduleConfigs, ScheduleConfigs, SystemSet},
+    system::{Commands, Query, ScheduleSystem},
+};
+
+/// The system set containing the system of every [derived component](self).
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DerivedComponentSystems;
+
+/// The system set containing the system that computes a single [derived component](self).
+///
+/// Use [`DerivedComponentSet::of`] to order systems relative to a specific derived component.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DerivedComponentSet(TypeId);
+
+impl DerivedComponentSet {
+    /// Returns the set containing the system that computes the derived component `T`.
+    pub fn of<T: Component>() -> Self {
+        Self(TypeId::of::<T>())
+    }
+}
+
+/// A set of input components that a [derived component](self) is computed from.
+///
+/// This is implemented for tuples of up to 15 [`Component`] types.
+pub trait DerivedInputs: 'static {
+    /// The read-only query data passed to the compute function.
+    type Data: ReadOnlyQueryData;
+    /// Matches entities where at least one of the inputs was added or changed.
+    type Changed: QueryFilter;
+    /// Matches entities that are missing at least one of the inputs.
+    type Missing: QueryFilter;
+
+    /// Returns the [`DerivedComponentSet`] of each input, so derived inputs are computed first.
+    fn sets() -> Vec<DerivedComponentSet>;
+}
+
+macro_rules! impl_derived_inputs {
+    ($(#[$meta:meta])* $($name: ident),*) => {
+        $(#[$meta])*
+        impl<$($name: Component),*> DerivedInputs for ($($name,)*) {
+            type Data = ($(&'static $name,)*);
+            type Changed = Or<($(Changed<$name>,)*)>;
+            type Missing = Or<($(Without<$name>,)*)>;
+
+            fn sets() -> Vec<DerivedComponentSet> {
+                vec![$(DerivedComponentSet::of::<$name>()),*]
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_derived_inputs,
+    1,
+    15,
+    C
+);
+
+/// Marks the entities whose `T` was inserted by its [derived component](self) system, so that values
+/// inserted by other means aren't removed when an input is missing.
+#[derive(Component)]
+struct Derived<T: Component>(PhantomData<T>);
+
+impl<T: Component> Default for Derived<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Returns the system that keeps the derived component `T` up to date, computing it from the
+/// inputs `I` with `compute`.
+///
+/// See the [module docs](self) for more information.
+///
+/// # Panics
+///
+/// Running the system panics if `T` is one of its own inputs.
+pub fn derived_component<T, I>(
+    compute: impl Fn(QueryItem<'_, I::Data>) -> T + Send + Sync + 'static,
+) -> ScheduleConfigs<ScheduleSystem>
+where
+    T: Component<Mutability = Mutable>,
+    I: DerivedInputs,
+{
+    let system = move |mut commands: Commands,
+                       mut outdated: Query<
+        (Entity, I::Data, Option<&mut T>, Has<Derived<T>>),
+        Or<(I::Changed, Without<T>)>,
+    >,
+                       stale: Query<Entity, (With<Derived<T>>, I::Missing)>| {
+        for (entity, inputs, output, derived) in &mut outdated {
+            let value = compute(inputs);
+            match output {
+                Some(mut output) => {
+                    *output = value;
+                    if !derived {
+                        commands.entity(entity).try_insert(Derived::<T>::default());
+                    }
+                }
+                None => {
+                    commands
+                        .entity(entity)
+                        .try_insert((value, Derived::<T>::default()));
+                }
+            }
+        }
+        for entity in &stale {
+            commands.entity(entity).try_remove::<(T, Derived<T>)>();
+        }
+    };
+
+    I::sets().into_iter().fold(
+        system
+            .in_set(DerivedComponentSystems)
+            .in_set(DerivedComponentSet::of::<T>()),
+        IntoScheduleConfigs::after,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Schedule, World},
+        schedule::{LogLevel, ScheduleBuildSettings},
+    };
+
+    #[derive(Component)]
+    struct Stats(u32);
+
+    #[derive(Component)]
+    struct Buffs(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Speed(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct DoubleSpeed(u32);
+
+    #[test]
+    fn recompute_changed_inputs() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        // Registered first so the ordering comes from the sets rather than insertion order.
+        schedule.add_systems(derived_component::<DoubleSpeed, (Speed,)>(|(speed,)| {
+            DoubleSpeed(speed.0 * 2)
+        }));
+        schedule.add_systems(derived_component::<Speed, (Stats, Buffs)>(
+            |(stats, buffs)| Speed(stats.0 + buffs.0),
+        ));
+        schedule.set_build_settings(ScheduleBuildSettings {
+            ambiguity_detection: LogLevel::Error,
+            ..Default::default()
+        });
+
+        let a = world.spawn((Stats(1), Buffs(2))).id();
+        let b = world.spawn((Stats(10), Buffs(20))).id();
+        let c = world.spawn(Stats(5)).id();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Speed>(a), Some(&Speed(3)));
+        assert_eq!(world.get::<DoubleSpeed>(a), Some(&DoubleSpeed(6)));
+        assert_eq!(world.get::<Speed>(b), Some(&Speed(30)));
+        assert_eq!(world.get::<Speed>(c), None);
+
+        world.get_mut::<Buffs>(a).unwrap().0 = 4;
+        // Only `a` has changed inputs, so a manual edit to `b` survives.
+        world.get_mut::<Speed>(b).unwrap().0 = 0;
+        world.entity_mut(c).insert(Buffs(1));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Speed>(a), Some(&Speed(5)));
+        assert_eq!(world.get::<DoubleSpeed>(a), Some(&DoubleSpeed(10)));
+        assert_eq!(world.get::<Speed>(b), Some(&Speed(0)));
+        assert_eq!(world.get::<DoubleSpeed>(b), Some(&DoubleSpeed(0)));
+        assert_eq!(world.get::<Speed>(c), Some(&Speed(6)));
+
+        world.entity_mut(a).remove::<Buffs>();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Speed>(a), None);
+        assert_eq!(world.get::<DoubleSpeed>(a), None);
+    }
+
+    #[test]
+    fn keep_inserted_values() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(derived_component::<Speed, (Stats, Buffs)>(
+            |(stats, buffs)| Speed(stats.0 + buffs.0),
+        ));
+
+        // Entities without the inputs keep the values inserted on them.
+        let a = world.spawn(Speed(7)).id();
+        let b = world.spawn((Stats(1), Speed(7))).id();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Speed>(a), Some(&Speed(7)));
+        assert_eq!(world.get::<Speed>(b), Some(&Speed(7)));
+
+        // Once computed, the value is removed along with an input.
+        world.entity_mut(b).insert(Buffs(2));
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Speed>(b), Some(&Speed(3)));
+        world.entity_mut(b).remove::<Stats>();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Speed>(b), None);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "&mut bevy_ecs::derived::tests::Speed conflicts with a previous access in this query."
+    )]
+    fn derived_from_itself() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(derived_component::<Speed, (Speed,)>(|(speed,)| {
+            Speed(speed.0)
+        }));
+        schedule.run(&mut world);
+    }
+}
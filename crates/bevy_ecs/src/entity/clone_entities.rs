@@ -8,7 +8,10 @@ use crate::{
     archetype::Archetype,
     bundle::Bundle,
     component::{Component, ComponentCloneBehavior, ComponentCloneFn, ComponentId, ComponentInfo},
-    entity::{hash_map::EntityHashMap, Entities, Entity, EntityMapper},
+    entity::{
+        hash_map::EntityHashMap, hash_set::EntityHashSet, Entities, Entity, EntityMapper,
+        SceneEntityMapper,
+    },
     query::DebugCheckedUnwrap,
     relationship::RelationshipHookMode,
     world::World,
@@ -157,6 +160,14 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         self.entity_cloner.linked_cloning
     }
 
+    /// Returns true if the [`EntityCloner`] is configured to also clone the entities of relationships without
+    /// [`RelationshipTarget::LINKED_SPAWN`](crate::relationship::RelationshipTarget::LINKED_SPAWN) when
+    /// [linked cloning](Self::linked_cloning) is enabled.
+    #[inline]
+    pub fn follow_all_relationships(&self) -> bool {
+        self.entity_cloner.follow_all_relationships
+    }
+
     /// Returns this context's [`EntityMapper`].
     pub fn entity_mapper(&mut self) -> &mut dyn EntityMapper {
         self.mapper
@@ -267,7 +278,12 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
     }
 
     /// Queues the `entity` to be cloned by the current [`EntityCloner`]
+    ///
+    /// Entities that were already cloned or queued by the current clone operation are skipped.
     pub fn queue_entity_clone(&mut self, entity: Entity) {
+        if !self.entity_cloner.visited.insert(entity) {
+            return;
+        }
         let target = self.entities.reserve_entity();
         self.mapper.set_mapped(entity, target);
         self.entity_cloner.clone_queue.push_back(entity);
@@ -345,8 +361,11 @@ pub struct EntityCloner {
     clone_behavior_overrides: HashMap<ComponentId, ComponentCloneBehavior>,
     move_components: bool,
     linked_cloning: bool,
+    follow_all_relationships: bool,
     default_clone_fn: ComponentCloneFn,
     clone_queue: VecDeque<Entity>,
+    /// The entities cloned or queued by the current clone operation.
+    visited: EntityHashSet,
     deferred_commands: VecDeque<Box<dyn FnOnce(&mut World, &mut dyn EntityMapper)>>,
 }
 
@@ -356,11 +375,13 @@ impl Default for EntityCloner {
             filter_allows_components: false,
             move_components: false,
             linked_cloning: false,
+            follow_all_relationships: false,
             default_clone_fn: ComponentCloneBehavior::global_default_fn(),
             filter: Default::default(),
             filter_required: Default::default(),
             clone_behavior_overrides: Default::default(),
             clone_queue: Default::default(),
+            visited: Default::default(),
             deferred_commands: Default::default(),
         }
     }
@@ -447,6 +468,14 @@ impl EntityCloner {
         self.linked_cloning
     }
 
+    /// Returns `true` if this cloner is configured to also follow relationships without
+    /// [`RelationshipTarget::LINKED_SPAWN`](crate::relationship::RelationshipTarget::LINKED_SPAWN).
+    /// See [`EntityClonerBuilder::follow_all_relationships`].
+    #[inline]
+    pub fn follow_all_relationships(&self) -> bool {
+        self.follow_all_relationships
+    }
+
     /// Clones and inserts components from the `source` entity into the entity mapped by `mapper` from `source` using the stored configuration.
     fn clone_entity_internal(
         &mut self,
@@ -484,7 +513,7 @@ impl EntityCloner {
             bundle_scratch = BundleScratch::with_capacity(archetype.component_count());
 
             for component in archetype.components() {
-                if !self.is_cloning_allowed(&component, target_archetype) {
+                if !self.is_cloning_allowed(&component, target_archetype, component) {
                     continue;
                 }
 
@@ -586,6 +615,8 @@ impl EntityCloner {
         source: Entity,
         mapper: &mut dyn EntityMapper,
     ) -> Entity {
+        self.visited.clear();
+        self.visited.insert(source);
         // All relationships on the root should have their hooks run
         let target = self.clone_entity_internal(world, source, mapper, RelationshipHookMode::Run);
         let child_hook_insert_mode = if self.linked_cloning {
@@ -608,15 +639,238 @@ impl EntityCloner {
         target
     }
 
+    /// Clones and inserts components from the `source` entity of `source_world` into the `target` entity of
+    /// `target_world` using the stored configuration.
+    ///
+    /// Components are matched between the two worlds by [`TypeId`], and registered in `target_world` if needed.
+    /// Components without a Rust type, such as those registered with a dynamic [`ComponentDescriptor`](crate::component::ComponentDescriptor),
+    /// are skipped.
+    /// Filters and clone behavior overrides use the [`ComponentId`]s of `source_world`, which is the world
+    /// this cloner should be built with.
+    ///
+    /// Entity references are remapped with [`MapEntities`](crate::entity::MapEntities): references to entities
+    /// cloned by this call point to their clones, and all other references point to entities that are guaranteed
+    /// to not exist in `target_world`. If this [`EntityCloner`] has [`EntityCloner::linked_cloning`], then the
+    /// entities related through [`RelationshipTarget::LINKED_SPAWN`](crate::relationship::RelationshipTarget::LINKED_SPAWN)
+    /// relationships, such as its [`Children`](crate::hierarchy::Children), are copied as well. Entities related through
+    /// other relationships are only copied with [`EntityClonerBuilder::follow_all_relationships`].
+    ///
+    /// [`EntityClonerBuilder::move_components`] does not apply here, the source world is never modified.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::entity::EntityCloner;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Label(&'static str);
+    ///
+    /// let mut world = World::new();
+    /// let root = world.spawn(Label("root")).with_child(Label("child")).id();
+    ///
+    /// let mut preview = World::new();
+    /// let copy = preview.spawn_empty().id();
+    /// let mut builder = EntityCloner::build(&mut world);
+    /// builder.linked_cloning(true);
+    /// let mut cloner = builder.finish();
+    /// cloner.clone_entity_to_world(&world, root, &mut preview, copy);
+    ///
+    /// assert_eq!(preview.get::<Label>(copy), Some(&Label("root")));
+    /// let child = preview.get::<Children>(copy).unwrap()[0];
+    /// assert_eq!(preview.get::<Label>(child), Some(&Label("child")));
+    /// ```
+    #[track_caller]
+    pub fn clone_entity_to_world(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        target: Entity,
+    ) {
+        let mut map = EntityHashMap::<Entity>::new();
+        map.set_mapped(source, target);
+        SceneEntityMapper::world_scope(&mut map, target_world, |target_world, mapper| {
+            self.clone_entity_to_world_mapped(source_world, source, target_world, mapper);
+        });
+    }
+
+    /// Clones the `source` entity of `source_world` into a newly spawned entity of `target_world` using the
+    /// stored configuration. See [`EntityCloner::clone_entity_to_world`] for details.
+    #[track_caller]
+    pub fn spawn_clone_to_world(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+    ) -> Entity {
+        let target = target_world.spawn_empty().id();
+        self.clone_entity_to_world(source_world, source, target_world, target);
+        target
+    }
+
+    /// Clones the `source` entity of `source_world` into whatever entity of `target_world` `mapper` chooses for it.
+    /// See [`EntityCloner::clone_entity_to_world`] for details.
+    #[track_caller]
+    pub fn clone_entity_to_world_mapped(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        mapper: &mut dyn EntityMapper,
+    ) -> Entity {
+        self.visited.clear();
+        self.visited.insert(source);
+        let target = self.clone_entity_to_world_internal(
+            source_world,
+            source,
+            target_world,
+            mapper,
+            RelationshipHookMode::Run,
+        );
+        let child_hook_insert_mode = if self.linked_cloning {
+            RelationshipHookMode::RunIfNotLinked
+        } else {
+            RelationshipHookMode::Run
+        };
+        while let Some(queued) = self.clone_queue.pop_front() {
+            self.clone_entity_to_world_internal(
+                source_world,
+                queued,
+                target_world,
+                mapper,
+                child_hook_insert_mode,
+            );
+        }
+        target
+    }
+
+    /// Like [`EntityCloner::clone_entity_internal`], but reads from `source_world` and writes to `target_world`.
+    fn clone_entity_to_world_internal(
+        &mut self,
+        source_world: &World,
+        source: Entity,
+        target_world: &mut World,
+        mapper: &mut dyn EntityMapper,
+        relationship_hook_insert_mode: RelationshipHookMode,
+    ) -> Entity {
+        let target = mapper.get_mapped(source);
+        let source_entity = source_world
+            .get_entity(source)
+            .expect("Source entity must exist");
+
+        #[cfg(feature = "bevy_reflect")]
+        let app_registry = source_world
+            .get_resource::<crate::reflect::AppTypeRegistry>()
+            .or_else(|| target_world.get_resource::<crate::reflect::AppTypeRegistry>())
+            .cloned();
+        #[cfg(not(feature = "bevy_reflect"))]
+        let app_registry = Option::<()>::None;
+
+        // Components have to be registered in the target world before it can be borrowed by the clone context.
+        let components: Vec<(ComponentId, ComponentId)> = source_entity
+            .archetype()
+            .components()
+            .filter_map(|component| {
+                let info = source_world.components().get_info(component)?;
+                let target_component = match target_world.components().get_valid_id(info.type_id()?)
+                {
+                    Some(target_component) => target_component,
+                    None => info.register_in(target_world)?,
+                };
+                Some((component, target_component))
+            })
+            .collect();
+
+        let bundle_scratch_allocator = Bump::new();
+        let mut bundle_scratch = BundleScratch::with_capacity(components.len());
+        {
+            let target_world = &*target_world;
+            let target_archetype = (!self.filter_required.is_empty()).then(|| {
+                let location = target_world
+                    .entities()
+                    .get(target)
+                    .expect("Target entity must exist");
+                &target_world.archetypes()[location.archetype_id]
+            });
+
+            for (component, target_component) in components {
+                if !self.is_cloning_allowed(&component, target_archetype, target_component) {
+                    continue;
+                }
+
+                let handler = match self.clone_behavior_overrides.get(&component) {
+                    Some(clone_behavior) => clone_behavior.resolve(self.default_clone_fn),
+                    None => source_world
+                        .components()
+                        .get_info(component)
+                        .map(|info| info.clone_behavior().resolve(self.default_clone_fn))
+                        .unwrap_or(self.default_clone_fn),
+                };
+
+                // SAFETY: This component exists because it is present on the archetype.
+                let info = unsafe { source_world.components().get_info_unchecked(component) };
+                // SAFETY: `target_component` was looked up or registered in `target_world` above.
+                let target_info = unsafe {
+                    target_world
+                        .components()
+                        .get_info_unchecked(target_component)
+                };
+
+                let source_component = SourceComponent {
+                    info,
+                    // `component` is from `source_entity`'s archetype
+                    ptr: source_entity.get_by_id(component).unwrap(),
+                };
+
+                // SAFETY:
+                // - `target_info` and `target_component` are from the same world
+                // - `target_component` represents the same type as `component`, matched by `TypeId`
+                let mut ctx = unsafe {
+                    ComponentCloneCtx::new(
+                        target_component,
+                        source,
+                        target,
+                        &bundle_scratch_allocator,
+                        &mut bundle_scratch,
+                        target_world.entities(),
+                        target_info,
+                        self,
+                        mapper,
+                        app_registry.as_ref(),
+                    )
+                };
+
+                (handler)(&source_component, &mut ctx);
+            }
+        }
+
+        target_world.flush();
+
+        for deferred in self.deferred_commands.drain(..) {
+            (deferred)(target_world, mapper);
+        }
+
+        if !target_world.entities.contains(target) {
+            panic!("Target entity does not exist");
+        }
+
+        // SAFETY:
+        // - All `component_ids` are from `target_world`
+        // - All `component_data_ptrs` are valid types represented by `component_ids`
+        unsafe { bundle_scratch.write(target_world, target, relationship_hook_insert_mode) };
+        target
+    }
+
+    /// `target_component` is the id of `component` in the world `target_archetype` belongs to.
     fn is_cloning_allowed(
         &self,
         component: &ComponentId,
         target_archetype: Option<&Archetype>,
+        target_component: ComponentId,
     ) -> bool {
         if self.filter_allows_components {
             self.filter.contains(component)
                 || target_archetype.is_some_and(|archetype| {
-                    !archetype.contains(*component) && self.filter_required.contains(component)
+                    !archetype.contains(target_component)
+                        && self.filter_required.contains(component)
                 })
         } else {
             !self.filter.contains(component) && !self.filter_required.contains(component)
@@ -637,6 +891,19 @@ impl<'w> EntityClonerBuilder<'w> {
         self.entity_cloner.clone_entity(self.world, source, target);
         self
     }
+    /// Internally calls [`EntityCloner::clone_entity_to_world`], cloning `source` from the builder's [`World`]
+    /// into the `target` entity of `target_world`.
+    pub fn clone_entity_to_world(
+        &mut self,
+        source: Entity,
+        target_world: &mut World,
+        target: Entity,
+    ) -> &mut Self {
+        self.entity_cloner
+            .clone_entity_to_world(self.world, source, target_world, target);
+        self
+    }
+
     /// Finishes configuring [`EntityCloner`] returns it.
     pub fn finish(self) -> EntityCloner {
         self.entity_cloner
@@ -811,6 +1078,20 @@ impl<'w> EntityClonerBuilder<'w> {
         self
     }
 
+    /// When true, [linked cloning](Self::linked_cloning) will also clone the entities in
+    /// [`RelationshipTarget`](crate::relationship::RelationshipTarget)s without
+    /// [`RelationshipTarget::LINKED_SPAWN`](crate::relationship::RelationshipTarget::LINKED_SPAWN),
+    /// so that every entity related to a cloned entity is cloned as well. Has no effect unless linked cloning is enabled.
+    ///
+    /// The clones of these entities are added to the clones of their relationship targets by the relationship hooks,
+    /// and each entity is only cloned once, even if the relationships form a cycle. A relationship pointing from an
+    /// entity to another one that is only reached later, such as the one closing a cycle, is not remapped to the
+    /// clone of its target, since that target was not known to be cloned yet.
+    pub fn follow_all_relationships(&mut self, follow_all_relationships: bool) -> &mut Self {
+        self.entity_cloner.follow_all_relationships = follow_all_relationships;
+        self
+    }
+
     /// Helper function that allows a component through the filter.
     fn filter_allow(&mut self, id: ComponentId) {
         if self.entity_cloner.filter_allows_components {
@@ -1451,4 +1732,126 @@ mod tests {
 
         assert_eq!(world.entity(e_clone2).get::<B>(), Some(&B(2)));
     }
+
+    #[test]
+    fn clone_hierarchy_to_world() {
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct A(u32);
+
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct Points(#[entities] Entity);
+
+        #[derive(Component, Reflect, PartialEq, Debug)]
+        #[reflect(Component)]
+        struct R(u32);
+
+        let mut source_world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<R>();
+        source_world.insert_resource(registry);
+
+        let outside = source_world.spawn_empty().id();
+        let root = source_world.spawn((A(1), R(2))).id();
+        let child = source_world.spawn((A(3), ChildOf(root), Points(root))).id();
+        let grandchild = source_world.spawn((ChildOf(child), Points(outside))).id();
+
+        let mut target_world = World::new();
+        // Register in a different order so the component ids differ between the two worlds.
+        target_world.register_component::<Points>();
+        let existing = target_world.spawn(A(0)).id();
+
+        let mut builder = EntityCloner::build(&mut source_world);
+        builder.linked_cloning(true);
+        let copy = builder
+            .finish()
+            .spawn_clone_to_world(&source_world, root, &mut target_world);
+
+        assert_eq!(target_world.get::<A>(copy), Some(&A(1)));
+        assert_eq!(target_world.get::<R>(copy), Some(&R(2)));
+        let children = target_world.get::<Children>(copy).unwrap();
+        assert_eq!(children.len(), 1);
+        let child_copy = children[0];
+        assert_eq!(target_world.get::<A>(child_copy), Some(&A(3)));
+        assert_eq!(target_world.get::<Points>(child_copy), Some(&Points(copy)));
+        assert_eq!(
+            target_world.get::<ChildOf>(child_copy),
+            Some(&ChildOf(copy))
+        );
+
+        let grandchild_copy = target_world.get::<Children>(child_copy).unwrap()[0];
+        let outside_copy = target_world.get::<Points>(grandchild_copy).unwrap().0;
+        assert!(target_world.get_entity(outside_copy).is_err());
+        assert_eq!(target_world.get::<A>(existing), Some(&A(0)));
+
+        // The source world is left untouched.
+        assert_eq!(
+            source_world.get::<Children>(child).unwrap()[..],
+            [grandchild]
+        );
+        assert_eq!(source_world.entities().len(), 4);
+    }
+
+    #[test]
+    fn clone_to_world_with_filter() {
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct A;
+
+        #[derive(Component, Clone, PartialEq, Debug)]
+        struct B;
+
+        let mut source_world = World::new();
+        let source = source_world.spawn((A, B)).id();
+
+        let mut target_world = World::new();
+        let target = target_world.spawn_empty().id();
+        EntityCloner::build(&mut source_world)
+            .deny::<B>()
+            .clone_entity_to_world(source, &mut target_world, target);
+
+        assert!(target_world.entity(target).contains::<A>());
+        assert!(!target_world.entity(target).contains::<B>());
+    }
+
+    #[test]
+    fn clone_to_world_following_all_relationships() {
+        #[derive(Component, Clone)]
+        #[relationship(relationship_target = LikedBy)]
+        struct Likes(Entity);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = Likes)]
+        struct LikedBy(Vec<Entity>);
+
+        let mut source_world = World::new();
+        let root = source_world.spawn_empty().id();
+        let fan = source_world.spawn(Likes(root)).id();
+        let fan_of_fan = source_world.spawn(Likes(fan)).id();
+        // Relationships can form cycles, each entity is still only cloned once.
+        source_world.entity_mut(root).insert(Likes(fan_of_fan));
+
+        let mut target_world = World::new();
+        let mut builder = EntityCloner::build(&mut source_world);
+        builder.linked_cloning(true);
+        let mut cloner = builder.finish();
+        let copy = cloner.spawn_clone_to_world(&source_world, root, &mut target_world);
+        assert_eq!(target_world.entities().len(), 1);
+        assert!(target_world
+            .get::<LikedBy>(copy)
+            .is_none_or(|liked_by| liked_by.0.is_empty()));
+
+        let mut target_world = World::new();
+        let mut builder = EntityCloner::build(&mut source_world);
+        builder.linked_cloning(true).follow_all_relationships(true);
+        let mut cloner = builder.finish();
+        let copy = cloner.spawn_clone_to_world(&source_world, root, &mut target_world);
+        assert_eq!(target_world.entities().len(), 3);
+        let fan_copy = target_world.get::<LikedBy>(copy).unwrap().0.clone();
+        assert_eq!(fan_copy.len(), 1);
+        let fan_of_fan_copy = target_world.get::<LikedBy>(fan_copy[0]).unwrap().0.clone();
+        assert_eq!(fan_of_fan_copy.len(), 1);
+        assert_eq!(
+            target_world.get::<Likes>(fan_of_fan_copy[0]).unwrap().0,
+            fan_copy[0]
+        );
+    }
 }
@@ -1222,6 +1222,58 @@ impl Entities {
         }
     }
 
+    /// Captures the generation of every [`EntityRow`] and the order of the freelist, so that
+    /// the allocator can later be returned to this exact state with [`Entities::restore`].
+    ///
+    /// Must not be called while reserved entities are awaiting `flush()`.
+    pub fn snapshot(&mut self) -> EntitiesSnapshot {
+        self.verify_flushed();
+        EntitiesSnapshot {
+            generations: self.meta.iter().map(|meta| meta.generation).collect(),
+            pending: self.pending.clone(),
+        }
+    }
+
+    /// Returns the allocator to the state captured by [`Entities::snapshot`].
+    ///
+    /// Rows that are in use in both the current state and the snapshot keep their location.
+    /// Rows that are alive in the snapshot but are not currently in use are left allocated
+    /// without a location, ready to be spawned into.
+    ///
+    /// Every entity that currently has a location must also be alive in the snapshot.
+    /// Must not be called while reserved entities are awaiting `flush()`.
+    #[cfg_attr(
+        not(feature = "bevy_reflect"),
+        expect(dead_code, reason = "only used by `WorldSnapshot`")
+    )]
+    pub(crate) fn restore(&mut self, snapshot: &EntitiesSnapshot) {
+        self.verify_flushed();
+
+        self.meta.truncate(snapshot.generations.len());
+        for (meta, &generation) in self.meta.iter_mut().zip(&snapshot.generations) {
+            if meta.generation != generation {
+                debug_assert!(
+                    meta.location.is_none(),
+                    "restored an entity row that is still in use"
+                );
+                meta.generation = generation;
+                meta.location = None;
+            }
+        }
+        let restored = self.meta.len();
+        self.meta.extend(
+            snapshot.generations[restored..]
+                .iter()
+                .map(|&generation| EntityMeta {
+                    generation,
+                    ..EntityMeta::EMPTY
+                }),
+        );
+
+        self.pending.clone_from(&snapshot.pending);
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+    }
+
     /// Constructs a message explaining why an entity does not exist, if known.
     pub(crate) fn entity_does_not_exist_error_details(
         &self,
@@ -1275,6 +1327,24 @@ impl fmt::Display for EntityDoesNotExistDetails {
     }
 }
 
+/// The allocation state of [`Entities`], captured by [`Entities::snapshot`].
+///
+/// This records which [`EntityGeneration`] every [`EntityRow`] is on and the order in which
+/// freed rows will be handed out again, which is enough to make future allocations
+/// reproduce the same [`Entity`] ids.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EntitiesSnapshot {
+    generations: Vec<EntityGeneration>,
+    pending: Vec<EntityRow>,
+}
+
+impl EntitiesSnapshot {
+    /// The number of entity rows that had ever been allocated when this snapshot was taken.
+    pub fn total_count(&self) -> usize {
+        self.generations.len()
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 struct EntityMeta {
     /// The current [`EntityGeneration`] of the [`EntityRow`].
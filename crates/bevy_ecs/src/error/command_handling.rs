@@ -7,6 +7,9 @@ use crate::{
     world::{error::EntityMutableFetchError, World},
 };
 
+#[cfg(feature = "bevy_reflect")]
+use crate::system::command::TransactionState;
+
 use super::{BevyError, ErrorContext, ErrorHandler};
 
 /// Takes a [`Command`] that potentially returns a Result and uses a given error handler function to convert it into
@@ -18,6 +21,11 @@ pub trait HandleError<Out = ()>: Send + 'static {
     /// Takes a [`Command`] that returns a Result and uses the default error handler function to convert it into
     /// a [`Command`] that internally handles an error if it occurs and returns `()`.
     fn handle_error(self) -> impl Command;
+    /// Takes a [`Command`] that returns a Result and converts it into a [`Command`] that records an error
+    /// in the state of the [transaction](crate::system::Commands::transaction) it is part of, and returns `()`.
+    #[cfg(feature = "bevy_reflect")]
+    #[doc(hidden)]
+    fn handle_error_in(self, transaction: TransactionState) -> impl Command;
 }
 
 impl<C, T, E> HandleError<Result<T, E>> for C
@@ -48,6 +56,15 @@ where
             ),
         }
     }
+
+    #[cfg(feature = "bevy_reflect")]
+    fn handle_error_in(self, transaction: TransactionState) -> impl Command {
+        move |world: &mut World| {
+            if let Err(err) = self.apply(world) {
+                transaction.fail(err.into());
+            }
+        }
+    }
 }
 
 impl<C> HandleError<Never> for C
@@ -66,6 +83,14 @@ where
             self.apply(world);
         }
     }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    fn handle_error_in(self, _transaction: TransactionState) -> impl Command {
+        move |world: &mut World| {
+            self.apply(world);
+        }
+    }
 }
 
 impl<C> HandleError for C
@@ -80,6 +105,12 @@ where
     fn handle_error(self) -> impl Command {
         self
     }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    fn handle_error_in(self, _transaction: TransactionState) -> impl Command {
+        self
+    }
 }
 
 /// Passes in a specific entity to an [`EntityCommand`], resulting in a [`Command`] that
@@ -1,9 +1,13 @@
-use alloc::vec::Vec;
+use alloc::{collections::VecDeque, vec::Vec};
 use bevy_ecs::{
     change_detection::MaybeLocation,
     event::{Event, EventCursor, EventId, EventInstance},
     resource::Resource,
 };
+use bevy_platform::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex, PoisonError, Weak,
+};
 use core::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
@@ -77,6 +81,10 @@ use {
 ///
 /// The buffers in [`Events`] will grow indefinitely if [`update`](Events::update) is never called.
 ///
+/// How long events are kept around can be configured with an [`EventRetention`] policy,
+/// see [`Events::with_retention`]. Events that are dropped before every [`EventReader`] that
+/// has read from this collection could see them are counted by [`Events::dropped_unread`].
+///
 /// An alternative call pattern would be to call [`update`](Events::update)
 /// manually across frames to control when events are cleared.
 /// This complicates consumption and risks ever-expanding memory usage if not cleaned up,
@@ -98,6 +106,16 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     pub(crate) events_b: EventSequence<E>,
     pub(crate) event_count: usize,
+    retention: EventRetention,
+    /// The ids of the first event sent during each of the updates still held in `events_a`.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    update_starts: VecDeque<usize>,
+    /// The positions of all live cursors that have read from these events.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    cursors: Mutex<Vec<Weak<AtomicUsize>>>,
+    dropped_unread: usize,
+    /// Whether dropping unread events was already reported.
+    warned_dropped_unread: bool,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -107,11 +125,164 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            retention: Default::default(),
+            update_starts: Default::default(),
+            cursors: Default::default(),
+            dropped_unread: Default::default(),
+            warned_dropped_unread: Default::default(),
         }
     }
 }
 
+/// Controls how long [`Events`] are kept around before being dropped by [`Events::update`].
+///
+/// The policy for an event type can be chosen when registering it with
+/// [`App::add_event_with_retention`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event_with_retention),
+/// or changed later with [`Events::set_retention`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash)
+)]
+pub enum EventRetention {
+    /// Events are dropped by the `n`th call to [`Events::update`] after they were sent.
+    ///
+    /// `Updates(1)` only keeps events until the end of the update they were sent in,
+    /// and `Updates(0)` behaves the same way.
+    /// The default is `Updates(2)`, the classic double buffer.
+    Updates(usize),
+    /// Keeps the `n` most recent events, regardless of how many updates ago they were sent.
+    ///
+    /// Events sent since the previous update are always kept, even if there are more than `n` of them.
+    Count(usize),
+    /// Events are kept until every live [`EventCursor`] that has read from the [`Events`]
+    /// has read past them, and at least as long as with the default policy.
+    ///
+    /// This includes the cursors of [`EventReader`](super::EventReader)s in systems that
+    /// are not running anymore, which can make events pile up indefinitely.
+    UntilConsumed,
+}
+
+impl Default for EventRetention {
+    fn default() -> Self {
+        Self::Updates(2)
+    }
+}
+
 impl<E: Event> Events<E> {
+    /// Creates an empty event collection that keeps events according to `retention`.
+    pub fn with_retention(retention: EventRetention) -> Self {
+        Self {
+            retention,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the [`EventRetention`] policy of this collection.
+    pub fn retention(&self) -> EventRetention {
+        self.retention
+    }
+
+    /// Changes the [`EventRetention`] policy of this collection.
+    /// The new policy is applied from the next call to [`Events::update`].
+    pub fn set_retention(&mut self, retention: EventRetention) {
+        self.retention = retention;
+    }
+
+    /// Returns the total number of events that were dropped by [`Events::update`]
+    /// before every cursor that has read from this collection could read them.
+    ///
+    /// Cursors only count once they have read at least once,
+    /// so this does not include events that were sent before any reader existed.
+    /// With a non-default [`EventRetention`], a warning is logged the first time events are dropped.
+    pub fn dropped_unread(&self) -> usize {
+        self.dropped_unread
+    }
+
+    /// Returns a shared position that an [`EventCursor`] must keep up to date
+    /// for these events to know which events it has consumed.
+    pub(super) fn register_cursor(&self, last_event_count: usize) -> Arc<AtomicUsize> {
+        let consumed = Arc::new(AtomicUsize::new(last_event_count));
+        self.cursors
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::downgrade(&consumed));
+        consumed
+    }
+
+    /// Returns the position of the live cursor that has consumed the fewest events,
+    /// or `None` if no registered cursor is alive.
+    fn min_consumed(&mut self) -> Option<usize> {
+        let cursors = self
+            .cursors
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut min = None;
+        cursors.retain(|cursor| {
+            let Some(consumed) = cursor.upgrade() else {
+                return false;
+            };
+            let consumed = consumed.load(Ordering::Relaxed);
+            min = Some(min.map_or(consumed, |min: usize| min.min(consumed)));
+            true
+        });
+        min
+    }
+
+    /// Returns the id of the oldest event to keep after the current update,
+    /// accounting for the events that are dropped before being consumed.
+    ///
+    /// Expects the start of the update that is ending to already be pushed to `update_starts`.
+    fn retain_from(&mut self) -> usize {
+        let oldest_updates = |starts: &VecDeque<usize>, updates: usize, event_count: usize| {
+            // Keep the events of the `updates - 1` most recent updates, including the one ending now.
+            match updates.checked_sub(1) {
+                None | Some(0) => event_count,
+                Some(kept) => starts
+                    .len()
+                    .checked_sub(kept)
+                    .map_or(starts[0], |index| starts[index]),
+            }
+        };
+
+        let oldest = self.events_a.start_event_count;
+        let min_consumed = self.min_consumed();
+        let retain_from = match self.retention {
+            EventRetention::Updates(updates) => {
+                oldest_updates(&self.update_starts, updates, self.event_count)
+            }
+            EventRetention::Count(count) => self
+                .event_count
+                .saturating_sub(count)
+                .min(self.events_b.start_event_count),
+            EventRetention::UntilConsumed => {
+                oldest_updates(&self.update_starts, 2, self.event_count)
+                    .min(min_consumed.unwrap_or(usize::MAX))
+            }
+        }
+        .clamp(oldest, self.event_count);
+
+        if let Some(min_consumed) = min_consumed {
+            let dropped_unread = retain_from.saturating_sub(min_consumed.max(oldest));
+            if dropped_unread > 0 {
+                self.dropped_unread += dropped_unread;
+                if self.retention != EventRetention::default() && !self.warned_dropped_unread {
+                    self.warned_dropped_unread = true;
+                    log::warn!(
+                        "{} {} events were dropped before all of their readers could read them. \
+                        Consider using a longer `EventRetention` or reading them more often. \
+                        Further drops are only counted by `Events::dropped_unread`.",
+                        dropped_unread,
+                        core::any::type_name::<E>(),
+                    );
+                }
+            }
+        }
+
+        retain_from
+    }
+
     /// Returns the index of the oldest event stored in the event buffer.
     pub fn oldest_event_count(&self) -> usize {
         self.events_a.start_event_count
@@ -182,35 +353,61 @@ impl<E: Event> Events<E> {
         }
     }
 
-    /// Swaps the event buffers and clears the oldest event buffer. In general, this should be
-    /// called once per frame/update.
+    /// Drops the events that are too old according to the [`EventRetention`] policy and
+    /// starts a new update. With the default policy, this swaps the event buffers and
+    /// clears the oldest one. In general, this should be called once per frame/update.
     ///
     /// If you need access to the events that were removed, consider using [`Events::update_drain`].
     pub fn update(&mut self) {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        self.events_b.clear();
-        self.events_b.start_event_count = self.event_count;
+        let retain_from = self.begin_update();
+        if retain_from >= self.events_b.start_event_count {
+            core::mem::swap(&mut self.events_a, &mut self.events_b);
+            self.events_b.clear();
+            let dropped = retain_from - self.events_a.start_event_count;
+            self.events_a.drain(..dropped);
+        } else {
+            let dropped = retain_from - self.events_a.start_event_count;
+            self.events_a.drain(..dropped);
+            self.events_a.events.append(&mut self.events_b.events);
+        }
+        self.end_update(retain_from);
         debug_assert_eq!(
             self.events_a.start_event_count + self.events_a.len(),
             self.events_b.start_event_count
         );
     }
 
-    /// Swaps the event buffers and drains the oldest event buffer, returning an iterator
-    /// of all events that were removed. In general, this should be called once per frame/update.
+    /// Like [`Events::update`], but returns an iterator of all events that were removed.
+    /// In general, this should be called once per frame/update.
     ///
     /// If you do not need to take ownership of the removed events, use [`Events::update`] instead.
     #[must_use = "If you do not need the returned events, call .update() instead."]
     pub fn update_drain(&mut self) -> impl Iterator<Item = E> + '_ {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        let iter = self.events_b.events.drain(..);
-        self.events_b.start_event_count = self.event_count;
+        let retain_from = self.begin_update();
+        let dropped = retain_from - self.events_a.start_event_count;
+        self.events_a.events.append(&mut self.events_b.events);
+        self.end_update(retain_from);
         debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
+            self.events_a.start_event_count + self.events_a.len() - dropped,
             self.events_b.start_event_count
         );
 
-        iter.map(|e| e.event)
+        self.events_a.drain(..dropped).map(|e| e.event)
+    }
+
+    fn begin_update(&mut self) -> usize {
+        self.update_starts
+            .push_back(self.events_b.start_event_count);
+        self.retain_from()
+    }
+
+    fn end_update(&mut self, retain_from: usize) {
+        self.events_a.start_event_count = retain_from;
+        self.events_b.start_event_count = self.event_count;
+        self.update_starts.retain(|&start| start > retain_from);
+        if retain_from < self.event_count {
+            self.update_starts.push_front(retain_from);
+        }
     }
 
     #[inline]
@@ -223,6 +420,7 @@ impl<E: Event> Events<E> {
     #[inline]
     pub fn clear(&mut self) {
         self.reset_start_event_count();
+        self.update_starts.clear();
         self.events_a.clear();
         self.events_b.clear();
     }
@@ -242,6 +440,7 @@ impl<E: Event> Events<E> {
     /// Creates a draining iterator that removes all events.
     pub fn drain(&mut self) -> impl Iterator<Item = E> + '_ {
         self.reset_start_event_count();
+        self.update_starts.clear();
 
         // Drain the oldest events first, then the newest
         self.events_a
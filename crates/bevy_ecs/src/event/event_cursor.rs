@@ -3,6 +3,10 @@ use bevy_ecs::event::{
 };
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::{EventMutParIter, EventParIter};
+use bevy_platform::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use core::marker::PhantomData;
 
 /// Stores the state for an [`EventReader`] or [`EventMutator`].
@@ -52,6 +56,12 @@ use core::marker::PhantomData;
 #[derive(Debug)]
 pub struct EventCursor<E: Event> {
     pub(super) last_event_count: usize,
+    /// The position of this cursor as seen by the [`Events`] it reads from,
+    /// set up the first time it reads. It is updated when an iterator is created or runs out,
+    /// rather than for every event. See [`EventRetention::UntilConsumed`].
+    ///
+    /// [`EventRetention::UntilConsumed`]: super::EventRetention::UntilConsumed
+    pub(super) consumed: Option<Arc<AtomicUsize>>,
     pub(super) _marker: PhantomData<E>,
 }
 
@@ -59,6 +69,7 @@ impl<E: Event> Default for EventCursor<E> {
     fn default() -> Self {
         EventCursor {
             last_event_count: 0,
+            consumed: None,
             _marker: Default::default(),
         }
     }
@@ -66,14 +77,48 @@ impl<E: Event> Default for EventCursor<E> {
 
 impl<E: Event> Clone for EventCursor<E> {
     fn clone(&self) -> Self {
+        // The clone reads independently, so it registers its own position when it first reads.
         EventCursor {
             last_event_count: self.last_event_count,
+            consumed: None,
             _marker: PhantomData,
         }
     }
 }
 
 impl<E: Event> EventCursor<E> {
+    /// Registers this cursor with `events`, so that they know which events it has consumed.
+    #[inline]
+    pub(super) fn register(&mut self, events: &Events<E>) {
+        if self.consumed.is_none() {
+            self.consumed = Some(events.register_cursor(self.last_event_count));
+        }
+    }
+
+    /// Marks the next `count` events as read.
+    ///
+    /// This doesn't let the [`Events`] know, call [`publish`](Self::publish) once done reading.
+    /// [`EventReader`](super::EventReader) and [`EventMutator`](super::EventMutator) also publish
+    /// when dropped, which covers iterators that were not exhausted.
+    #[inline]
+    pub(super) fn advance(&mut self, count: usize) {
+        self.last_event_count += count;
+    }
+
+    #[inline]
+    pub(super) fn set_last_event_count(&mut self, last_event_count: usize) {
+        self.last_event_count = last_event_count;
+        self.publish();
+    }
+
+    /// Lets the [`Events`] this cursor reads from know which events it has consumed.
+    #[inline]
+    pub(super) fn publish(&self) {
+        if let Some(consumed) = &self.consumed {
+            consumed.store(self.last_event_count, Ordering::Relaxed);
+        }
+    }
+
     /// See [`EventReader::read`](super::EventReader::read)
     pub fn read<'a>(&'a mut self, events: &'a Events<E>) -> EventIterator<'a, E> {
         self.read_with_id(events).without_id()
@@ -135,6 +180,6 @@ impl<E: Event> EventCursor<E> {
 
     /// See [`EventReader::clear()`](super::EventReader::clear)
     pub fn clear(&mut self, events: &Events<E>) {
-        self.last_event_count = events.event_count;
+        self.set_last_event_count(events.event_count);
     }
 }
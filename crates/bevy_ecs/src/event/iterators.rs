@@ -52,6 +52,7 @@ pub struct EventIteratorWithId<'a, E: Event> {
 impl<'a, E: Event> EventIteratorWithId<'a, E> {
     /// Creates a new iterator that yields any `events` that have not yet been seen by `reader`.
     pub fn new(reader: &'a mut EventCursor<E>, events: &'a Events<E>) -> Self {
+        reader.register(events);
         let a_index = reader
             .last_event_count
             .saturating_sub(events.events_a.start_event_count);
@@ -64,7 +65,8 @@ impl<'a, E: Event> EventIteratorWithId<'a, E> {
         let unread_count = a.len() + b.len();
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, reader.len(events));
-        reader.last_event_count = events.event_count - unread_count;
+        // Also publishes the position reached by the previous iterator, which may not have finished.
+        reader.set_last_event_count(events.event_count - unread_count);
         // Iterate the oldest first, then the newer events
         let chain = a.iter().chain(b.iter());
 
@@ -92,11 +94,14 @@ impl<'a, E: Event> Iterator for EventIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventReader::iter() -> {}", item.1);
-                self.reader.last_event_count += 1;
+                self.reader.advance(1);
                 self.unread -= 1;
                 Some(item)
             }
-            None => None,
+            None => {
+                self.reader.publish();
+                None
+            }
         }
     }
 
@@ -105,7 +110,8 @@ impl<'a, E: Event> Iterator for EventIteratorWithId<'a, E> {
     }
 
     fn count(self) -> usize {
-        self.reader.last_event_count += self.unread;
+        self.reader.advance(self.unread);
+        self.reader.publish();
         self.unread
     }
 
@@ -114,17 +120,19 @@ impl<'a, E: Event> Iterator for EventIteratorWithId<'a, E> {
         Self: Sized,
     {
         let EventInstance { event_id, event } = self.chain.last()?;
-        self.reader.last_event_count += self.unread;
+        self.reader.advance(self.unread);
+        self.reader.publish();
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.reader.last_event_count += n + 1;
+            self.reader.advance(n + 1);
             self.unread -= n + 1;
             Some((event, *event_id))
         } else {
-            self.reader.last_event_count += self.unread;
+            self.reader.advance(self.unread);
+            self.reader.publish();
             self.unread = 0;
             None
         }
@@ -152,6 +160,7 @@ pub struct EventParIter<'a, E: Event> {
 impl<'a, E: Event> EventParIter<'a, E> {
     /// Creates a new parallel iterator over `events` that have not yet been seen by `reader`.
     pub fn new(reader: &'a mut EventCursor<E>, events: &'a Events<E>) -> Self {
+        reader.register(events);
         let a_index = reader
             .last_event_count
             .saturating_sub(events.events_a.start_event_count);
@@ -164,7 +173,7 @@ impl<'a, E: Event> EventParIter<'a, E> {
         let unread_count = a.len() + b.len();
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, reader.len(events));
-        reader.last_event_count = events.event_count - unread_count;
+        reader.set_last_event_count(events.event_count - unread_count);
 
         Self {
             reader,
@@ -243,7 +252,8 @@ impl<'a, E: Event> EventParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.reader.last_event_count += self.unread;
+            self.reader.advance(self.unread);
+            self.reader.publish();
             self.unread = 0;
         }
     }
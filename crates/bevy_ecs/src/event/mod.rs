@@ -13,7 +13,7 @@ mod writer;
 pub(crate) use base::EventInstance;
 pub use base::{Event, EventId};
 pub use bevy_ecs_macros::Event;
-pub use collections::{Events, SendBatchIds};
+pub use collections::{EventRetention, Events, SendBatchIds};
 pub use event_cursor::EventCursor;
 #[cfg(feature = "multi_threaded")]
 pub use iterators::EventParIter;
@@ -623,4 +623,150 @@ mod tests {
         });
         schedule.run(&mut world);
     }
+
+    #[test]
+    fn test_events_retention_updates() {
+        let mut events = Events::<TestEvent>::with_retention(EventRetention::Updates(3));
+        let mut reader = events.get_cursor();
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.update();
+        assert_eq!(events.len(), 2);
+
+        events.update();
+        assert_eq!(reader.missed_events(&events), 1);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 1 }],
+            "events are dropped on the third update after being sent"
+        );
+
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_events_retention_count() {
+        let mut events = Events::<TestEvent>::with_retention(EventRetention::Count(3));
+        let mut reader = events.get_cursor();
+
+        for i in 0..5 {
+            events.send(TestEvent { i });
+            events.update();
+        }
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 2 }, TestEvent { i: 3 }, TestEvent { i: 4 }]
+        );
+
+        events.send_batch((5..10).map(|i| TestEvent { i }));
+        events.update();
+        assert_eq!(
+            events.len(),
+            5,
+            "events from the last update are always kept"
+        );
+        events.update();
+        assert_eq!(events.len(), 3);
+    }
+
+    #[test]
+    fn test_events_retention_until_consumed() {
+        let mut events = Events::<TestEvent>::with_retention(EventRetention::UntilConsumed);
+        let mut fast = events.get_cursor();
+        let mut slow = events.get_cursor();
+        assert!(get_events(&events, &mut slow).is_empty());
+
+        events.send(TestEvent { i: 0 });
+        for _ in 0..5 {
+            fast.read(&events).count();
+            events.update();
+        }
+        assert_eq!(get_events(&events, &mut slow), vec![TestEvent { i: 0 }]);
+        assert_eq!(events.dropped_unread(), 0);
+
+        events.update();
+        assert!(
+            events.is_empty(),
+            "events are dropped once every reader read them"
+        );
+
+        // Readers that are gone don't hold on to events.
+        drop(slow);
+        events.send(TestEvent { i: 1 });
+        fast.read(&events).count();
+        events.update();
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_event_reader_publishes_partial_reads() {
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.insert_resource(Events::<TestEvent>::with_retention(
+            EventRetention::UntilConsumed,
+        ));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut events: EventReader<TestEvent>| {
+            events.read().next();
+        });
+        schedule.run(&mut world);
+
+        world.send_event(TestEvent { i: 0 });
+        world.send_event(TestEvent { i: 1 });
+        schedule.run(&mut world);
+        for _ in 0..3 {
+            world.resource_mut::<Events<TestEvent>>().update();
+        }
+        assert_eq!(
+            world.resource::<Events<TestEvent>>().len(),
+            1,
+            "only the event the reader didn't reach is kept"
+        );
+    }
+
+    #[test]
+    fn test_events_dropped_unread() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_cursor();
+        assert!(get_events(&events, &mut reader).is_empty());
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.update();
+        assert_eq!(events.dropped_unread(), 2);
+
+        events.send(TestEvent { i: 2 });
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 2 }]);
+        events.update();
+        events.update();
+        assert_eq!(events.dropped_unread(), 2);
+    }
+
+    #[test]
+    fn test_event_registry_keeps_retained_events() {
+        use bevy_ecs::{schedule::Schedule, world::World};
+
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(event_update_system);
+        EventRegistry::register_event::<TestEvent>(&mut world);
+        world
+            .resource_mut::<Events<TestEvent>>()
+            .set_retention(EventRetention::Updates(4));
+        world.send_event(TestEvent { i: 0 });
+
+        for _ in 0..3 {
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<Events<TestEvent>>().len(), 1);
+        }
+        schedule.run(&mut world);
+        assert!(world.resource::<Events<TestEvent>>().is_empty());
+    }
 }
@@ -56,6 +56,7 @@ pub struct EventMutIteratorWithId<'a, E: Event> {
 impl<'a, E: Event> EventMutIteratorWithId<'a, E> {
     /// Creates a new iterator that yields any `events` that have not yet been seen by `mutator`.
     pub fn new(mutator: &'a mut EventCursor<E>, events: &'a mut Events<E>) -> Self {
+        mutator.register(events);
         let a_index = mutator
             .last_event_count
             .saturating_sub(events.events_a.start_event_count);
@@ -67,7 +68,8 @@ impl<'a, E: Event> EventMutIteratorWithId<'a, E> {
 
         let unread_count = a.len() + b.len();
 
-        mutator.last_event_count = events.event_count - unread_count;
+        // Also publishes the position reached by the previous iterator, which may not have finished.
+        mutator.set_last_event_count(events.event_count - unread_count);
         // Iterate the oldest first, then the newer events
         let chain = a.iter_mut().chain(b.iter_mut());
 
@@ -95,11 +97,14 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventMutator::iter() -> {}", item.1);
-                self.mutator.last_event_count += 1;
+                self.mutator.advance(1);
                 self.unread -= 1;
                 Some(item)
             }
-            None => None,
+            None => {
+                self.mutator.publish();
+                None
+            }
         }
     }
 
@@ -108,7 +113,8 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
     }
 
     fn count(self) -> usize {
-        self.mutator.last_event_count += self.unread;
+        self.mutator.advance(self.unread);
+        self.mutator.publish();
         self.unread
     }
 
@@ -117,17 +123,19 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
         Self: Sized,
     {
         let EventInstance { event_id, event } = self.chain.last()?;
-        self.mutator.last_event_count += self.unread;
+        self.mutator.advance(self.unread);
+        self.mutator.publish();
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.mutator.last_event_count += n + 1;
+            self.mutator.advance(n + 1);
             self.unread -= n + 1;
             Some((event, *event_id))
         } else {
-            self.mutator.last_event_count += self.unread;
+            self.mutator.advance(self.unread);
+            self.mutator.publish();
             self.unread = 0;
             None
         }
@@ -155,6 +163,7 @@ pub struct EventMutParIter<'a, E: Event> {
 impl<'a, E: Event> EventMutParIter<'a, E> {
     /// Creates a new parallel iterator over `events` that have not yet been seen by `mutator`.
     pub fn new(mutator: &'a mut EventCursor<E>, events: &'a mut Events<E>) -> Self {
+        mutator.register(events);
         let a_index = mutator
             .last_event_count
             .saturating_sub(events.events_a.start_event_count);
@@ -165,7 +174,7 @@ impl<'a, E: Event> EventMutParIter<'a, E> {
         let b = events.events_b.get_mut(b_index..).unwrap_or_default();
 
         let unread_count = a.len() + b.len();
-        mutator.last_event_count = events.event_count - unread_count;
+        mutator.set_last_event_count(events.event_count - unread_count);
 
         Self {
             mutator,
@@ -246,7 +255,8 @@ impl<'a, E: Event> EventMutParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.mutator.last_event_count += self.unread;
+            self.mutator.advance(self.unread);
+            self.mutator.publish();
             self.unread = 0;
         }
     }
@@ -141,3 +141,11 @@ impl<'w, 's, E: Event> EventMutator<'w, 's, E> {
         self.reader.clear(&self.events);
     }
 }
+
+impl<'w, 's, E: Event> Drop for EventMutator<'w, 's, E> {
+    fn drop(&mut self) {
+        // Iterators only publish the position of the cursor once exhausted, so publish it here too
+        // in case the last one was dropped early.
+        self.reader.publish();
+    }
+}
@@ -0,0 +1,432 @@
+//! Indexes that look up entities by the value of one of their components.
+//!
+//! Finding the entity with a given component value normally requires scanning a [`Query`].
+//! Immutable components that opt in by implementing [`IndexedComponent`] can instead be indexed
+//! with [`World::register_component_index`], which keeps a [`ComponentIndex`] resource up to date
+//! using `on_insert` and `on_replace` [component hooks](crate::component::ComponentHooks).
+//! The [`Indexed`] system parameter then finds entities by component value without iterating.
+//!
+//! ```
+//! use bevy_ecs::{index::{HashIndex, Indexed, IndexedComponent}, prelude::*};
+//!
+//! #[derive(Component, Clone, PartialEq, Eq, Hash)]
+//! #[component(immutable)]
+//! struct PlayerId(u32);
+//!
+//! impl IndexedComponent for PlayerId {
+//!     type Storage = HashIndex<Self>;
+//! }
+//!
+//! let mut world = World::new();
+//! world.register_component_index::<PlayerId>().unwrap();
+//! let player = world.spawn(PlayerId(7)).id();
+//!
+//! fn find_player(players: Indexed<PlayerId>) -> Option<Entity> {
+//!     players.get_single(&PlayerId(7))
+//! }
+//!
+//! let mut system = IntoSystem::into_system(find_player);
+//! system.initialize(&mut world);
+//! assert_eq!(system.run((), &mut world), Some(player));
+//! ```
+//!
+//! Only [immutable](Immutable) components can be indexed: they can only change
+//! through insertion and removal, which the hooks see, so the index is always exact.
+
+use alloc::collections::BTreeMap;
+use core::{
+    hash::Hash,
+    ops::{Deref, RangeBounds},
+};
+
+use bevy_platform::collections::HashMap;
+use smallvec::SmallVec;
+use thiserror::Error;
+
+use crate::{
+    component::{Component, ComponentId, HookContext, Immutable},
+    entity::Entity,
+    resource::Resource,
+    system::{Query, Res, SystemParam},
+    world::{DeferredWorld, World},
+};
+
+/// A [`Component`] that can be indexed by value with a [`ComponentIndex`].
+///
+/// See the [module docs](crate::index) for an example.
+pub trait IndexedComponent: Component<Mutability = Immutable> + Clone + Eq {
+    /// The data structure storing the index, such as [`HashIndex`] or [`OrderedIndex`].
+    type Storage: IndexStorage<Self>;
+}
+
+/// A data structure mapping component values to the entities that have them.
+pub trait IndexStorage<C>: Default + Send + Sync + 'static {
+    /// Records that `entity` has the component `value`.
+    fn insert(&mut self, value: C, entity: Entity);
+
+    /// Removes the record that `entity` has the component `value`.
+    fn remove(&mut self, value: &C, entity: Entity);
+
+    /// Returns the entities that have the component `value`.
+    fn get(&self, value: &C) -> &[Entity];
+
+    /// Removes all records.
+    fn clear(&mut self);
+}
+
+type IndexEntities = SmallVec<[Entity; 1]>;
+
+fn remove_entity(entities: &mut IndexEntities, entity: Entity) {
+    if let Some(index) = entities.iter().position(|e| *e == entity) {
+        entities.swap_remove(index);
+    }
+}
+
+/// An [`IndexStorage`] backed by a hash map, for components that are [`Hash`].
+#[derive(Debug)]
+pub struct HashIndex<C> {
+    map: HashMap<C, IndexEntities>,
+}
+
+impl<C> Default for HashIndex<C> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::default(),
+        }
+    }
+}
+
+impl<C: Eq + Hash + Send + Sync + 'static> IndexStorage<C> for HashIndex<C> {
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.map.entry(value).or_default().push(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.map.get_mut(value) {
+            remove_entity(entities, entity);
+            if entities.is_empty() {
+                self.map.remove(value);
+            }
+        }
+    }
+
+    fn get(&self, value: &C) -> &[Entity] {
+        self.map.get(value).map_or(&[], |entities| entities)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<C> HashIndex<C> {
+    /// Returns the number of distinct indexed values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if there are no indexed values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// An [`IndexStorage`] backed by a sorted map, for components that are [`Ord`].
+/// This additionally supports looking up [ranges](OrderedIndex::range) of values.
+#[derive(Debug)]
+pub struct OrderedIndex<C> {
+    map: BTreeMap<C, IndexEntities>,
+}
+
+impl<C> Default for OrderedIndex<C> {
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::default(),
+        }
+    }
+}
+
+impl<C: Ord + Send + Sync + 'static> IndexStorage<C> for OrderedIndex<C> {
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.map.entry(value).or_default().push(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.map.get_mut(value) {
+            remove_entity(entities, entity);
+            if entities.is_empty() {
+                self.map.remove(value);
+            }
+        }
+    }
+
+    fn get(&self, value: &C) -> &[Entity] {
+        self.map.get(value).map_or(&[], |entities| entities)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<C: Ord> OrderedIndex<C> {
+    /// Iterates the entities whose component value is within `range`, in ascending order of value.
+    pub fn range<R: RangeBounds<C>>(&self, range: R) -> impl Iterator<Item = (&C, Entity)> + '_ {
+        self.map
+            .range(range)
+            .flat_map(|(value, entities)| entities.iter().map(move |entity| (value, *entity)))
+    }
+
+    /// Returns the number of distinct indexed values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if there are no indexed values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// A [`Resource`] that maps the values of the [`IndexedComponent`] `C` to the entities that have them.
+///
+/// Created by [`World::register_component_index`], and usually accessed through [`Indexed`].
+/// This dereferences to the [`IndexedComponent::Storage`], for storage specific lookups like [`OrderedIndex::range`].
+#[derive(Resource)]
+pub struct ComponentIndex<C: IndexedComponent> {
+    storage: C::Storage,
+}
+
+impl<C: IndexedComponent> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            storage: Default::default(),
+        }
+    }
+}
+
+impl<C: IndexedComponent> Deref for ComponentIndex<C> {
+    type Target = C::Storage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.storage
+    }
+}
+
+impl<C: IndexedComponent> ComponentIndex<C> {
+    /// Returns the entities that have the component `value`.
+    pub fn get(&self, value: &C) -> &[Entity] {
+        self.storage.get(value)
+    }
+
+    /// Returns the entity that has the component `value`, if exactly one entity has it.
+    pub fn get_single(&self, value: &C) -> Option<Entity> {
+        match self.get(value) {
+            [entity] => Some(*entity),
+            _ => None,
+        }
+    }
+
+    /// Returns true if any entity has the component `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        !self.get(value).is_empty()
+    }
+}
+
+/// A [`SystemParam`] that looks up entities by the value of their [`IndexedComponent`] `C`.
+///
+/// This has read access to `C`, so it is scheduled like a `Query<&C>`.
+/// It dereferences to the [`ComponentIndex`] of `C`.
+///
+/// # Panics
+///
+/// Panics when run if the index of `C` was not registered with [`World::register_component_index`].
+#[derive(SystemParam)]
+pub struct Indexed<'w, 's, C: IndexedComponent> {
+    index: Res<'w, ComponentIndex<C>>,
+    components: Query<'w, 's, &'static C>,
+}
+
+impl<'w, 's, C: IndexedComponent> Deref for Indexed<'w, 's, C> {
+    type Target = ComponentIndex<C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+impl<'w, 's, C: IndexedComponent> Indexed<'w, 's, C> {
+    /// Returns the query over `C` that this index is scheduled as.
+    pub fn components(&self) -> &Query<'w, 's, &'static C> {
+        &self.components
+    }
+}
+
+fn index_on_insert<C: IndexedComponent>(mut world: DeferredWorld, context: HookContext) {
+    let Some(value) = world.get::<C>(context.entity).cloned() else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.storage.insert(value, context.entity);
+    }
+}
+
+fn index_on_replace<C: IndexedComponent>(mut world: DeferredWorld, context: HookContext) {
+    let Some(value) = world.get::<C>(context.entity).cloned() else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.storage.remove(&value, context.entity);
+    }
+}
+
+/// An error returned by [`World::register_component_index`].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ComponentIndexError {
+    /// The component was already added to an entity, so its hooks can no longer be set.
+    #[error("Cannot index component {0:?}, as it was already added to an entity")]
+    AlreadyInUse(ComponentId),
+    /// The component already has an `on_insert` or `on_replace` hook.
+    #[error("Cannot index component {0:?}, as it already has `on_insert` or `on_replace` hooks")]
+    ConflictingHooks(ComponentId),
+}
+
+impl World {
+    /// Starts indexing the entities in this world by the value of their [`IndexedComponent`] `C`,
+    /// inserting a [`ComponentIndex`] resource that can be accessed through the [`Indexed`] system parameter.
+    ///
+    /// Calling this again does nothing.
+    ///
+    /// # Errors
+    ///
+    /// The index is maintained with component hooks, so this fails without changing anything if `C`
+    /// was already added to an entity, or if `C` already has `on_insert` or `on_replace` hooks.
+    pub fn register_component_index<C: IndexedComponent>(
+        &mut self,
+    ) -> Result<(), ComponentIndexError> {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return Ok(());
+        }
+
+        let id = self.register_component::<C>();
+        if self
+            .archetypes()
+            .iter()
+            .any(|archetype| archetype.contains(id))
+        {
+            return Err(ComponentIndexError::AlreadyInUse(id));
+        }
+        let hooks = self.register_component_hooks::<C>();
+        if hooks.on_insert.is_some() || hooks.on_replace.is_some() {
+            return Err(ComponentIndexError::ConflictingHooks(id));
+        }
+        hooks
+            .on_insert(index_on_insert::<C>)
+            .on_replace(index_on_replace::<C>);
+        self.init_resource::<ComponentIndex<C>>();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::RunSystemOnce;
+    use alloc::vec::Vec;
+
+    #[derive(Component, Clone, PartialEq, Eq, Hash, Debug)]
+    #[component(immutable)]
+    struct PlayerId(u32);
+
+    impl IndexedComponent for PlayerId {
+        type Storage = HashIndex<Self>;
+    }
+
+    #[derive(Component, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    #[component(immutable)]
+    struct Score(u32);
+
+    impl IndexedComponent for Score {
+        type Storage = OrderedIndex<Self>;
+    }
+
+    #[test]
+    fn immutable_index() {
+        let mut world = World::new();
+        world.register_component_index::<PlayerId>().unwrap();
+        let a = world.spawn(PlayerId(1)).id();
+        let b = world.spawn(PlayerId(2)).id();
+
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert_eq!(index.get_single(&PlayerId(1)), Some(a));
+        assert_eq!(index.get_single(&PlayerId(2)), Some(b));
+
+        world.entity_mut(b).insert(PlayerId(3));
+        let c = world.spawn(PlayerId(1)).id();
+        world.despawn(a);
+
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert!(!index.contains(&PlayerId(2)));
+        assert_eq!(index.get(&PlayerId(3)), &[b]);
+        assert_eq!(index.get(&PlayerId(1)), &[c]);
+
+        world.entity_mut(c).remove::<PlayerId>();
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert!(!index.contains(&PlayerId(1)));
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn ordered_index() {
+        let mut world = World::new();
+        world.register_component_index::<Score>().unwrap();
+        let a = world.spawn(Score(10)).id();
+        let b = world.spawn(Score(20)).id();
+        let c = world.spawn(Score(30)).id();
+        world.entity_mut(a).insert(Score(25));
+
+        let found = world
+            .run_system_once(|scores: Indexed<Score>| {
+                scores
+                    .range(Score(15)..Score(30))
+                    .map(|(_, entity)| entity)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(found, [b, a]);
+
+        world.despawn(c);
+        let index = world.resource::<ComponentIndex<Score>>();
+        assert!(!index.contains(&Score(30)));
+        assert_eq!(index.len(), 2);
+    }
+
+    #[test]
+    fn register_index_after_use() {
+        let mut world = World::new();
+        world.spawn(PlayerId(1));
+        assert!(matches!(
+            world.register_component_index::<PlayerId>(),
+            Err(ComponentIndexError::AlreadyInUse(_))
+        ));
+        assert!(!world.contains_resource::<ComponentIndex<PlayerId>>());
+    }
+
+    #[test]
+    fn register_index_with_hooks() {
+        let mut world = World::new();
+        world
+            .register_component_hooks::<PlayerId>()
+            .on_insert(|_, _| {});
+        assert!(matches!(
+            world.register_component_index::<PlayerId>(),
+            Err(ComponentIndexError::ConflictingHooks(_))
+        ));
+        // The free hook slot was left untouched.
+        assert!(world
+            .register_component_hooks::<PlayerId>()
+            .try_on_replace(|_, _| {})
+            .is_some());
+    }
+}
@@ -34,11 +34,13 @@ pub mod batching;
 pub mod bundle;
 pub mod change_detection;
 pub mod component;
+pub mod derived;
 pub mod entity;
 pub mod entity_disabling;
 pub mod error;
 pub mod event;
 pub mod hierarchy;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod name;
@@ -76,7 +78,7 @@ pub mod prelude {
         error::{BevyError, Result},
         event::{Event, EventMutator, EventReader, EventWriter, Events},
         hierarchy::{ChildOf, ChildSpawner, ChildSpawnerCommands, Children},
-        name::{Name, NameOrEntity},
+        name::{Name, NameOrEntity, NamePaths},
         observer::{Observer, Trigger},
         query::{Added, Allows, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
         related,
@@ -107,7 +109,7 @@ pub mod prelude {
     #[doc(hidden)]
     #[cfg(feature = "bevy_reflect")]
     pub use crate::reflect::{
-        AppTypeRegistry, ReflectComponent, ReflectFromWorld, ReflectResource,
+        AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectFromWorld, ReflectResource,
     };
 
     #[doc(hidden)]
@@ -137,7 +139,7 @@ pub struct HotPatched;
 mod tests {
     use crate::{
         bundle::Bundle,
-        change_detection::Ref,
+        change_detection::{DetectChangesMut, Ref},
         component::{Component, ComponentId, RequiredComponents, RequiredComponentsError},
         entity::{Entity, EntityMapper},
         entity_disabling::DefaultQueryFilters,
@@ -1180,6 +1182,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn storage_ticks() {
+        let mut world = World::default();
+        let e1 = world.spawn(A(0)).id();
+        let e2 = world.spawn((A(0), B(0))).id();
+        let e3 = world.spawn(SparseStored(0)).id();
+        let a = world.register_component::<A>();
+
+        world.clear_trackers();
+
+        let is_changed = |world: &World, entity: Entity| {
+            let table_id = world.entity(entity).location().table_id;
+            world.storages().tables[table_id]
+                .get_storage_ticks_for(a)
+                .unwrap()
+                .is_changed(world.last_change_tick(), world.read_change_tick())
+        };
+        assert!(!is_changed(&world, e1));
+        assert!(!is_changed(&world, e2));
+
+        world.get_mut::<A>(e2).unwrap().0 += 1;
+        assert!(!is_changed(&world, e1));
+        assert!(is_changed(&world, e2));
+        assert_eq!(
+            world
+                .query_filtered::<Entity, Changed<A>>()
+                .iter(&world)
+                .collect::<Vec<_>>(),
+            [e2]
+        );
+
+        // moving a changed value to another table marks that table as changed
+        world.entity_mut(e2).remove::<B>();
+        assert!(is_changed(&world, e1));
+        world.clear_trackers();
+        assert!(!is_changed(&world, e1));
+
+        let (last_run, this_run) = (world.last_change_tick(), world.read_change_tick());
+        let is_added_and_changed = |world: &World| {
+            let id = world.component_id::<SparseStored>().unwrap();
+            let ticks = world
+                .storages()
+                .sparse_sets
+                .get(id)
+                .unwrap()
+                .get_storage_ticks();
+            (
+                ticks.is_added(last_run, this_run),
+                ticks.is_changed(last_run, this_run),
+            )
+        };
+        assert_eq!(is_added_and_changed(&world), (false, false));
+        world.get_mut::<SparseStored>(e3).unwrap().set_added();
+        assert_eq!(is_added_and_changed(&world), (true, true));
+    }
+
     #[test]
     fn empty_spawn() {
         let mut world = World::default();
@@ -1,4 +1,9 @@
-//! Provides the [`Name`] [`Component`], used for identifying an [`Entity`].
+//! Provides the [`Name`] [`Component`], used for identifying an [`Entity`],
+//! and [`NamePaths`] for looking up entities by the path of names leading to them.
+
+mod path;
+
+pub use path::*;
 
 use crate::{component::Component, entity::Entity, query::QueryData};
 
@@ -8,6 +13,7 @@ use alloc::{
 };
 use bevy_platform::hash::FixedHasher;
 use core::{
+    borrow::Borrow,
     hash::{BuildHasher, Hash, Hasher},
     ops::Deref,
 };
@@ -174,6 +180,12 @@ impl AsRef<str> for Name {
         &self.name
     }
 }
+impl Borrow<str> for Name {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        &self.name
+    }
+}
 impl From<&Name> for String {
     #[inline(always)]
     fn from(val: &Name) -> String {
@@ -0,0 +1,556 @@
+//! Looking up entities by the path of [`Name`]s leading to them in the [`ChildOf`] hierarchy.
+
+use alloc::{string::String, vec, vec::Vec};
+use bevy_platform::collections::{HashMap, HashSet};
+use smallvec::SmallVec;
+
+use crate::{
+    entity::{Entity, EntityHashMap},
+    hierarchy::{ChildOf, Children},
+    observer::Trigger,
+    query::{Changed, With, Without},
+    resource::Resource,
+    system::{Query, Res, ResMut, SystemParam},
+    world::{OnInsert, OnReplace, World},
+};
+
+use super::Name;
+
+/// An error returned when resolving a name path fails.
+///
+/// See [`NamePaths`] for the path syntax.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum NamePathError {
+    /// The path contains an empty segment, like `"Player//RightHand"` or `"Player/"`.
+    #[error("The name path `{0}` contains an empty segment.")]
+    EmptySegment(String),
+    /// No entity matches the path.
+    #[error("No entity matches the name path `{0}`.")]
+    NotFound(String),
+    /// More than one entity matches the path, when exactly one was expected.
+    #[error("Multiple entities match the name path `{0}`.")]
+    Ambiguous(String),
+}
+
+type IndexEntities = SmallVec<[Entity; 1]>;
+
+/// A [`Resource`] that maps the [`Name`] of each entity to the entities with that name under the same parent.
+///
+/// Created by [`World::register_name_path_index`]. When present, [`NamePaths`] and the
+/// `World::resolve_name_path*` methods use it instead of walking [`Children`].
+///
+/// The index is kept up to date by [`OnInsert`] and [`OnReplace`] observers of [`Name`] and [`ChildOf`].
+/// Names changed in place through [`Name::set`] or [`Name::mutate`] are picked up when
+/// [`update_name_path_index`] runs. `App::register_name_path_index` schedules it at the end of every
+/// update; worlds driven without an `App` need to run it themselves.
+#[derive(Resource, Default, Debug)]
+pub struct NamePathIndex {
+    children: HashMap<Option<Entity>, HashMap<Name, IndexEntities>>,
+    indexed: EntityHashMap<(Option<Entity>, Name)>,
+}
+
+impl NamePathIndex {
+    /// Returns the entities named `name` with the given `parent`, or without a parent if it is `None`.
+    pub fn get(&self, parent: Option<Entity>, name: &str) -> &[Entity] {
+        self.children
+            .get(&parent)
+            .and_then(|names| names.get(name))
+            .map_or(&[], |entities| entities)
+    }
+
+    /// Returns the named entities without a parent.
+    pub fn roots(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.children
+            .get(&None)
+            .into_iter()
+            .flat_map(|names| names.values().flatten().copied())
+    }
+
+    /// Returns the number of indexed entities.
+    pub fn len(&self) -> usize {
+        self.indexed.len()
+    }
+
+    /// Returns true if no entities are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.indexed.is_empty()
+    }
+
+    fn insert(&mut self, entity: Entity, parent: Option<Entity>, name: Name) {
+        self.remove(entity);
+        self.children
+            .entry(parent)
+            .or_default()
+            .entry(name.clone())
+            .or_default()
+            .push(entity);
+        self.indexed.insert(entity, (parent, name));
+    }
+
+    fn remove(&mut self, entity: Entity) -> Option<(Option<Entity>, Name)> {
+        let (parent, name) = self.indexed.remove(&entity)?;
+        if let Some(names) = self.children.get_mut(&parent) {
+            if let Some(entities) = names.get_mut(&name) {
+                if let Some(index) = entities.iter().position(|e| *e == entity) {
+                    entities.swap_remove(index);
+                }
+                if entities.is_empty() {
+                    names.remove(&name);
+                }
+            }
+            if names.is_empty() {
+                self.children.remove(&parent);
+            }
+        }
+        Some((parent, name))
+    }
+
+    fn reparent(&mut self, entity: Entity, parent: Option<Entity>) {
+        if let Some((_, name)) = self.remove(entity) {
+            self.insert(entity, parent, name);
+        }
+    }
+}
+
+fn name_path_on_insert_name(
+    trigger: Trigger<OnInsert, Name>,
+    names: Query<(&Name, Option<&ChildOf>)>,
+    mut index: ResMut<NamePathIndex>,
+) {
+    let entity = trigger.target();
+    if let Ok((name, child_of)) = names.get(entity) {
+        index.insert(entity, child_of.map(ChildOf::parent), name.clone());
+    }
+}
+
+fn name_path_on_replace_name(trigger: Trigger<OnReplace, Name>, mut index: ResMut<NamePathIndex>) {
+    index.remove(trigger.target());
+}
+
+fn name_path_on_insert_child_of(
+    trigger: Trigger<OnInsert, ChildOf>,
+    parents: Query<&ChildOf>,
+    mut index: ResMut<NamePathIndex>,
+) {
+    let entity = trigger.target();
+    let parent = parents.get(entity).ok().map(ChildOf::parent);
+    index.reparent(entity, parent);
+}
+
+fn name_path_on_replace_child_of(
+    trigger: Trigger<OnReplace, ChildOf>,
+    mut index: ResMut<NamePathIndex>,
+) {
+    index.reparent(trigger.target(), None);
+}
+
+/// Updates the [`NamePathIndex`] with the names that were changed in place.
+pub fn update_name_path_index(
+    mut index: ResMut<NamePathIndex>,
+    changed: Query<(Entity, &Name), Changed<Name>>,
+) {
+    for (entity, name) in &changed {
+        if let Some((parent, indexed)) = index.indexed.get(&entity) {
+            if indexed != name {
+                let parent = *parent;
+                index.insert(entity, parent, name.clone());
+            }
+        }
+    }
+}
+
+/// Read access to the hierarchy, shared by [`NamePaths`] and the [`World`] methods.
+trait NameTree {
+    fn name(&self, entity: Entity) -> Option<&Name>;
+
+    fn children(&self, entity: Entity) -> &[Entity];
+
+    fn parent(&self, entity: Entity) -> Option<Entity>;
+
+    /// Pushes the named entities without a parent to `out`.
+    fn roots(&self, out: &mut Vec<Option<Entity>>);
+
+    fn index(&self) -> Option<&NamePathIndex>;
+
+    /// Pushes the children of `node` to `out`, where `None` is the parent of all roots.
+    fn push_children(&self, node: Option<Entity>, out: &mut Vec<Option<Entity>>) {
+        match node {
+            Some(entity) => out.extend(self.children(entity).iter().copied().map(Some)),
+            None => match self.index() {
+                Some(index) => out.extend(index.roots().map(Some)),
+                None => self.roots(out),
+            },
+        }
+    }
+
+    /// Pushes the children of `node` named `name` to `out`.
+    fn push_named_children(&self, node: Option<Entity>, name: &str, out: &mut Vec<Option<Entity>>) {
+        if let Some(index) = self.index() {
+            out.extend(index.get(node, name).iter().copied().map(Some));
+            return;
+        }
+        let start = out.len();
+        self.push_children(node, out);
+        let mut kept = start;
+        for i in start..out.len() {
+            if out[i]
+                .and_then(|e| self.name(e))
+                .is_some_and(|n| n.as_str() == name)
+            {
+                out.swap(kept, i);
+                kept += 1;
+            }
+        }
+        out.truncate(kept);
+    }
+
+    /// Pushes `node` and all of its descendants to `out`, parents first.
+    fn push_descendants(&self, node: Option<Entity>, out: &mut Vec<Option<Entity>>) {
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            out.push(node);
+            let start = stack.len();
+            self.push_children(node, &mut stack);
+            stack[start..].reverse();
+        }
+    }
+
+    fn resolve_all(&self, start: Option<Entity>, path: &str) -> Result<Vec<Entity>, NamePathError> {
+        let (start, relative) = match path.strip_prefix('/') {
+            Some(relative) => (None, relative),
+            None => (start, path),
+        };
+        let mut nodes = vec![start];
+        if relative.is_empty() {
+            return Ok(nodes.into_iter().flatten().collect());
+        }
+
+        let mut next = Vec::new();
+        let mut seen = HashSet::new();
+        for segment in relative.split('/') {
+            for &node in &nodes {
+                match segment {
+                    "" => return Err(NamePathError::EmptySegment(path.into())),
+                    "." => next.push(node),
+                    ".." => {
+                        if let Some(entity) = node {
+                            next.push(self.parent(entity));
+                        }
+                    }
+                    "*" => self.push_children(node, &mut next),
+                    "**" => self.push_descendants(node, &mut next),
+                    name => self.push_named_children(node, name, &mut next),
+                }
+            }
+            seen.clear();
+            next.retain(|node| seen.insert(*node));
+            core::mem::swap(&mut nodes, &mut next);
+            next.clear();
+        }
+        Ok(nodes.into_iter().flatten().collect())
+    }
+
+    fn resolve(&self, start: Option<Entity>, path: &str) -> Result<Entity, NamePathError> {
+        match self.resolve_all(start, path)?.as_slice() {
+            [entity] => Ok(*entity),
+            [] => Err(NamePathError::NotFound(path.into())),
+            _ => Err(NamePathError::Ambiguous(path.into())),
+        }
+    }
+}
+
+/// A [`SystemParam`] that looks up entities by the path of [`Name`]s leading to them in the [`ChildOf`] hierarchy.
+///
+/// Paths are made of segments separated by `/`. Each segment is one of:
+/// - a name, matching the children with that exact [`Name`],
+/// - `*`, matching all children,
+/// - `**`, matching the current entities and all of their descendants,
+/// - `..`, matching the parents,
+/// - `.`, matching the current entities.
+///
+/// Paths passed to [`resolve`](Self::resolve) start at the root entities, which are the entities with a
+/// [`Name`] and without a [`ChildOf`]. Paths passed to [`resolve_from`](Self::resolve_from) start at the
+/// given entity, unless they begin with `/`.
+///
+/// Lookups walk [`Children`], or use the [`NamePathIndex`] if it was registered with
+/// [`World::register_name_path_index`].
+///
+/// ```
+/// # use bevy_ecs::{name::NamePaths, prelude::*};
+/// # #[derive(Component)]
+/// # struct Weapon;
+/// fn equip(mut commands: Commands, players: NamePaths) {
+///     if let Ok(hand) = players.resolve("Player/Armature/RightHand") {
+///         commands.spawn((Weapon, ChildOf(hand)));
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(equip);
+/// ```
+#[derive(SystemParam)]
+pub struct NamePaths<'w, 's> {
+    nodes: Query<
+        'w,
+        's,
+        (
+            Option<&'static Name>,
+            Option<&'static Children>,
+            Option<&'static ChildOf>,
+        ),
+    >,
+    roots: Query<'w, 's, Entity, (With<Name>, Without<ChildOf>)>,
+    index: Option<Res<'w, NamePathIndex>>,
+}
+
+impl<'w, 's> NameTree for NamePaths<'w, 's> {
+    fn name(&self, entity: Entity) -> Option<&Name> {
+        self.nodes.get(entity).ok().and_then(|(name, ..)| name)
+    }
+
+    fn children(&self, entity: Entity) -> &[Entity] {
+        self.nodes
+            .get(entity)
+            .ok()
+            .and_then(|(_, children, _)| children)
+            .map_or(&[], |children| children)
+    }
+
+    fn parent(&self, entity: Entity) -> Option<Entity> {
+        self.nodes
+            .get(entity)
+            .ok()
+            .and_then(|(.., child_of)| child_of)
+            .map(ChildOf::parent)
+    }
+
+    fn roots(&self, out: &mut Vec<Option<Entity>>) {
+        out.extend(self.roots.iter().map(Some));
+    }
+
+    fn index(&self) -> Option<&NamePathIndex> {
+        self.index.as_deref()
+    }
+}
+
+impl<'w, 's> NamePaths<'w, 's> {
+    /// Returns the single entity at `path`, starting from the root entities.
+    pub fn resolve(&self, path: &str) -> Result<Entity, NamePathError> {
+        NameTree::resolve(self, None, path)
+    }
+
+    /// Returns the single entity at `path`, starting from `entity`.
+    pub fn resolve_from(&self, entity: Entity, path: &str) -> Result<Entity, NamePathError> {
+        NameTree::resolve(self, Some(entity), path)
+    }
+
+    /// Returns all entities matching `path`, starting from the root entities.
+    pub fn resolve_all(&self, path: &str) -> Result<Vec<Entity>, NamePathError> {
+        NameTree::resolve_all(self, None, path)
+    }
+
+    /// Returns all entities matching `path`, starting from `entity`.
+    pub fn resolve_all_from(
+        &self,
+        entity: Entity,
+        path: &str,
+    ) -> Result<Vec<Entity>, NamePathError> {
+        NameTree::resolve_all(self, Some(entity), path)
+    }
+}
+
+struct WorldNameTree<'w>(&'w World);
+
+impl<'w> NameTree for WorldNameTree<'w> {
+    fn name(&self, entity: Entity) -> Option<&Name> {
+        self.0.get::<Name>(entity)
+    }
+
+    fn children(&self, entity: Entity) -> &[Entity] {
+        self.0
+            .get::<Children>(entity)
+            .map_or(&[], |children| children)
+    }
+
+    fn parent(&self, entity: Entity) -> Option<Entity> {
+        self.0.get::<ChildOf>(entity).map(ChildOf::parent)
+    }
+
+    fn roots(&self, out: &mut Vec<Option<Entity>>) {
+        let Some(name) = self.0.component_id::<Name>() else {
+            return;
+        };
+        let child_of = self.0.component_id::<ChildOf>();
+        for archetype in self.0.archetypes().iter() {
+            if archetype.contains(name) && !child_of.is_some_and(|id| archetype.contains(id)) {
+                out.extend(archetype.entities().iter().map(|e| Some(e.id())));
+            }
+        }
+    }
+
+    fn index(&self) -> Option<&NamePathIndex> {
+        self.0.get_resource::<NamePathIndex>()
+    }
+}
+
+impl World {
+    /// Returns the single entity at the name `path`, starting from the root entities.
+    ///
+    /// See [`NamePaths`] for the path syntax.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// let mut world = World::new();
+    /// let player = world.spawn(Name::new("Player")).id();
+    /// let armature = world.spawn((Name::new("Armature"), ChildOf(player))).id();
+    /// let hand = world.spawn((Name::new("RightHand"), ChildOf(armature))).id();
+    ///
+    /// assert_eq!(world.resolve_name_path("Player/Armature/RightHand"), Ok(hand));
+    /// assert_eq!(world.resolve_name_path("Player/*/RightHand"), Ok(hand));
+    /// assert_eq!(world.resolve_name_path_from(hand, "../.."), Ok(player));
+    /// ```
+    pub fn resolve_name_path(&self, path: &str) -> Result<Entity, NamePathError> {
+        WorldNameTree(self).resolve(None, path)
+    }
+
+    /// Returns the single entity at the name `path`, starting from `entity`.
+    ///
+    /// See [`NamePaths`] for the path syntax.
+    pub fn resolve_name_path_from(
+        &self,
+        entity: Entity,
+        path: &str,
+    ) -> Result<Entity, NamePathError> {
+        WorldNameTree(self).resolve(Some(entity), path)
+    }
+
+    /// Returns all entities matching the name `path`, starting from the root entities.
+    ///
+    /// See [`NamePaths`] for the path syntax.
+    pub fn resolve_name_path_all(&self, path: &str) -> Result<Vec<Entity>, NamePathError> {
+        WorldNameTree(self).resolve_all(None, path)
+    }
+
+    /// Returns all entities matching the name `path`, starting from `entity`.
+    ///
+    /// See [`NamePaths`] for the path syntax.
+    pub fn resolve_name_path_all_from(
+        &self,
+        entity: Entity,
+        path: &str,
+    ) -> Result<Vec<Entity>, NamePathError> {
+        WorldNameTree(self).resolve_all(Some(entity), path)
+    }
+
+    /// Starts indexing the named entities in this world by their parent and [`Name`], inserting a
+    /// [`NamePathIndex`] resource that speeds up name path lookups.
+    ///
+    /// Entities that already have a [`Name`] are indexed right away. Calling this again does nothing.
+    ///
+    /// Names changed in place are only picked up by [`update_name_path_index`], which this does not
+    /// schedule. Prefer `App::register_name_path_index` when using `bevy_app`, which does.
+    pub fn register_name_path_index(&mut self) {
+        if self.contains_resource::<NamePathIndex>() {
+            return;
+        }
+
+        let mut index = NamePathIndex::default();
+        let mut query = self.query::<(Entity, &Name, Option<&ChildOf>)>();
+        for (entity, name, child_of) in query.iter(self) {
+            index.insert(entity, child_of.map(ChildOf::parent), name.clone());
+        }
+        self.insert_resource(index);
+
+        self.add_observer(name_path_on_insert_name);
+        self.add_observer(name_path_on_replace_name);
+        self.add_observer(name_path_on_insert_child_of);
+        self.add_observer(name_path_on_replace_child_of);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::RunSystemOnce;
+
+    fn spawn_rig(world: &mut World) -> [Entity; 5] {
+        let player = world.spawn(Name::new("Player")).id();
+        let armature = world.spawn((Name::new("Armature"), ChildOf(player))).id();
+        let left = world.spawn((Name::new("LeftHand"), ChildOf(armature))).id();
+        let right = world
+            .spawn((Name::new("RightHand"), ChildOf(armature)))
+            .id();
+        let enemy = world.spawn(Name::new("Enemy")).id();
+        [player, armature, left, right, enemy]
+    }
+
+    fn check_paths(world: &mut World) {
+        let [player, armature, left, right, enemy] = spawn_rig(world);
+
+        assert_eq!(
+            world.resolve_name_path("Player/Armature/RightHand"),
+            Ok(right)
+        );
+        assert_eq!(world.resolve_name_path("/Enemy"), Ok(enemy));
+        assert_eq!(
+            world.resolve_name_path_from(player, "Armature/LeftHand"),
+            Ok(left)
+        );
+        assert_eq!(
+            world.resolve_name_path_from(left, "../RightHand"),
+            Ok(right)
+        );
+        assert_eq!(world.resolve_name_path_from(left, "/Enemy"), Ok(enemy));
+        assert_eq!(
+            world.resolve_name_path_from(left, "../../.."),
+            Err(NamePathError::NotFound("../../..".into()))
+        );
+        assert_eq!(
+            world.resolve_name_path("Player/Armature/*"),
+            Err(NamePathError::Ambiguous("Player/Armature/*".into()))
+        );
+        assert_eq!(
+            world.resolve_name_path_all("Player/Armature/*"),
+            Ok(vec![left, right])
+        );
+        assert_eq!(world.resolve_name_path("**/RightHand"), Ok(right));
+        assert_eq!(
+            world.resolve_name_path_all_from(player, "**"),
+            Ok(vec![player, armature, left, right])
+        );
+        assert_eq!(
+            world.resolve_name_path("Player//Armature"),
+            Err(NamePathError::EmptySegment("Player//Armature".into()))
+        );
+
+        world.entity_mut(right).insert(ChildOf(enemy));
+        assert_eq!(world.resolve_name_path("Enemy/RightHand"), Ok(right));
+        world.entity_mut(right).insert(Name::new("Claw"));
+        assert_eq!(world.resolve_name_path("Enemy/Claw"), Ok(right));
+        world.entity_mut(right).remove::<ChildOf>();
+        assert_eq!(world.resolve_name_path("Claw"), Ok(right));
+        world.despawn(player);
+        assert!(world.resolve_name_path("Player").is_err());
+
+        let found = world
+            .run_system_once(|paths: NamePaths| paths.resolve("Enemy"))
+            .unwrap();
+        assert_eq!(found, Ok(enemy));
+    }
+
+    #[test]
+    fn resolve_name_paths() {
+        check_paths(&mut World::new());
+    }
+
+    #[test]
+    fn resolve_name_paths_indexed() {
+        let mut world = World::new();
+        world.register_name_path_index();
+        check_paths(&mut world);
+        assert_eq!(world.resource::<NamePathIndex>().len(), 2);
+
+        let enemy = world.resolve_name_path("Enemy").unwrap();
+        world.get_mut::<Name>(enemy).unwrap().set("Boss");
+        world.run_system_once(update_name_path_index).unwrap();
+        assert_eq!(world.resolve_name_path("Boss"), Ok(enemy));
+    }
+}
@@ -376,6 +376,13 @@ pub struct CachedObservers {
     entity_observers: EntityHashMap<ObserverMap>,
 }
 
+impl CachedObservers {
+    /// Returns the observers listening for this trigger fired at specific components.
+    pub(crate) fn component_observers(&self) -> &HashMap<ComponentId, CachedComponentObservers> {
+        &self.component_observers
+    }
+}
+
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
 #[derive(Default, Debug)]
 pub struct Observers {
@@ -385,6 +392,7 @@ pub struct Observers {
     on_replace: CachedObservers,
     on_remove: CachedObservers,
     on_despawn: CachedObservers,
+    on_mutate: CachedObservers,
     // Map from trigger type to set of observers
     cache: HashMap<ComponentId, CachedObservers>,
 }
@@ -397,6 +405,7 @@ impl Observers {
             ON_REPLACE => &mut self.on_replace,
             ON_REMOVE => &mut self.on_remove,
             ON_DESPAWN => &mut self.on_despawn,
+            ON_MUTATE => &mut self.on_mutate,
             _ => self.cache.entry(event_type).or_default(),
         }
     }
@@ -408,6 +417,7 @@ impl Observers {
             ON_REPLACE => Some(&self.on_replace),
             ON_REMOVE => Some(&self.on_remove),
             ON_DESPAWN => Some(&self.on_despawn),
+            ON_MUTATE => Some(&self.on_mutate),
             _ => self.cache.get(&event_type),
         }
     }
@@ -847,7 +857,7 @@ mod tests {
     use crate::component::ComponentId;
     use crate::{
         change_detection::MaybeLocation,
-        observer::{Observer, OnReplace},
+        observer::{Observer, OnMutate, OnReplace},
         prelude::*,
         traversal::Traversal,
     };
@@ -1784,4 +1794,83 @@ mod tests {
         assert_eq!(4, *counter.0.get(&a_id).unwrap());
         assert_eq!(3, *counter.0.get(&b_id).unwrap());
     }
+
+    #[test]
+    fn observer_mutate_is_batched() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.add_observer(
+            |trigger: Trigger<OnMutate, (A, S)>, mut res: ResMut<Order>| {
+                assert_eq!(2, trigger.components().len());
+                res.observed("mutate");
+            },
+        );
+
+        let entity = world.spawn((A, S, B)).id();
+        world.trigger_mutations();
+        assert!(world.resource::<Order>().0.is_empty());
+
+        world.get_mut::<A>(entity).unwrap().set_changed();
+        world.get_mut::<A>(entity).unwrap().set_changed();
+        world.get_mut::<S>(entity).unwrap().set_changed();
+        world.get_mut::<B>(entity).unwrap().set_changed();
+        world.trigger_mutations();
+        assert_eq!(vec!["mutate"], world.resource::<Order>().0);
+
+        world.trigger_mutations();
+        assert_eq!(vec!["mutate"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_mutate_after_add() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.add_observer(|_: Trigger<OnMutate, A>, mut res: ResMut<Order>| {
+            res.observed("mutate");
+        });
+        world.trigger_mutations();
+
+        // Added in this window, so left to `OnAdd`.
+        let entity = world.spawn(A).id();
+        world.get_mut::<A>(entity).unwrap().set_changed();
+        world.trigger_mutations();
+        assert!(world.resource::<Order>().0.is_empty());
+
+        // Changed again after being added.
+        let entity = world.spawn(A).id();
+        world.increment_change_tick();
+        world.get_mut::<A>(entity).unwrap().set_changed();
+        world.trigger_mutations();
+        assert_eq!(vec!["mutate"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_mutate_entity_propagating() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let parent = world
+            .spawn_empty()
+            .observe(|_: Trigger<OnMutate, A>, mut res: ResMut<Order>| {
+                res.observed("parent");
+            })
+            .id();
+        let child = world
+            .spawn((A, crate::hierarchy::ChildOf(parent)))
+            .observe(
+                |mut trigger: Trigger<OnMutate, A>, mut res: ResMut<Order>| {
+                    res.observed("child");
+                    trigger.propagate(true);
+                },
+            )
+            .id();
+        let other = world.spawn(A).id();
+        world.flush();
+        world.trigger_mutations();
+
+        world.get_mut::<A>(other).unwrap().set_changed();
+        world.get_mut::<A>(child).unwrap().set_changed();
+        world.trigger_mutations();
+        assert_eq!(vec!["child", "parent"], world.resource::<Order>().0);
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     archetype::Archetype,
-    component::{Component, ComponentId, Components, StorageType, Tick},
+    component::{Component, ComponentId, Components, StorageTicks, StorageType, Tick},
     entity::{Entities, Entity},
     query::{DebugCheckedUnwrap, FilteredAccess, StorageSwitch, WorldQuery},
     storage::{ComponentSparseSet, Table, TableRow},
@@ -107,6 +107,19 @@ pub unsafe trait QueryFilter: WorldQuery {
         entity: Entity,
         table_row: TableRow,
     ) -> bool;
+
+    /// Returns false if no entity of the current [`Table`] or [`Archetype`] can pass the filter,
+    /// allowing the query to skip it without calling [`QueryFilter::filter_fetch`] for each entity.
+    ///
+    /// The default implementation always returns true.
+    ///
+    /// # Safety
+    ///
+    /// Must always be called _after_ [`WorldQuery::set_table`] or [`WorldQuery::set_archetype`].
+    #[inline(always)]
+    unsafe fn filter_storage(_fetch: &Self::Fetch<'_>) -> bool {
+        true
+    }
 }
 
 /// Filter that selects entities with a component `T`.
@@ -503,6 +516,13 @@ macro_rules! impl_or_query_filter {
                 // SAFETY: The invariants are upheld by the caller.
                 false $(|| ($filter.matches && unsafe { $filter::filter_fetch(&mut $filter.fetch, entity, table_row) }))*
             }
+
+            #[inline(always)]
+            unsafe fn filter_storage(fetch: &Self::Fetch<'_>) -> bool {
+                let ($($filter,)*) = fetch;
+                // SAFETY: The invariants are upheld by the caller.
+                false $(|| ($filter.matches && unsafe { $filter::filter_storage(&$filter.fetch) }))*
+            }
         }
     };
 }
@@ -536,6 +556,13 @@ macro_rules! impl_tuple_query_filter {
                 // SAFETY: The invariants are upheld by the caller.
                 true $(&& unsafe { $name::filter_fetch($name, entity, table_row) })*
             }
+
+            #[inline(always)]
+            unsafe fn filter_storage(fetch: &Self::Fetch<'_>) -> bool {
+                let ($($name,)*) = fetch;
+                // SAFETY: The invariants are upheld by the caller.
+                true $(&& unsafe { $name::filter_storage($name) })*
+            }
         }
 
     };
@@ -633,9 +660,13 @@ unsafe impl<T: Component> QueryFilter for Allows<T> {
 ///
 /// `Added` is not [`ArchetypeFilter`], which practically means that
 /// if the query (with `T` component filter) matches a million entities,
-/// `Added<T>` filter will iterate over all of them even if none of them were just added.
+/// `Added<T>` filter may have to iterate over all of them even if only a few of them were just added.
 ///
-/// For example, these two systems are roughly equivalent in terms of performance:
+/// Tables (or sparse sets, for components stored in them) to which no `T` was added since the system
+/// last ran are skipped as a whole, so entities that don't change their components are cheap to filter out.
+///
+/// For example, these two systems are roughly equivalent in terms of performance,
+/// except that the first one skips the tables where nothing was added:
 ///
 /// ```
 /// # use bevy_ecs::change_detection::{DetectChanges, Ref};
@@ -690,6 +721,9 @@ pub struct AddedFetch<'w, T: Component> {
         // Can be `None` when the component has never been inserted
         Option<&'w ComponentSparseSet>,
     >,
+    /// The ticks of the storage of the current table or archetype.
+    /// `None` if the component has never been inserted.
+    storage_ticks: Option<&'w StorageTicks>,
     last_run: Tick,
     this_run: Tick,
 }
@@ -698,6 +732,7 @@ impl<T: Component> Clone for AddedFetch<'_, T> {
     fn clone(&self) -> Self {
         Self {
             ticks: self.ticks,
+            storage_ticks: self.storage_ticks,
             last_run: self.last_run,
             this_run: self.this_run,
         }
@@ -735,6 +770,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
                     unsafe { world.storages().sparse_sets.get(id) }
                 },
             ),
+            storage_ticks: None,
             last_run,
             this_run,
         }
@@ -759,6 +795,11 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
             unsafe {
                 Self::set_table(fetch, component_id, table);
             }
+        } else {
+            fetch.storage_ticks = fetch.ticks.extract(
+                |_| None,
+                |sparse_set| sparse_set.map(ComponentSparseSet::get_storage_ticks),
+            );
         }
     }
 
@@ -768,6 +809,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
         &component_id: &ComponentId,
         table: &'w Table,
     ) {
+        fetch.storage_ticks = table.get_storage_ticks_for(component_id);
         let table_ticks = Some(
             table
                 .get_added_ticks_slice_for(component_id)
@@ -834,6 +876,13 @@ unsafe impl<T: Component> QueryFilter for Added<T> {
             },
         )
     }
+
+    #[inline(always)]
+    unsafe fn filter_storage(fetch: &Self::Fetch<'_>) -> bool {
+        fetch
+            .storage_ticks
+            .is_some_and(|ticks| ticks.is_added(fetch.last_run, fetch.this_run))
+    }
 }
 
 /// A filter on a component that only retains results the first time after they have been added or mutably dereferenced.
@@ -858,9 +907,13 @@ unsafe impl<T: Component> QueryFilter for Added<T> {
 ///
 /// `Changed` is not [`ArchetypeFilter`], which practically means that
 /// if query (with `T` component filter) matches million entities,
-/// `Changed<T>` filter will iterate over all of them even if none of them were changed.
+/// `Changed<T>` filter may have to iterate over all of them even if only a few of them were changed.
 ///
-/// For example, these two systems are roughly equivalent in terms of performance:
+/// Tables (or sparse sets, for components stored in them) in which no `T` was added or changed since
+/// the system last ran are skipped as a whole, so static entities are cheap to filter out.
+///
+/// For example, these two systems are roughly equivalent in terms of performance,
+/// except that the first one skips the tables where nothing changed:
 ///
 /// ```
 /// # use bevy_ecs::change_detection::DetectChanges;
@@ -916,6 +969,9 @@ pub struct ChangedFetch<'w, T: Component> {
         // Can be `None` when the component has never been inserted
         Option<&'w ComponentSparseSet>,
     >,
+    /// The ticks of the storage of the current table or archetype.
+    /// `None` if the component has never been inserted.
+    storage_ticks: Option<&'w StorageTicks>,
     last_run: Tick,
     this_run: Tick,
 }
@@ -924,6 +980,7 @@ impl<T: Component> Clone for ChangedFetch<'_, T> {
     fn clone(&self) -> Self {
         Self {
             ticks: self.ticks,
+            storage_ticks: self.storage_ticks,
             last_run: self.last_run,
             this_run: self.this_run,
         }
@@ -961,6 +1018,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
                     unsafe { world.storages().sparse_sets.get(id) }
                 },
             ),
+            storage_ticks: None,
             last_run,
             this_run,
         }
@@ -985,6 +1043,11 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
             unsafe {
                 Self::set_table(fetch, component_id, table);
             }
+        } else {
+            fetch.storage_ticks = fetch.ticks.extract(
+                |_| None,
+                |sparse_set| sparse_set.map(ComponentSparseSet::get_storage_ticks),
+            );
         }
     }
 
@@ -994,6 +1057,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
         &component_id: &ComponentId,
         table: &'w Table,
     ) {
+        fetch.storage_ticks = table.get_storage_ticks_for(component_id);
         let table_ticks = Some(
             table
                 .get_changed_ticks_slice_for(component_id)
@@ -1061,6 +1125,13 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
             },
         )
     }
+
+    #[inline(always)]
+    unsafe fn filter_storage(fetch: &Self::Fetch<'_>) -> bool {
+        fetch
+            .storage_ticks
+            .is_some_and(|ticks| ticks.is_changed(fetch.last_run, fetch.this_run))
+    }
 }
 
 /// A filter that only retains results the first time after the entity has been spawned.
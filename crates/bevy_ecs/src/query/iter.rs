@@ -215,6 +215,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             &self.query_state.filter_state,
             table,
         );
+        // SAFETY: set_table was called prior.
+        if !unsafe { F::filter_storage(&self.cursor.filter) } {
+            return accum;
+        }
 
         let entities = table.entities();
         for row in rows {
@@ -273,6 +277,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             archetype,
             table,
         );
+        // SAFETY: set_archetype was called prior.
+        if !unsafe { F::filter_storage(&self.cursor.filter) } {
+            return accum;
+        }
 
         let entities = archetype.entities();
         for index in indices {
@@ -347,6 +355,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
             archetype,
             table,
         );
+        // SAFETY: set_archetype was called prior.
+        if !unsafe { F::filter_storage(&self.cursor.filter) } {
+            return accum;
+        }
         let entities = table.entities();
         for row in rows {
             // SAFETY: Caller assures `row` in range of the current archetype.
@@ -2473,6 +2485,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                         D::set_table(&mut self.fetch, &query_state.fetch_state, table);
                         F::set_table(&mut self.filter, &query_state.filter_state, table);
                     }
+                    // SAFETY: set_table was called prior.
+                    if !unsafe { F::filter_storage(&self.filter) } {
+                        continue;
+                    }
                     self.table_entities = table.entities();
                     self.current_len = table.entity_count();
                     self.current_row = 0;
@@ -2524,6 +2540,10 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIterationCursor<'w, 's, D, F> {
                             table,
                         );
                     }
+                    // SAFETY: set_archetype was called prior.
+                    if !unsafe { F::filter_storage(&self.filter) } {
+                        continue;
+                    }
                     self.archetype_entities = archetype.entities();
                     self.current_len = archetype.len();
                     self.current_row = 0;
@@ -0,0 +1,189 @@
+//! Definitions for [`Event`] reflection.
+//! This allows sending and triggering events of types that are known only at runtime.
+//!
+//! This module exports two types: [`ReflectEventFns`] and [`ReflectEvent`].
+//!
+//! Same as [`super::component`], but for [`Event`]s.
+
+use bevy_reflect::{FromReflect, FromType, PartialReflect, TypePath, TypeRegistry};
+
+use crate::{entity::Entity, event::Event, world::World};
+
+use super::from_reflect_with_fallback;
+
+/// A struct used to operate on the reflected [`Event`] trait of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectEvent(ReflectEventFns);
+
+/// The raw function pointers needed to make up a [`ReflectEvent`].
+#[derive(Clone)]
+pub struct ReflectEventFns {
+    /// Function pointer implementing [`ReflectEvent::send()`].
+    pub send: fn(&mut World, &dyn PartialReflect, &TypeRegistry) -> bool,
+    /// Function pointer implementing [`ReflectEvent::trigger()`].
+    pub trigger: fn(&mut World, &dyn PartialReflect, &TypeRegistry, &[Entity]),
+}
+
+impl ReflectEventFns {
+    /// Get the default set of [`ReflectEventFns`] for a specific event type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Event + FromReflect + TypePath>() -> Self {
+        <ReflectEvent as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectEvent {
+    /// Sends the reflected event to its [`Events`](crate::event::Events) resource, like
+    /// [`World::send_event`].
+    ///
+    /// Returns `false` if the event could not be sent, because the [`Events`](crate::event::Events)
+    /// resource of its type doesn't exist.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event can't be created from the reflected value, its [`Default`] or its
+    /// [`FromWorld`](crate::world::FromWorld) implementation.
+    pub fn send(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> bool {
+        (self.0.send)(world, event, registry)
+    }
+
+    /// Triggers the reflected event for the given `targets`, running the [`Observer`]s watching for
+    /// it, like [`World::trigger_targets`].
+    ///
+    /// If `targets` is empty, the event is triggered without targets, like [`World::trigger`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event can't be created from the reflected value, its [`Default`] or its
+    /// [`FromWorld`](crate::world::FromWorld) implementation.
+    ///
+    /// [`Observer`]: crate::observer::Observer
+    pub fn trigger(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        targets: &[Entity],
+    ) {
+        (self.0.trigger)(world, event, registry, targets);
+    }
+
+    /// Create a custom implementation of [`ReflectEvent`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`](bevy_reflect::Reflect) and add the
+    /// `#[reflect(Event)]` attribute to generate a [`ReflectEvent`] implementation automatically.
+    ///
+    /// See [`ReflectEventFns`] for more information.
+    pub fn new(fns: ReflectEventFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectEvent`.
+    ///
+    /// This is useful when you want to keep track locally of an individual
+    /// function pointer.
+    ///
+    /// Calling [`TypeRegistry::get`] followed by
+    /// [`TypeRegistration::data::<ReflectEvent>`] can be costly if done several
+    /// times per frame. Consider cloning [`ReflectEvent`] and keeping it
+    /// between frames, cloning a `ReflectEvent` is very cheap.
+    ///
+    /// If you only need a subset of the methods on `ReflectEvent`,
+    /// use `fn_pointers` to get the underlying [`ReflectEventFns`]
+    /// and copy the subset of function pointers you care about.
+    ///
+    /// [`TypeRegistration::data::<ReflectEvent>`]: bevy_reflect::TypeRegistration::data
+    pub fn fn_pointers(&self) -> &ReflectEventFns {
+        &self.0
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent(ReflectEventFns {
+            send: |world, reflected_event, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.send_event(event).is_some()
+            },
+            trigger: |world, reflected_event, registry, targets| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.trigger_targets(event, targets);
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Events},
+        observer::Trigger,
+        prelude::{AppTypeRegistry, ReflectEvent, Resource, World},
+        system::ResMut,
+    };
+    use alloc::vec::Vec;
+    use bevy_reflect::{DynamicStruct, Reflect};
+
+    #[derive(Event, Reflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Damage {
+        amount: u32,
+    }
+
+    #[derive(Resource, Default)]
+    struct Received(u32);
+
+    #[test]
+    fn send_and_trigger_reflected_event() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<Received>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Damage>();
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap();
+
+        let mut damage = DynamicStruct::default();
+        damage.insert("amount", 5_u32);
+
+        // Without an `Events<Damage>` resource, the event can't be sent.
+        assert!(!reflect_event.send(&mut world, &damage, &registry));
+        world.init_resource::<Events<Damage>>();
+        assert!(reflect_event.send(&mut world, &damage, &registry));
+        let events = world.resource::<Events<Damage>>();
+        assert_eq!(
+            events.iter_current_update_events().collect::<Vec<_>>(),
+            [&Damage { amount: 5 }]
+        );
+
+        let target = world.spawn_empty().id();
+        world.add_observer(|trigger: Trigger<Damage>, mut received: ResMut<Received>| {
+            received.0 += trigger.event().amount;
+        });
+        reflect_event.trigger(&mut world, &damage, &registry, &[target]);
+        reflect_event.trigger(&mut world, &damage, &registry, &[]);
+        assert_eq!(world.resource::<Received>().0, 10);
+    }
+}
@@ -0,0 +1,478 @@
+use alloc::{format, vec::Vec};
+use log::warn;
+
+use crate::{
+    component::{Component, HookContext, Immutable, Mutable},
+    entity::{ComponentCloneCtx, SourceComponent},
+    error::{ignore, CommandWithEntity, HandleError},
+    relationship::{RelationshipHookMode, RelationshipSourceCollection},
+    world::{DeferredWorld, EntityWorldMut},
+};
+
+/// A [`Component`] on a "source" [`Entity`](crate::entity::Entity) that references any number of "target" entities, creating a many-to-many
+/// relationship between them. Every [`ManyToManyRelationship`] has a corresponding [`ManyToManyTarget`] type (and vice-versa),
+/// which exists on each target entity and contains the list of all source entities that relate to it.
+///
+/// Just like [`Relationship`](super::Relationship), the [`ManyToManyRelationship`] component is the "source of truth" and the
+/// [`ManyToManyTarget`] component reflects that source of truth. Component hooks keep both sides in sync whenever the
+/// [`ManyToManyRelationship`] component is inserted, replaced or removed, or when either entity is despawned.
+///
+/// [`ManyToManyRelationship`] components are immutable: to change the targets of an entity, insert a new value, use
+/// [`EntityWorldMut::modify_component`] or the [`add_targets`](EntityWorldMut::add_targets) and
+/// [`remove_targets`](EntityWorldMut::remove_targets) helpers.
+///
+/// [`ManyToManyRelationship`] and [`ManyToManyTarget`] should always be derived via the [`Component`] trait to ensure the hooks
+/// are set up properly.
+///
+/// ## Derive
+///
+/// [`ManyToManyRelationship`] and [`ManyToManyTarget`] follow the same rules as [`Relationship`](super::Relationship) and
+/// [`RelationshipTarget`](super::RelationshipTarget) when derived: the collection is the only field,
+/// or the field annotated with `#[relationship]`, and all other fields must implement [`Default`].
+/// The collection of the [`ManyToManyTarget`] must be private.
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::entity::Entity;
+/// #[derive(Component)]
+/// #[many_to_many(relationship_target = Members)]
+/// pub struct MemberOf(pub Vec<Entity>);
+///
+/// #[derive(Component)]
+/// #[many_to_many_target(relationship = MemberOf)]
+/// pub struct Members(Vec<Entity>);
+/// ```
+///
+/// When deriving [`ManyToManyTarget`] you can specify the `#[many_to_many_target(linked_spawn)]` attribute to
+/// automatically despawn the source entities when a target entity is despawned, even if they are related to other targets.
+pub trait ManyToManyRelationship: Component<Mutability = Immutable> + Sized {
+    /// The [`Component`] added to the "target" entities of this [`ManyToManyRelationship`], which contains the list of all "source"
+    /// entities that relate to the "target".
+    type RelationshipTarget: ManyToManyTarget<Relationship = Self>;
+    /// The collection type that stores the "target" entities for this [`ManyToManyRelationship`] component.
+    ///
+    /// The collection should not contain duplicates.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`ManyToManyRelationship::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Returns a mutable reference to the stored [`ManyToManyRelationship::Collection`].
+    ///
+    /// As this component is immutable, the collection can only be changed before it is inserted or through
+    /// [`EntityWorldMut::modify_component`], which keeps the relationship up to date.
+    fn collection_mut(&mut self) -> &mut Self::Collection;
+
+    /// Creates this [`ManyToManyRelationship`] from the given collection of target entities.
+    fn from_collection(collection: Self::Collection) -> Self;
+
+    /// Iterates the target entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of target entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this collection has no target entities.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_insert` component hook that maintains the [`ManyToManyRelationship`] / [`ManyToManyTarget`] connection.
+    fn on_insert(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            caller,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        match relationship_hook_mode {
+            RelationshipHookMode::Run => {}
+            RelationshipHookMode::Skip => return,
+            RelationshipHookMode::RunIfNotLinked => {
+                if <Self::RelationshipTarget as ManyToManyTarget>::LINKED_SPAWN {
+                    return;
+                }
+            }
+        }
+        let targets = world
+            .entity(entity)
+            .get::<Self>()
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+        let mut invalid = Vec::new();
+        for target_entity in targets {
+            if target_entity == entity {
+                warn!(
+                    "{}The {} relationship on entity {entity:?} points to itself. The invalid target has been removed.",
+                    caller.map(|location| format!("{location}: ")).unwrap_or_default(),
+                    core::any::type_name::<Self>(),
+                );
+                invalid.push(target_entity);
+                continue;
+            }
+            if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+                if let Some(mut relationship_target) =
+                    target_entity_mut.get_mut::<Self::RelationshipTarget>()
+                {
+                    relationship_target.collection_mut_risky().add(entity);
+                } else {
+                    let mut target =
+                        <Self::RelationshipTarget as ManyToManyTarget>::with_capacity(1);
+                    target.collection_mut_risky().add(entity);
+                    world.commands().entity(target_entity).insert(target);
+                }
+            } else {
+                warn!(
+                    "{}The {} relationship on entity {entity:?} relates to {target_entity:?}, which does not exist. The invalid target has been removed.",
+                    caller.map(|location| format!("{location}: ")).unwrap_or_default(),
+                    core::any::type_name::<Self>(),
+                );
+                invalid.push(target_entity);
+            }
+        }
+        if !invalid.is_empty() {
+            let command = move |mut entity: EntityWorldMut| {
+                entity.modify_component(|relationship: &mut Self| {
+                    for target in &invalid {
+                        relationship.collection_mut().remove(*target);
+                    }
+                });
+            };
+            world
+                .commands()
+                .queue(command.with_entity(entity).handle_error_with(ignore));
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`ManyToManyRelationship`] / [`ManyToManyTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(
+        mut world: DeferredWorld,
+        HookContext {
+            entity,
+            relationship_hook_mode,
+            ..
+        }: HookContext,
+    ) {
+        match relationship_hook_mode {
+            RelationshipHookMode::Run => {}
+            RelationshipHookMode::Skip => return,
+            RelationshipHookMode::RunIfNotLinked => {
+                if <Self::RelationshipTarget as ManyToManyTarget>::LINKED_SPAWN {
+                    return;
+                }
+            }
+        }
+        let targets = world
+            .entity(entity)
+            .get::<Self>()
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+        for target_entity in targets {
+            let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                continue;
+            };
+            let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            else {
+                continue;
+            };
+            relationship_target.collection_mut_risky().remove(entity);
+            if relationship_target.is_empty() {
+                let command = |mut entity: EntityWorldMut| {
+                    // Only remove the target if it is still empty: the same relationship might have been inserted again.
+                    if entity
+                        .get::<Self::RelationshipTarget>()
+                        .is_some_and(ManyToManyTarget::is_empty)
+                    {
+                        entity.remove::<Self::RelationshipTarget>();
+                    }
+                };
+
+                world
+                    .commands()
+                    .queue(command.with_entity(target_entity).handle_error_with(ignore));
+            }
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`](crate::entity::Entity) via the associated
+/// [`ManyToManyRelationship`] type. See the [`ManyToManyRelationship`] documentation for more information.
+pub trait ManyToManyTarget: Component<Mutability = Mutable> + Sized {
+    /// If this is true, when despawning or cloning (when [linked cloning is enabled](crate::entity::EntityClonerBuilder::linked_cloning)),
+    /// the source entities relating to this entity will also be despawned or cloned.
+    ///
+    /// This defaults to false when derived.
+    const LINKED_SPAWN: bool;
+    /// The [`ManyToManyRelationship`] that populates this [`ManyToManyTarget`] collection.
+    type Relationship: ManyToManyRelationship<RelationshipTarget = Self>;
+    /// The collection type that stores the "source" entities for this [`ManyToManyTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`ManyToManyTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+    /// Returns a mutable reference to the stored [`ManyToManyTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`ManyToManyTarget`] from the given [`ManyToManyTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    /// The collection should not contain duplicates.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// The `on_replace` component hook that maintains the [`ManyToManyRelationship`] / [`ManyToManyTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let (entities, mut commands) = world.entities_and_commands();
+        let relationship_target = entities.get(entity).unwrap().get::<Self>().unwrap();
+        for source_entity in relationship_target.iter() {
+            let command = move |mut source: EntityWorldMut| {
+                source.modify_component(|relationship: &mut Self::Relationship| {
+                    relationship.collection_mut().remove(entity);
+                });
+            };
+            commands.queue(command.with_entity(source_entity).handle_error_with(ignore));
+        }
+    }
+
+    /// The `on_despawn` component hook that despawns the source entities stored in an entity's [`ManyToManyTarget`]
+    /// when that entity is despawned.
+    // note: think of this as "on_drop"
+    fn on_despawn(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let (entities, mut commands) = world.entities_and_commands();
+        let relationship_target = entities.get(entity).unwrap().get::<Self>().unwrap();
+        for source_entity in relationship_target.iter() {
+            commands.entity(source_entity).despawn();
+        }
+    }
+
+    /// Creates this [`ManyToManyTarget`] with the given pre-allocated entity capacity.
+    fn with_capacity(capacity: usize) -> Self {
+        let collection =
+            <Self::Collection as RelationshipSourceCollection>::with_capacity(capacity);
+        Self::from_collection_risky(collection)
+    }
+
+    /// Iterates the source entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of source entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this collection has no source entities.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+/// The "clone behavior" for [`ManyToManyTarget`]. Like [`clone_relationship_target`](super::clone_relationship_target),
+/// this creates an empty [`ManyToManyTarget`] that is populated when the cloned [`ManyToManyRelationship`] sources are inserted,
+/// and queues up clones of the sources if the [`EntityCloner`](crate::entity::EntityCloner) is configured to spawn recursively.
+pub fn clone_many_to_many_target<T: ManyToManyTarget>(
+    source: &SourceComponent,
+    context: &mut ComponentCloneCtx,
+) {
+    if let Some(component) = source.read::<T>() {
+        let mut cloned = T::with_capacity(component.len());
+        if context.linked_cloning() && T::LINKED_SPAWN {
+            let collection = cloned.collection_mut_risky();
+            for entity in component.iter() {
+                collection.add(entity);
+                context.queue_entity_clone(entity);
+            }
+        } else if context.linked_cloning() && context.follow_all_relationships() {
+            // The hooks of the cloned relationships add the clones to the collection.
+            for entity in component.iter() {
+                context.queue_entity_clone(entity);
+            }
+        }
+        context.write_target_component(cloned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::Component,
+        entity::Entity,
+        relationship::{ManyToManyRelationship, ManyToManyTarget},
+        world::World,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[many_to_many(relationship_target = Members)]
+    struct MemberOf(Vec<Entity>);
+
+    #[derive(Component)]
+    #[many_to_many_target(relationship = MemberOf)]
+    struct Members(Vec<Entity>);
+
+    fn members(world: &World, entity: Entity) -> Vec<Entity> {
+        world
+            .get::<Members>(entity)
+            .map(|members| members.iter().collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn many_to_many_relationship() {
+        let mut world = World::new();
+        let red = world.spawn_empty().id();
+        let blue = world.spawn_empty().id();
+        let a = world.spawn(MemberOf(vec![red, blue])).id();
+        let b = world.spawn(MemberOf(vec![blue])).id();
+
+        assert_eq!(members(&world, red), vec![a]);
+        assert_eq!(members(&world, blue), vec![a, b]);
+
+        world.entity_mut(a).insert(MemberOf(vec![red]));
+        assert_eq!(members(&world, red), vec![a]);
+        assert_eq!(members(&world, blue), vec![b]);
+
+        world.entity_mut(b).remove::<MemberOf>();
+        world.flush();
+        assert!(!world.entity(blue).contains::<Members>());
+    }
+
+    #[test]
+    fn many_to_many_despawn() {
+        let mut world = World::new();
+        let red = world.spawn_empty().id();
+        let blue = world.spawn_empty().id();
+        let a = world.spawn(MemberOf(vec![red, blue])).id();
+        let b = world.spawn(MemberOf(vec![red])).id();
+
+        world.despawn(red);
+        assert_eq!(
+            world.get::<MemberOf>(a).unwrap().iter().collect::<Vec<_>>(),
+            vec![blue]
+        );
+        assert!(world.get::<MemberOf>(b).unwrap().is_empty());
+
+        world.despawn(a);
+        world.flush();
+        assert!(!world.entity(blue).contains::<Members>());
+    }
+
+    #[test]
+    fn many_to_many_invalid_targets_are_removed() {
+        let mut world = World::new();
+        let red = world.spawn_empty().id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(MemberOf(vec![a, red, missing]));
+        world.flush();
+
+        assert_eq!(
+            world.get::<MemberOf>(a).unwrap().iter().collect::<Vec<_>>(),
+            vec![red]
+        );
+        assert_eq!(members(&world, red), vec![a]);
+        assert!(!world.entity(a).contains::<Members>());
+    }
+
+    #[test]
+    fn many_to_many_linked_spawn() {
+        #[derive(Component)]
+        #[many_to_many(relationship_target = Contents)]
+        struct StoredIn(Vec<Entity>);
+
+        #[derive(Component)]
+        #[many_to_many_target(relationship = StoredIn, linked_spawn)]
+        struct Contents(Vec<Entity>);
+
+        let mut world = World::new();
+        let chest = world.spawn_empty().id();
+        let bag = world.spawn_empty().id();
+        let item = world.spawn(StoredIn(vec![chest, bag])).id();
+        assert_eq!(world.get::<Contents>(bag).unwrap().len(), 1);
+
+        world.despawn(chest);
+        assert!(world.get_entity(item).is_err());
+        assert!(!world.entity(bag).contains::<Contents>());
+    }
+
+    #[test]
+    fn many_to_many_add_and_remove_targets() {
+        let mut world = World::new();
+        let red = world.spawn_empty().id();
+        let blue = world.spawn_empty().id();
+        let a = world.spawn_empty().id();
+
+        world
+            .entity_mut(a)
+            .add_targets::<MemberOf>(&[red])
+            .add_targets::<MemberOf>(&[red, blue]);
+        world.flush();
+        assert_eq!(world.get::<MemberOf>(a).unwrap().0, vec![red, blue]);
+        assert_eq!(members(&world, red), vec![a]);
+        assert_eq!(members(&world, blue), vec![a]);
+
+        world.entity_mut(a).remove_targets::<MemberOf>(&[red]);
+        world.flush();
+        assert_eq!(world.get::<MemberOf>(a).unwrap().0, vec![blue]);
+        assert!(!world.entity(red).contains::<Members>());
+    }
+
+    #[test]
+    fn many_to_many_reachable() {
+        use crate::system::{Query, RunSystemOnce};
+
+        #[derive(Component)]
+        #[many_to_many(relationship_target = IncomingEdges)]
+        struct Edges(Vec<Entity>);
+
+        #[derive(Component)]
+        #[many_to_many_target(relationship = Edges)]
+        struct IncomingEdges(Vec<Entity>);
+
+        let mut world = World::new();
+        let [a, b, c, d] = core::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).insert(Edges(vec![b, c]));
+        world.entity_mut(b).insert(Edges(vec![c, a]));
+        world.entity_mut(c).insert(Edges(vec![a]));
+
+        let (reachable, sources) = world
+            .run_system_once(
+                move |edges: Query<&Edges>, incoming: Query<&IncomingEdges>| {
+                    (
+                        edges.iter_many_reachable::<Edges>(a).collect::<Vec<_>>(),
+                        incoming
+                            .many_related_sources::<IncomingEdges>(c)
+                            .collect::<Vec<_>>(),
+                    )
+                },
+            )
+            .unwrap();
+        assert_eq!(reachable, vec![b, c]);
+        assert_eq!(sources, vec![a, b]);
+        assert!(!world.entity(d).contains::<IncomingEdges>());
+    }
+}
@@ -1,11 +1,16 @@
 //! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
+//!
+//! Many-to-many relationships, where both sides store a collection of entities, are supported through the
+//! [`ManyToManyRelationship`] and [`ManyToManyTarget`] traits.
 
+mod many_to_many;
 mod related_methods;
 mod relationship_query;
 mod relationship_source_collection;
 
 use alloc::format;
 
+pub use many_to_many::*;
 pub use related_methods::*;
 pub use relationship_query::*;
 pub use relationship_source_collection::*;
@@ -289,6 +294,11 @@ pub fn clone_relationship_target<T: RelationshipTarget>(
                 collection.add(entity);
                 context.queue_entity_clone(entity);
             }
+        } else if context.linked_cloning() && context.follow_all_relationships() {
+            // The hooks of the cloned relationships add the clones to the collection.
+            for entity in component.iter() {
+                context.queue_entity_clone(entity);
+            }
         }
         context.write_target_component(cloned);
     }
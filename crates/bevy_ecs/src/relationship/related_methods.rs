@@ -2,7 +2,8 @@ use crate::{
     bundle::Bundle,
     entity::{hash_set::EntityHashSet, Entity},
     relationship::{
-        Relationship, RelationshipHookMode, RelationshipSourceCollection, RelationshipTarget,
+        ManyToManyRelationship, Relationship, RelationshipHookMode, RelationshipSourceCollection,
+        RelationshipTarget,
     },
     system::{Commands, EntityCommands},
     world::{EntityWorldMut, World},
@@ -287,6 +288,51 @@ impl<'w> EntityWorldMut<'w> {
         self.add_related::<R>(&[entity])
     }
 
+    /// Adds the given entities to the targets of this entity's [`ManyToManyRelationship`] `R`,
+    /// inserting `R` if this entity doesn't have it yet. Entities that are already targets are ignored.
+    pub fn add_targets<R: ManyToManyRelationship>(&mut self, targets: &[Entity]) -> &mut Self {
+        fn add_all(collection: &mut impl RelationshipSourceCollection, targets: &[Entity]) {
+            for target in targets {
+                if !collection.iter().any(|entity| entity == *target) {
+                    collection.add(*target);
+                }
+            }
+        }
+
+        if !self.contains::<R>() {
+            let mut collection = R::Collection::with_capacity(targets.len());
+            add_all(&mut collection, targets);
+            self.insert(R::from_collection(collection));
+        } else if targets.iter().any(|target| {
+            !self
+                .get::<R>()
+                .is_some_and(|relationship| relationship.iter().any(|entity| entity == *target))
+        }) {
+            self.modify_component(|relationship: &mut R| {
+                add_all(relationship.collection_mut(), targets);
+            });
+        }
+        self
+    }
+
+    /// Removes the given entities from the targets of this entity's [`ManyToManyRelationship`] `R`.
+    ///
+    /// The `R` component is kept even if it has no targets left.
+    pub fn remove_targets<R: ManyToManyRelationship>(&mut self, targets: &[Entity]) -> &mut Self {
+        if self
+            .get::<R>()
+            .is_some_and(|relationship| relationship.iter().any(|entity| targets.contains(&entity)))
+        {
+            self.modify_component(|relationship: &mut R| {
+                let collection = relationship.collection_mut();
+                for target in targets {
+                    collection.remove(*target);
+                }
+            });
+        }
+        self
+    }
+
     /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
     /// This entity will not be despawned.
     pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
@@ -459,6 +505,27 @@ impl<'a> EntityCommands<'a> {
         })
     }
 
+    /// Adds the given entities to the targets of this entity's [`ManyToManyRelationship`] `R`,
+    /// inserting `R` if this entity doesn't have it yet. Entities that are already targets are ignored.
+    pub fn add_targets<R: ManyToManyRelationship>(&mut self, targets: &[Entity]) -> &mut Self {
+        let targets: Box<[Entity]> = targets.into();
+
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.add_targets::<R>(&targets);
+        })
+    }
+
+    /// Removes the given entities from the targets of this entity's [`ManyToManyRelationship`] `R`.
+    ///
+    /// The `R` component is kept even if it has no targets left.
+    pub fn remove_targets<R: ManyToManyRelationship>(&mut self, targets: &[Entity]) -> &mut Self {
+        let targets: Box<[Entity]> = targets.into();
+
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.remove_targets::<R>(&targets);
+        })
+    }
+
     /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
     /// This entity will not be despawned.
     pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
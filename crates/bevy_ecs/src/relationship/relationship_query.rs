@@ -1,7 +1,7 @@
 use crate::{
-    entity::Entity,
+    entity::{Entity, EntityHashSet},
     query::{QueryData, QueryFilter},
-    relationship::{Relationship, RelationshipTarget},
+    relationship::{ManyToManyRelationship, ManyToManyTarget, Relationship, RelationshipTarget},
     system::Query,
 };
 use alloc::collections::VecDeque;
@@ -33,6 +33,49 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
             .flat_map(RelationshipTarget::iter)
     }
 
+    /// If the given `entity` contains the `R` [`ManyToManyRelationship`] component, returns the
+    /// target entities stored on that component.
+    pub fn many_related<R: ManyToManyRelationship>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: QueryData<Item<'w> = &'w R>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(ManyToManyRelationship::iter)
+    }
+
+    /// If the given `entity` contains the `S` [`ManyToManyTarget`] component, returns the
+    /// source entities stored on that component.
+    pub fn many_related_sources<S: ManyToManyTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: QueryData<Item<'w> = &'w S>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(ManyToManyTarget::iter)
+    }
+
+    /// Iterates all entities that can be reached from the given `entity` by repeatedly following the targets of
+    /// the `R` [`ManyToManyRelationship`], in breadth-first order.
+    ///
+    /// Every entity is returned at most once and `entity` itself is never returned,
+    /// so this is safe to use on relationship graphs that contain loops.
+    pub fn iter_many_reachable<R: ManyToManyRelationship>(
+        &'w self,
+        entity: Entity,
+    ) -> ManyToManyReachableIter<'w, 's, D, F, R>
+    where
+        D::ReadOnly: QueryData<Item<'w> = &'w R>,
+    {
+        ManyToManyReachableIter::new(self, entity)
+    }
+
     /// Recursively walks up the tree defined by the given `R` [`Relationship`] until
     /// there are no more related entities, returning the "root entity" of the relationship hierarchy.
     ///
@@ -270,3 +313,56 @@ where
         self.next
     }
 }
+
+/// An [`Iterator`] of [`Entity`]s over the entities reachable from an [`Entity`] through a [`ManyToManyRelationship`].
+///
+/// Traverses the relationship graph breadth-first, visiting each entity once.
+pub struct ManyToManyReachableIter<'w, 's, D: QueryData, F: QueryFilter, R: ManyToManyRelationship>
+where
+    D::ReadOnly: QueryData<Item<'w> = &'w R>,
+{
+    query: &'w Query<'w, 's, D, F>,
+    visited: EntityHashSet,
+    vecdeque: VecDeque<Entity>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, R: ManyToManyRelationship>
+    ManyToManyReachableIter<'w, 's, D, F, R>
+where
+    D::ReadOnly: QueryData<Item<'w> = &'w R>,
+{
+    /// Returns a new [`ManyToManyReachableIter`].
+    pub fn new(query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        let mut iter = ManyToManyReachableIter {
+            query,
+            visited: EntityHashSet::from_iter([entity]),
+            vecdeque: VecDeque::new(),
+        };
+        iter.visit_targets(entity);
+        iter
+    }
+
+    fn visit_targets(&mut self, entity: Entity) {
+        if let Ok(relationship) = self.query.get(entity) {
+            for target in relationship.iter() {
+                if self.visited.insert(target) {
+                    self.vecdeque.push_back(target);
+                }
+            }
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, R: ManyToManyRelationship> Iterator
+    for ManyToManyReachableIter<'w, 's, D, F, R>
+where
+    D::ReadOnly: QueryData<Item<'w> = &'w R>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.vecdeque.pop_front()?;
+        self.visit_targets(entity);
+        Some(entity)
+    }
+}
@@ -0,0 +1,470 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use crate::{
+    component::{ComponentId, ComponentInfo, Components},
+    schedule::{is_apply_deferred, NodeId, Schedule, ScheduleNotInitialized, Schedules},
+};
+
+/// A snapshot of the structure of an initialized [`Schedule`], as returned by [`Schedule::describe`].
+///
+/// This can be rendered as a Graphviz graph with [`ScheduleDescription::to_dot`], or, with the
+/// `serialize` feature, serialized to any `serde` format such as JSON. All lists are sorted,
+/// so the output of an unchanged schedule is stable and can be diffed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleDescription {
+    /// The name of the schedule's label.
+    pub label: String,
+    /// All systems in the schedule, in the order the single-threaded executor would run them.
+    pub systems: Vec<SystemDescription>,
+    /// All system sets in the schedule.
+    pub sets: Vec<SystemSetDescription>,
+    /// `(parent, child)` pairs, where `parent` is a set and `child` is a system or set contained in it.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// `(before, after)` pairs for every ordering constraint added to the schedule,
+    /// between systems and sets.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// `(before, after)` pairs between systems, after ordering constraints on sets have been
+    /// applied to their systems, sync points have been inserted and redundant edges have been removed.
+    ///
+    /// This is the graph the executors actually use.
+    pub flattened_dependencies: Vec<(NodeId, NodeId)>,
+    /// Pairs of systems with conflicting data access and no ordering between them.
+    ///
+    /// See [`ScheduleBuildSettings::ambiguity_detection`](crate::schedule::ScheduleBuildSettings::ambiguity_detection).
+    pub ambiguities: Vec<AmbiguityDescription>,
+}
+
+/// A system in a [`ScheduleDescription`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemDescription {
+    /// The id of the system within its schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: String,
+    /// The names of the run conditions attached directly to this system.
+    pub conditions: Vec<String>,
+    /// Whether the system requires exclusive [`World`](crate::world::World) access.
+    pub is_exclusive: bool,
+    /// Whether the system has deferred buffers, such as [`Commands`](crate::system::Commands).
+    pub has_deferred: bool,
+    /// Whether this is an [`ApplyDeferred`](crate::schedule::ApplyDeferred) sync point that was
+    /// inserted automatically by the [`AutoInsertApplyDeferredPass`](crate::schedule::passes::AutoInsertApplyDeferredPass).
+    pub is_auto_sync_point: bool,
+}
+
+/// A system set in a [`ScheduleDescription`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemSetDescription {
+    /// The id of the set within its schedule.
+    pub id: NodeId,
+    /// The name of the set.
+    pub name: String,
+    /// The names of the run conditions attached to this set.
+    pub conditions: Vec<String>,
+    /// Whether this set was created automatically for a system type.
+    pub is_system_type: bool,
+    /// Whether this set was created automatically for a group of systems with shared configuration.
+    pub is_anonymous: bool,
+}
+
+/// A scheduling ambiguity in a [`ScheduleDescription`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmbiguityDescription {
+    /// The first of the two conflicting systems.
+    pub first: NodeId,
+    /// The second of the two conflicting systems.
+    pub second: NodeId,
+    /// The components and resources that both systems access, with at least one of them mutably.
+    ///
+    /// If this is empty, the systems conflict on access to the whole [`World`](crate::world::World).
+    pub conflicts: Vec<ConflictDescription>,
+}
+
+/// A component or resource accessed by both systems of an [`AmbiguityDescription`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConflictDescription {
+    /// The name of the component or resource.
+    pub name: String,
+    /// Whether this is a resource rather than a component.
+    pub is_resource: bool,
+}
+
+impl Schedule {
+    /// Describes the systems, sets, ordering and ambiguities of this schedule.
+    ///
+    /// `components` should come from the [`World`](crate::world::World) the schedule was initialized
+    /// with, and is used to name conflicting accesses.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn describe(
+        &self,
+        components: &Components,
+    ) -> Result<ScheduleDescription, ScheduleNotInitialized> {
+        let systems = self
+            .systems()?
+            .zip(&self.executable().system_conditions)
+            .map(|((id, system), conditions)| SystemDescription {
+                id,
+                name: system.name().into_owned(),
+                conditions: conditions
+                    .iter()
+                    .map(|condition| condition.name().into_owned())
+                    .collect(),
+                is_exclusive: system.is_exclusive(),
+                has_deferred: system.has_deferred(),
+                // Systems added by the user are always part of the hierarchy, even without a set.
+                is_auto_sync_point: is_apply_deferred(system)
+                    && !self.graph().hierarchy().graph().contains_node(id),
+            })
+            .collect();
+
+        let executable = self.executable();
+        let mut sets = self
+            .graph()
+            .system_sets()
+            .map(|(id, set, _)| SystemSetDescription {
+                id,
+                name: format!("{set:?}"),
+                conditions: executable
+                    .set_ids
+                    .iter()
+                    .position(|&set_id| set_id == id)
+                    .map(|index| {
+                        executable.set_conditions[index]
+                            .iter()
+                            .map(|condition| condition.name().into_owned())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                is_system_type: set.system_type().is_some(),
+                is_anonymous: set.is_anonymous(),
+            })
+            .collect::<Vec<_>>();
+        sets.sort_by_key(|set| set.id);
+
+        let mut hierarchy = self
+            .graph()
+            .hierarchy()
+            .graph()
+            .all_edges()
+            .collect::<Vec<_>>();
+        hierarchy.sort();
+
+        let mut dependencies = self
+            .graph()
+            .dependency()
+            .graph()
+            .all_edges()
+            .collect::<Vec<_>>();
+        dependencies.sort();
+
+        let mut flattened_dependencies = executable
+            .system_dependents
+            .iter()
+            .enumerate()
+            .flat_map(|(before, dependents)| {
+                dependents.iter().map(move |&after| {
+                    (executable.system_ids[before], executable.system_ids[after])
+                })
+            })
+            .collect::<Vec<_>>();
+        flattened_dependencies.sort();
+
+        let mut ambiguities = self
+            .graph()
+            .conflicting_systems()
+            .iter()
+            .map(|(first, second, conflicts)| AmbiguityDescription {
+                first: *first.min(second),
+                second: *first.max(second),
+                conflicts: conflicts
+                    .iter()
+                    .map(|&id| describe_conflict(components, id))
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        ambiguities.sort_by_key(|ambiguity| (ambiguity.first, ambiguity.second));
+
+        Ok(ScheduleDescription {
+            label: format!("{:?}", self.label()),
+            systems,
+            sets,
+            hierarchy,
+            dependencies,
+            flattened_dependencies,
+            ambiguities,
+        })
+    }
+}
+
+impl Schedules {
+    /// Describes every initialized schedule, sorted by label.
+    ///
+    /// Schedules that have never been initialized or run are skipped.
+    /// See [`Schedule::describe`] for more details.
+    pub fn describe(&self, components: &Components) -> Vec<ScheduleDescription> {
+        let mut descriptions = self
+            .iter()
+            .filter_map(|(_, schedule)| schedule.describe(components).ok())
+            .collect::<Vec<_>>();
+        descriptions.sort_by(|a, b| a.label.cmp(&b.label));
+        descriptions
+    }
+}
+
+fn describe_conflict(components: &Components, id: ComponentId) -> ConflictDescription {
+    let info = components.get_info(id);
+    ConflictDescription {
+        name: info.map_or_else(|| format!("{id:?}"), |info| info.name().to_string()),
+        is_resource: info
+            .and_then(ComponentInfo::type_id)
+            .and_then(|type_id| components.get_resource_id(type_id))
+            == Some(id),
+    }
+}
+
+impl ScheduleDescription {
+    /// Returns the name of the system or set with the given id, if it is part of this description.
+    pub fn name_of(&self, id: NodeId) -> Option<&str> {
+        match id {
+            NodeId::System(_) => self
+                .systems
+                .iter()
+                .find(|system| system.id == id)
+                .map(|system| system.name.as_str()),
+            NodeId::Set(_) => self
+                .sets
+                .iter()
+                .find(|set| set.id == id)
+                .map(|set| set.name.as_str()),
+        }
+    }
+
+    /// Renders this schedule as a [Graphviz](https://graphviz.org/) graph in the DOT language.
+    ///
+    /// - Systems are drawn as ellipses, and automatically inserted sync points as diamonds.
+    /// - Sets are drawn as dashed boxes, with dotted edges to the systems and sets they contain.
+    /// - Ordering constraints are drawn as solid edges.
+    /// - Ambiguities are drawn as red edges labeled with the conflicting accesses.
+    ///
+    /// Run conditions are listed in the label of the system or set they are attached to.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        // Writing to a `String` can't fail.
+        let _ = self.write_dot(&mut dot);
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String) -> core::fmt::Result {
+        writeln!(dot, "digraph {} {{", quote(&self.label))?;
+        writeln!(dot, "\trankdir=LR;")?;
+
+        for system in &self.systems {
+            let shape = if system.is_auto_sync_point {
+                "diamond"
+            } else {
+                "ellipse"
+            };
+            writeln!(
+                dot,
+                "\t{} [label={}, shape={shape}];",
+                node_name(system.id),
+                quote(&label_with_conditions(&system.name, &system.conditions)),
+            )?;
+        }
+        for set in &self.sets {
+            writeln!(
+                dot,
+                "\t{} [label={}, shape=box, style=dashed];",
+                node_name(set.id),
+                quote(&label_with_conditions(&set.name, &set.conditions)),
+            )?;
+        }
+
+        for (parent, child) in &self.hierarchy {
+            writeln!(
+                dot,
+                "\t{} -> {} [style=dotted, arrowhead=empty];",
+                node_name(*parent),
+                node_name(*child),
+            )?;
+        }
+        for (before, after) in &self.dependencies {
+            writeln!(dot, "\t{} -> {};", node_name(*before), node_name(*after))?;
+        }
+        for (before, after) in &self.flattened_dependencies {
+            // Edges between user systems are usually already implied by `dependencies`,
+            // only draw the ones created by sync point insertion.
+            let to_sync_point = [before, after].into_iter().any(|id| {
+                self.systems
+                    .iter()
+                    .any(|system| system.id == *id && system.is_auto_sync_point)
+            });
+            if to_sync_point {
+                writeln!(
+                    dot,
+                    "\t{} -> {} [color=gray];",
+                    node_name(*before),
+                    node_name(*after),
+                )?;
+            }
+        }
+        for ambiguity in &self.ambiguities {
+            let conflicts = if ambiguity.conflicts.is_empty() {
+                String::from("World")
+            } else {
+                ambiguity
+                    .conflicts
+                    .iter()
+                    .map(|conflict| conflict.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\\n")
+            };
+            writeln!(
+                dot,
+                "\t{} -> {} [dir=none, color=red, fontcolor=red, label={}];",
+                node_name(ambiguity.first),
+                node_name(ambiguity.second),
+                quote(&conflicts),
+            )?;
+        }
+
+        writeln!(dot, "}}")
+    }
+}
+
+fn node_name(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system_{index}"),
+        NodeId::Set(index) => format!("set_{index}"),
+    }
+}
+
+fn label_with_conditions(name: &str, conditions: &[String]) -> String {
+    let mut label = String::from(name);
+    for condition in conditions {
+        label.push_str("\\nif ");
+        label.push_str(condition);
+    }
+    label
+}
+
+/// Quotes a DOT identifier, escaping inner quotes but keeping `\n` line breaks.
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        schedule::{NodeId, ScheduleLabel},
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
+    struct TestSchedule;
+
+    #[derive(SystemSet, Hash, PartialEq, Eq, Debug, Clone)]
+    struct TestSet;
+
+    #[derive(Resource)]
+    struct R;
+
+    #[derive(Component)]
+    struct C;
+
+    fn spawn(mut commands: Commands) {
+        commands.spawn(C);
+    }
+
+    fn read(_: Query<&C>) {}
+
+    fn write_a(_: ResMut<R>) {}
+
+    fn write_b(_: ResMut<R>) {}
+
+    #[test]
+    fn describe_schedule() {
+        let mut world = World::new();
+        world.insert_resource(R);
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule
+            .configure_sets(TestSet.run_if(|| true))
+            .add_systems((spawn, read).chain().in_set(TestSet))
+            .add_systems((write_a, write_b));
+
+        assert!(schedule.describe(world.components()).is_err());
+        schedule.initialize(&mut world).unwrap();
+        let description = schedule.describe(world.components()).unwrap();
+
+        assert_eq!(description.label, "TestSchedule");
+        let names = description
+            .systems
+            .iter()
+            .map(|system| system.name.rsplit("::").next().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names.len(), 5);
+        assert!(names.contains(&"apply_deferred"));
+
+        let spawn_id = find(&description, "spawn");
+        let read_id = find(&description, "read");
+        let sync = description
+            .systems
+            .iter()
+            .find(|system| system.is_auto_sync_point)
+            .unwrap();
+        assert!(description.systems.iter().any(|system| system.has_deferred));
+        assert!(description
+            .flattened_dependencies
+            .contains(&(spawn_id, sync.id)));
+        assert!(description
+            .flattened_dependencies
+            .contains(&(sync.id, read_id)));
+        assert!(description.dependencies.contains(&(spawn_id, read_id)));
+
+        let set = description
+            .sets
+            .iter()
+            .find(|set| set.name == "TestSet")
+            .unwrap();
+        assert_eq!(set.conditions.len(), 1);
+        assert!(description.hierarchy.contains(&(set.id, spawn_id)));
+
+        let [ambiguity] = description.ambiguities.as_slice() else {
+            panic!("expected a single ambiguity: {:?}", description.ambiguities);
+        };
+        let mut pair = vec![ambiguity.first, ambiguity.second];
+        pair.sort();
+        let mut expected = vec![find(&description, "write_a"), find(&description, "write_b")];
+        expected.sort();
+        assert_eq!(pair, expected);
+        assert_eq!(ambiguity.conflicts.len(), 1);
+        assert!(ambiguity.conflicts[0].is_resource);
+
+        let dot = description.to_dot();
+        assert!(dot.starts_with("digraph \"TestSchedule\" {"));
+        assert!(dot.contains("shape=diamond"));
+        assert!(dot.contains("color=red"));
+    }
+
+    fn find(description: &super::ScheduleDescription, name: &str) -> NodeId {
+        description
+            .systems
+            .iter()
+            .find(|system| system.name.ends_with(name))
+            .unwrap()
+            .id
+    }
+}
@@ -1,13 +1,25 @@
 #[cfg(feature = "std")]
 mod multi_threaded;
+mod replay;
 mod simple;
 mod single_threaded;
+mod timing;
+mod trace;
 
 use alloc::{borrow::Cow, vec, vec::Vec};
 use core::any::TypeId;
 
+pub use self::{
+    replay::ReplayExecutor,
+    timing::{SystemTiming, SystemTimings},
+    trace::{
+        ExecutionTrace, InvalidExecutionTraceError, ParseExecutionTraceError, TraceEvent,
+        TraceEventKind,
+    },
+};
 #[expect(deprecated, reason = "We still need to support this.")]
 pub use self::{simple::SimpleExecutor, single_threaded::SingleThreadedExecutor};
+pub(super) use trace::ExecutionRecorder;
 
 #[cfg(feature = "std")]
 pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
@@ -36,6 +48,8 @@ pub(super) trait SystemExecutor: Send + Sync {
         error_handler: fn(BevyError, ErrorContext),
     );
     fn set_apply_final_deferred(&mut self, value: bool);
+    /// Sets the recorder that the start and end of each system run are reported to.
+    fn set_recorder(&mut self, recorder: Option<ExecutionRecorder>);
 }
 
 /// Specifies how a [`Schedule`](super::Schedule) will be run.
@@ -62,6 +76,10 @@ pub enum ExecutorKind {
     #[cfg(feature = "std")]
     #[cfg_attr(all(not(target_arch = "wasm32"), feature = "multi_threaded"), default)]
     MultiThreaded,
+    /// Runs the schedule using a single thread, in the order recorded in an [`ExecutionTrace`].
+    ///
+    /// See [`Schedule::replay`](super::Schedule::replay).
+    Replay,
 }
 
 /// Holds systems and conditions of a [`Schedule`](super::Schedule) sorted in topological order
@@ -86,10 +104,6 @@ pub struct SystemSchedule {
     pub(super) system_dependencies: Vec<usize>,
     /// Indexed by system node id.
     /// List of systems that immediately depend on the system.
-    #[cfg_attr(
-        not(feature = "std"),
-        expect(dead_code, reason = "currently only used with the std feature")
-    )]
     pub(super) system_dependents: Vec<Vec<usize>>,
     /// Indexed by system node id.
     /// List of sets containing the system that have conditions
@@ -335,11 +349,12 @@ mod tests {
     #[derive(Component)]
     struct TestComponent;
 
-    const EXECUTORS: [ExecutorKind; 3] = [
+    const EXECUTORS: [ExecutorKind; 4] = [
         #[expect(deprecated, reason = "We still need to test this.")]
         ExecutorKind::Simple,
         ExecutorKind::SingleThreaded,
         ExecutorKind::MultiThreaded,
+        ExecutorKind::Replay,
     ];
 
     #[derive(Resource, Default)]
@@ -582,4 +597,71 @@ mod tests {
         let counter = world.resource::<Counter>();
         assert_eq!(counter.0, 0);
     }
+
+    #[derive(Resource, Default)]
+    struct RunOrder(alloc::vec::Vec<u8>);
+
+    #[test]
+    fn record_and_replay() {
+        use crate::schedule::{ExecutionTrace, IntoScheduleConfigs, TraceEvent, TraceEventKind};
+        use alloc::vec::Vec;
+
+        fn a(mut order: ResMut<RunOrder>) {
+            order.0.push(0);
+        }
+        fn b(mut order: ResMut<RunOrder>) {
+            order.0.push(1);
+        }
+        fn c(mut order: ResMut<RunOrder>) {
+            order.0.push(2);
+        }
+
+        for executor in EXECUTORS {
+            let mut world = World::new();
+            world.init_resource::<RunOrder>();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(executor);
+            schedule.add_systems((a, b, c.after(a)));
+
+            schedule.start_recording();
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+            let trace = schedule.stop_recording().unwrap();
+            assert_eq!(trace.frames.len(), 2);
+            for frame in &trace.frames {
+                let finished = frame
+                    .iter()
+                    .filter(|event| event.kind == TraceEventKind::Finished)
+                    .count();
+                assert_eq!(finished, 3);
+            }
+
+            // Force an order the systems would not run in by default.
+            let index = |name: &str| {
+                trace
+                    .systems
+                    .iter()
+                    .position(|system| system.ends_with(name))
+                    .unwrap()
+            };
+            let forced = ExecutionTrace {
+                systems: trace.systems.clone(),
+                frames: alloc::vec![["::b", "::a", "::c"]
+                    .into_iter()
+                    .flat_map(|name| {
+                        let system = index(name);
+                        [TraceEventKind::Started, TraceEventKind::Finished]
+                            .map(|kind| TraceEvent { system, kind })
+                    })
+                    .collect::<Vec<_>>()],
+            };
+
+            world.resource_mut::<RunOrder>().0.clear();
+            schedule.replay(forced.clone()).unwrap();
+            schedule.start_recording();
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<RunOrder>().0, [1, 0, 2]);
+            assert_eq!(schedule.recorded_trace().unwrap(), forced);
+        }
+    }
 }
@@ -22,7 +22,7 @@ use crate::{
 #[cfg(feature = "hotpatching")]
 use crate::{event::Events, HotPatched};
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, ExecutionRecorder, TraceEventKind};
 
 /// Borrowed data used by the [`MultiThreadedExecutor`].
 struct Environment<'env, 'sys> {
@@ -93,6 +93,8 @@ pub struct MultiThreadedExecutor {
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
     starting_systems: FixedBitSet,
+    /// Receives the start and end of each system run, if the schedule is being recorded.
+    recorder: Option<ExecutionRecorder>,
     /// Cached tracing span
     #[cfg(feature = "trace")]
     executor_span: Span,
@@ -325,6 +327,10 @@ impl SystemExecutor for MultiThreadedExecutor {
     fn set_apply_final_deferred(&mut self, value: bool) {
         self.apply_final_deferred = value;
     }
+
+    fn set_recorder(&mut self, recorder: Option<ExecutionRecorder>) {
+        self.recorder = recorder;
+    }
 }
 
 impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
@@ -334,6 +340,7 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
     ) {
+        self.record(system_index, TraceEventKind::Finished);
         // tell the executor that the system finished
         self.environment
             .executor
@@ -355,6 +362,12 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         self.tick_executor();
     }
 
+    fn record(&self, system_index: usize, kind: TraceEventKind) {
+        if let Some(recorder) = &self.environment.executor.recorder {
+            recorder.record(system_index, kind);
+        }
+    }
+
     fn try_lock<'a>(&'a self) -> Option<(&'a mut Conditions<'sys>, MutexGuard<'a, ExecutorState>)> {
         let guard = self.environment.executor.state.try_lock().ok()?;
         // SAFETY: This is an exclusive access as no other location fetches conditions mutably, and
@@ -394,6 +407,7 @@ impl MultiThreadedExecutor {
             starting_systems: FixedBitSet::new(),
             apply_final_deferred: true,
             panic_payload: Mutex::new(None),
+            recorder: None,
             #[cfg(feature = "trace")]
             executor_span: info_span!("multithreaded executor"),
         }
@@ -668,6 +682,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            context.record(system_index, TraceEventKind::Started);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -712,6 +727,7 @@ impl ExecutorState {
             let unapplied_systems = self.unapplied_systems.clone();
             self.unapplied_systems.clear();
             let task = async move {
+                context.record(system_index, TraceEventKind::Started);
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
@@ -722,6 +738,7 @@ impl ExecutorState {
             context.scope.spawn_on_scope(task);
         } else {
             let task = async move {
+                context.record(system_index, TraceEventKind::Started);
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
@@ -0,0 +1,253 @@
+use alloc::vec::Vec;
+use core::panic::AssertUnwindSafe;
+use fixedbitset::FixedBitSet;
+use log::warn;
+
+#[cfg(feature = "std")]
+use std::eprintln;
+
+use crate::{
+    error::{ErrorContext, ErrorHandler},
+    schedule::{
+        is_apply_deferred, ExecutionTrace, ExecutorKind, SystemExecutor, SystemSchedule,
+        TraceEventKind,
+    },
+    world::World,
+};
+
+use super::{
+    __rust_begin_short_backtrace, single_threaded::evaluate_and_fold_conditions,
+    trace::ExecutionRecorder,
+};
+
+/// Runs the schedule on a single thread, starting systems in the order recorded in an [`ExecutionTrace`].
+///
+/// Each run of the schedule replays the next frame of the trace. Systems that ran in parallel when the
+/// trace was recorded could only do so because they don't conflict with each other, so running them one
+/// at a time in the recorded start order reproduces every order that could be observed in the world.
+///
+/// Systems that didn't run in the recorded frame are skipped without evaluating their run conditions.
+/// Once all frames have been replayed, or if the systems of the schedule no longer match the ones in the
+/// trace, systems run in the same order as with the [`SingleThreadedExecutor`](super::SingleThreadedExecutor).
+pub struct ReplayExecutor {
+    trace: ExecutionTrace,
+    /// The index of the next frame of `trace` to replay.
+    next_frame: usize,
+    /// Whether the systems in `trace` match the systems of the schedule.
+    matches_schedule: bool,
+    /// The order systems are started in during the current run.
+    order: Vec<usize>,
+    /// System sets whose conditions have been evaluated.
+    evaluated_sets: FixedBitSet,
+    /// Systems that have run or been skipped.
+    completed_systems: FixedBitSet,
+    /// Systems that have run but have not had their buffers applied.
+    unapplied_systems: FixedBitSet,
+    /// Setting when true applies deferred system buffers after all systems have run
+    apply_final_deferred: bool,
+    recorder: Option<ExecutionRecorder>,
+}
+
+impl Default for ReplayExecutor {
+    fn default() -> Self {
+        Self::new(ExecutionTrace::default())
+    }
+}
+
+impl SystemExecutor for ReplayExecutor {
+    fn kind(&self) -> ExecutorKind {
+        ExecutorKind::Replay
+    }
+
+    fn init(&mut self, schedule: &SystemSchedule) {
+        let sys_count = schedule.system_ids.len();
+        let set_count = schedule.set_ids.len();
+        self.evaluated_sets = FixedBitSet::with_capacity(set_count);
+        self.completed_systems = FixedBitSet::with_capacity(sys_count);
+        self.unapplied_systems = FixedBitSet::with_capacity(sys_count);
+
+        self.matches_schedule = self.trace.systems.len() == sys_count
+            && self
+                .trace
+                .systems
+                .iter()
+                .zip(&schedule.systems)
+                .all(|(name, system)| *name == system.name());
+        if !self.matches_schedule && self.next_frame < self.trace.frames.len() {
+            warn!(
+                "The systems of the schedule don't match the execution trace being replayed; \
+                systems will run in their default order instead."
+            );
+        }
+    }
+
+    fn run(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &mut World,
+        _skip_systems: Option<&FixedBitSet>,
+        error_handler: ErrorHandler,
+    ) {
+        #[cfg(feature = "bevy_debug_stepping")]
+        if let Some(skipped_systems) = _skip_systems {
+            // mark skipped systems as completed
+            self.completed_systems |= skipped_systems;
+        }
+
+        self.order.clear();
+        if self.matches_schedule && self.next_frame < self.trace.frames.len() {
+            self.order.extend(self.trace.start_order(self.next_frame));
+            self.next_frame += 1;
+        } else {
+            self.order.extend(0..schedule.systems.len());
+        }
+
+        for order_index in 0..self.order.len() {
+            let system_index = self.order[order_index];
+            if self.completed_systems.put(system_index) {
+                continue;
+            }
+
+            let mut should_run = true;
+            for set_idx in schedule.sets_with_conditions_of_systems[system_index].ones() {
+                if self.evaluated_sets.contains(set_idx) {
+                    continue;
+                }
+
+                // evaluate system set's conditions
+                let set_conditions_met = evaluate_and_fold_conditions(
+                    &mut schedule.set_conditions[set_idx],
+                    world,
+                    error_handler,
+                );
+
+                if !set_conditions_met {
+                    self.completed_systems
+                        .union_with(&schedule.systems_in_sets_with_conditions[set_idx]);
+                }
+
+                should_run &= set_conditions_met;
+                self.evaluated_sets.insert(set_idx);
+            }
+
+            // evaluate system's conditions
+            should_run &= evaluate_and_fold_conditions(
+                &mut schedule.system_conditions[system_index],
+                world,
+                error_handler,
+            );
+
+            let system = &mut schedule.systems[system_index];
+            if should_run {
+                if let Err(e) = system.validate_param(world) {
+                    if !e.skipped {
+                        error_handler(
+                            e.into(),
+                            ErrorContext::System {
+                                name: system.name(),
+                                last_run: system.get_last_run(),
+                            },
+                        );
+                    }
+                    should_run = false;
+                }
+            }
+
+            if !should_run {
+                continue;
+            }
+
+            if let Some(recorder) = &self.recorder {
+                recorder.record(system_index, TraceEventKind::Started);
+            }
+
+            if is_apply_deferred(system) {
+                self.apply_deferred(schedule, world);
+            } else {
+                let f = AssertUnwindSafe(|| {
+                    if let Err(err) =
+                        __rust_begin_short_backtrace::run_without_applying_deferred(system, world)
+                    {
+                        error_handler(
+                            err,
+                            ErrorContext::System {
+                                name: system.name(),
+                                last_run: system.get_last_run(),
+                            },
+                        );
+                    }
+                });
+
+                #[cfg(feature = "std")]
+                #[expect(clippy::print_stderr, reason = "Allowed behind `std` feature gate.")]
+                {
+                    if let Err(payload) = std::panic::catch_unwind(f) {
+                        eprintln!("Encountered a panic in system `{}`!", &*system.name());
+                        std::panic::resume_unwind(payload);
+                    }
+                }
+
+                #[cfg(not(feature = "std"))]
+                {
+                    (f)();
+                }
+
+                self.unapplied_systems.insert(system_index);
+            }
+
+            if let Some(recorder) = &self.recorder {
+                recorder.record(system_index, TraceEventKind::Finished);
+            }
+        }
+
+        if self.apply_final_deferred {
+            self.apply_deferred(schedule, world);
+        }
+        self.evaluated_sets.clear();
+        self.completed_systems.clear();
+    }
+
+    fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
+        self.apply_final_deferred = apply_final_deferred;
+    }
+
+    fn set_recorder(&mut self, recorder: Option<ExecutionRecorder>) {
+        self.recorder = recorder;
+    }
+}
+
+impl ReplayExecutor {
+    /// Creates a new executor that replays `trace`.
+    pub fn new(trace: ExecutionTrace) -> Self {
+        Self {
+            trace,
+            next_frame: 0,
+            matches_schedule: false,
+            order: Vec::new(),
+            evaluated_sets: FixedBitSet::new(),
+            completed_systems: FixedBitSet::new(),
+            unapplied_systems: FixedBitSet::new(),
+            apply_final_deferred: true,
+            recorder: None,
+        }
+    }
+
+    /// Returns the trace being replayed.
+    pub fn trace(&self) -> &ExecutionTrace {
+        &self.trace
+    }
+
+    /// Returns the number of frames of the trace that have not been replayed yet.
+    pub fn remaining_frames(&self) -> usize {
+        self.trace.frames.len().saturating_sub(self.next_frame)
+    }
+
+    fn apply_deferred(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
+        for system_index in self.unapplied_systems.ones() {
+            let system = &mut schedule.systems[system_index];
+            system.apply_deferred(world);
+        }
+
+        self.unapplied_systems.clear();
+    }
+}
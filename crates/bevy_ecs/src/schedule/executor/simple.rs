@@ -19,7 +19,7 @@ use crate::{
 #[cfg(feature = "hotpatching")]
 use crate::{event::Events, HotPatched};
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, ExecutionRecorder, TraceEventKind};
 
 /// A variant of [`SingleThreadedExecutor`](crate::schedule::SingleThreadedExecutor) that calls
 /// [`apply_deferred`](crate::system::System::apply_deferred) immediately after running each system.
@@ -33,6 +33,8 @@ pub struct SimpleExecutor {
     evaluated_sets: FixedBitSet,
     /// Systems that have run or been skipped.
     completed_systems: FixedBitSet,
+    /// Receives the start and end of each system run, if the schedule is being recorded.
+    recorder: Option<ExecutionRecorder>,
 }
 
 impl SystemExecutor for SimpleExecutor {
@@ -144,6 +146,10 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            if let Some(recorder) = &self.recorder {
+                recorder.record(system_index, TraceEventKind::Started);
+            }
+
             let f = AssertUnwindSafe(|| {
                 if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
                     error_handler(
@@ -169,6 +175,10 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            if let Some(recorder) = &self.recorder {
+                recorder.record(system_index, TraceEventKind::Finished);
+            }
         }
 
         self.evaluated_sets.clear();
@@ -178,6 +188,10 @@ impl SystemExecutor for SimpleExecutor {
     fn set_apply_final_deferred(&mut self, _: bool) {
         // do nothing. simple executor does not do a final sync
     }
+
+    fn set_recorder(&mut self, recorder: Option<ExecutionRecorder>) {
+        self.recorder = recorder;
+    }
 }
 
 impl SimpleExecutor {
@@ -187,6 +201,7 @@ impl SimpleExecutor {
         Self {
             evaluated_sets: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
+            recorder: None,
         }
     }
 }
@@ -15,7 +15,7 @@ use crate::{
 #[cfg(feature = "hotpatching")]
 use crate::{event::Events, HotPatched};
 
-use super::__rust_begin_short_backtrace;
+use super::{__rust_begin_short_backtrace, ExecutionRecorder, TraceEventKind};
 
 /// Runs the schedule using a single thread.
 ///
@@ -31,6 +31,8 @@ pub struct SingleThreadedExecutor {
     unapplied_systems: FixedBitSet,
     /// Setting when true applies deferred system buffers after all systems have run
     apply_final_deferred: bool,
+    /// Receives the start and end of each system run, if the schedule is being recorded.
+    recorder: Option<ExecutionRecorder>,
 }
 
 impl SystemExecutor for SingleThreadedExecutor {
@@ -141,8 +143,15 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            if let Some(recorder) = &self.recorder {
+                recorder.record(system_index, TraceEventKind::Started);
+            }
+
             if is_apply_deferred(system) {
                 self.apply_deferred(schedule, world);
+                if let Some(recorder) = &self.recorder {
+                    recorder.record(system_index, TraceEventKind::Finished);
+                }
                 continue;
             }
 
@@ -175,6 +184,10 @@ impl SystemExecutor for SingleThreadedExecutor {
             }
 
             self.unapplied_systems.insert(system_index);
+
+            if let Some(recorder) = &self.recorder {
+                recorder.record(system_index, TraceEventKind::Finished);
+            }
         }
 
         if self.apply_final_deferred {
@@ -187,6 +200,10 @@ impl SystemExecutor for SingleThreadedExecutor {
     fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
         self.apply_final_deferred = apply_final_deferred;
     }
+
+    fn set_recorder(&mut self, recorder: Option<ExecutionRecorder>) {
+        self.recorder = recorder;
+    }
 }
 
 impl SingleThreadedExecutor {
@@ -199,6 +216,7 @@ impl SingleThreadedExecutor {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             apply_final_deferred: true,
+            recorder: None,
         }
     }
 
@@ -212,7 +230,7 @@ impl SingleThreadedExecutor {
     }
 }
 
-fn evaluate_and_fold_conditions(
+pub(super) fn evaluate_and_fold_conditions(
     conditions: &mut [BoxedCondition],
     world: &mut World,
     error_handler: ErrorHandler,
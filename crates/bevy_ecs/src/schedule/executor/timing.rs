@@ -0,0 +1,295 @@
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+use core::time::Duration;
+
+use crate::{
+    resource::Resource,
+    schedule::{graph::Direction::Outgoing, NodeId, ScheduleGraph, ScheduleLabel, SystemSchedule},
+};
+
+/// The wall time spent running each system and system set.
+///
+/// While this resource exists in a [`World`](crate::world::World), every [`Schedule`] run on that
+/// world measures how long each of its systems takes, with any [`ExecutorKind`], and adds the
+/// measurements here when the run is over. A system set is timed from the moment its first system
+/// starts to the moment its last system finishes, so the time of systems that ran in parallel is
+/// only counted once.
+///
+/// Timings accumulate until they are [cleared](Self::clear), which is usually done once per frame
+/// by whoever reads them.
+///
+/// [`Schedule`]: crate::schedule::Schedule
+/// [`ExecutorKind`]: crate::schedule::ExecutorKind
+#[derive(Resource, Debug, Default)]
+pub struct SystemTimings {
+    systems: HashMap<String, SystemTiming>,
+    sets: HashMap<String, SystemTiming>,
+}
+
+/// The time spent running a system or system set since [`SystemTimings`] were last cleared.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemTiming {
+    /// The total wall time of all runs.
+    pub total: Duration,
+    /// The number of runs.
+    pub runs: u32,
+}
+
+impl SystemTimings {
+    /// Returns the timing of each system that ran since the timings were last cleared.
+    ///
+    /// Systems are identified by the label of their schedule and their name, as in `Update/my_system`.
+    pub fn systems(&self) -> impl Iterator<Item = (&str, SystemTiming)> + '_ {
+        Self::ran(&self.systems)
+    }
+
+    /// Returns the timing of each named system set that ran since the timings were last cleared.
+    ///
+    /// Sets are identified by the label of their schedule and the debug representation of the set,
+    /// as in `Update/MySet`.
+    pub fn sets(&self) -> impl Iterator<Item = (&str, SystemTiming)> + '_ {
+        Self::ran(&self.sets)
+    }
+
+    /// Resets all timings, without forgetting which systems and sets exist.
+    pub fn clear(&mut self) {
+        self.systems
+            .values_mut()
+            .chain(self.sets.values_mut())
+            .for_each(|timing| *timing = SystemTiming::default());
+    }
+
+    fn ran(
+        timings: &HashMap<String, SystemTiming>,
+    ) -> impl Iterator<Item = (&str, SystemTiming)> + '_ {
+        timings
+            .iter()
+            .filter(|(_, timing)| timing.runs > 0)
+            .map(|(key, timing)| (key.as_str(), *timing))
+    }
+
+    fn add(timings: &mut HashMap<String, SystemTiming>, key: &str, duration: Duration) {
+        let timing = match timings.get_mut(key) {
+            Some(timing) => timing,
+            None => timings.entry(key.to_string()).or_default(),
+        };
+        timing.total += duration;
+        timing.runs += 1;
+    }
+}
+
+/// Measures the systems of a schedule for [`SystemTimings`].
+///
+/// Each system has its own slots, so that systems running in parallel can be measured without locking.
+pub(crate) struct SystemTimer {
+    /// The key of each system in [`SystemTimings`].
+    systems: Vec<String>,
+    /// The key of each named set in [`SystemTimings`], and the indices of its systems.
+    sets: Vec<(String, Vec<usize>)>,
+    /// The instant that `spans` are measured from.
+    origin: Instant,
+    /// When each system started and finished during the current run of the schedule, in nanoseconds
+    /// since `origin` plus one, or zero if it didn't.
+    spans: Box<[(AtomicU64, AtomicU64)]>,
+}
+
+impl SystemTimer {
+    pub(crate) fn new(
+        label: &dyn ScheduleLabel,
+        schedule: &SystemSchedule,
+        graph: &ScheduleGraph,
+    ) -> Self {
+        let indices: HashMap<NodeId, usize> = schedule
+            .system_ids
+            .iter()
+            .enumerate()
+            .map(|(index, &id)| (id, index))
+            .collect();
+
+        let sets = graph
+            .system_sets()
+            .filter(|(_, set, _)| !set.is_anonymous() && set.system_type().is_none())
+            .filter_map(|(id, set, _)| {
+                let mut systems = Vec::new();
+                let mut visited = HashSet::new();
+                let mut stack = Vec::from([id]);
+                while let Some(id) = stack.pop() {
+                    for child in graph.hierarchy().graph().neighbors_directed(id, Outgoing) {
+                        if !visited.insert(child) {
+                            continue;
+                        }
+                        match child {
+                            NodeId::System(_) => systems.extend(indices.get(&child)),
+                            NodeId::Set(_) => stack.push(child),
+                        }
+                    }
+                }
+                (!systems.is_empty()).then(|| (format!("{label:?}/{set:?}"), systems))
+            })
+            .collect();
+
+        Self {
+            systems: schedule
+                .systems
+                .iter()
+                .map(|system| format!("{label:?}/{}", system.name()))
+                .collect(),
+            sets,
+            origin: Instant::now(),
+            spans: (0..schedule.systems.len())
+                .map(|_| (AtomicU64::new(0), AtomicU64::new(0)))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn start(&self, system: usize, now: Instant) {
+        let (start, end) = &self.spans[system];
+        start.store(self.since_origin(now), Ordering::Relaxed);
+        end.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn finish(&self, system: usize, now: Instant) {
+        self.spans[system]
+            .1
+            .store(self.since_origin(now), Ordering::Relaxed);
+    }
+
+    fn since_origin(&self, now: Instant) -> u64 {
+        let nanos = now.saturating_duration_since(self.origin).as_nanos();
+        u64::try_from(nanos).unwrap_or(u64::MAX - 1) + 1
+    }
+
+    /// Adds the measurements of the current run to `timings` and gets ready for the next run.
+    ///
+    /// Must only be called once the systems of the run have finished.
+    pub(crate) fn flush(&self, timings: &mut SystemTimings) {
+        let spans: Vec<_> = self
+            .spans
+            .iter()
+            .map(|(start, end)| {
+                let start = start.swap(0, Ordering::Relaxed);
+                let end = end.swap(0, Ordering::Relaxed);
+                (start != 0 && end != 0).then_some((start, end))
+            })
+            .collect();
+
+        for (key, span) in self.systems.iter().zip(&spans) {
+            if let Some((start, end)) = span {
+                SystemTimings::add(&mut timings.systems, key, Duration::from_nanos(end - start));
+            }
+        }
+
+        for (key, systems) in &self.sets {
+            let span = systems
+                .iter()
+                .filter_map(|&system| spans[system])
+                .reduce(|(first, last), (start, end)| (first.min(start), last.max(end)));
+            if let Some((start, end)) = span {
+                SystemTimings::add(&mut timings.sets, key, Duration::from_nanos(end - start));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{IntoScheduleConfigs, Schedule, SystemSet, World},
+        schedule::ExecutorKind,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Outer;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Inner;
+
+    fn a() {}
+    fn b() {}
+    fn c() {}
+
+    fn run_timed(kind: ExecutorKind) {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(kind);
+        schedule.configure_sets(Inner.in_set(Outer));
+        schedule.add_systems(((a, b).in_set(Inner), c).chain());
+
+        // Nothing is measured without the resource, and running doesn't register it.
+        schedule.run(&mut world);
+        assert!(world.components().resource_id::<SystemTimings>().is_none());
+        world.init_resource::<SystemTimings>();
+        schedule.start_recording();
+        schedule.run(&mut world);
+        // Timing keeps going after recording stops.
+        schedule.stop_recording();
+        schedule.run(&mut world);
+
+        let timings = world.resource::<SystemTimings>();
+        let mut systems: Vec<_> = timings
+            .systems()
+            .map(|(key, timing)| (key, timing.runs))
+            .collect();
+        systems.sort();
+        assert_eq!(
+            systems,
+            [
+                (
+                    "DefaultSchedule/bevy_ecs::schedule::executor::timing::tests::a",
+                    2
+                ),
+                (
+                    "DefaultSchedule/bevy_ecs::schedule::executor::timing::tests::b",
+                    2
+                ),
+                (
+                    "DefaultSchedule/bevy_ecs::schedule::executor::timing::tests::c",
+                    2
+                ),
+            ]
+        );
+        let mut sets: Vec<_> = timings
+            .sets()
+            .map(|(key, timing)| (key, timing.runs))
+            .collect();
+        sets.sort();
+        assert_eq!(
+            sets,
+            [("DefaultSchedule/Inner", 2), ("DefaultSchedule/Outer", 2)]
+        );
+
+        world.resource_mut::<SystemTimings>().clear();
+        assert_eq!(world.resource::<SystemTimings>().systems().count(), 0);
+
+        let timings = world.remove_resource::<SystemTimings>().unwrap();
+        schedule.run(&mut world);
+        world.insert_resource(timings);
+        schedule.run(&mut world);
+        assert!(world
+            .resource::<SystemTimings>()
+            .systems()
+            .all(|(_, timing)| timing.runs == 1));
+    }
+
+    #[test]
+    fn single_threaded_timings() {
+        run_timed(ExecutorKind::SingleThreaded);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn multi_threaded_timings() {
+        run_timed(ExecutorKind::MultiThreaded);
+    }
+}
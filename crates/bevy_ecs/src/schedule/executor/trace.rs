@@ -0,0 +1,360 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Instant,
+};
+use core::{fmt, str::FromStr};
+
+use crate::schedule::{ScheduleGraph, ScheduleLabel, SystemSchedule};
+
+use super::timing::{SystemTimer, SystemTimings};
+
+/// Whether a [`TraceEvent`] marks the start or the end of a system run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum TraceEventKind {
+    /// The system started running.
+    Started,
+    /// The system finished running.
+    Finished,
+}
+
+/// A system starting or finishing in an [`ExecutionTrace`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceEvent {
+    /// The index of the system in [`ExecutionTrace::systems`].
+    pub system: usize,
+    /// Whether the system started or finished.
+    pub kind: TraceEventKind,
+}
+
+/// The order in which the systems of a [`Schedule`] started and finished each time it ran.
+///
+/// Traces are recorded with [`Schedule::start_recording`], with any [`ExecutorKind`], and can be
+/// replayed with [`Schedule::replay`] to run the systems in exactly the same order again.
+/// This makes bugs that depend on the order of ambiguous systems reproducible.
+///
+/// A trace can be saved to a file using its [`Display`](fmt::Display) implementation and loaded
+/// back with [`FromStr`], or with `serde` when the `serialize` feature is enabled.
+///
+/// [`Schedule`]: crate::schedule::Schedule
+/// [`Schedule::start_recording`]: crate::schedule::Schedule::start_recording
+/// [`Schedule::replay`]: crate::schedule::Schedule::replay
+/// [`ExecutorKind`]: crate::schedule::ExecutorKind
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionTrace {
+    /// The names of the systems of the schedule, in the order they're stored in.
+    pub systems: Vec<String>,
+    /// The events of each run of the schedule, in the order they happened.
+    pub frames: Vec<Vec<TraceEvent>>,
+}
+
+impl ExecutionTrace {
+    /// Returns the indices of the systems that ran in the given frame, in the order they started.
+    pub fn start_order(&self, frame: usize) -> impl Iterator<Item = usize> + '_ {
+        self.frames
+            .get(frame)
+            .into_iter()
+            .flatten()
+            .filter(|event| event.kind == TraceEventKind::Started)
+            .map(|event| event.system)
+    }
+
+    /// Checks that every event refers to one of the [`systems`](Self::systems) of the trace.
+    pub fn validate(&self) -> Result<(), InvalidExecutionTraceError> {
+        for (frame, events) in self.frames.iter().enumerate() {
+            if let Some(event) = events
+                .iter()
+                .find(|event| event.system >= self.systems.len())
+            {
+                return Err(InvalidExecutionTraceError {
+                    frame,
+                    system: event.system,
+                    system_count: self.systems.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when an [`ExecutionTrace`] refers to a system it doesn't list.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Frame {frame} of the execution trace refers to system {system}, but the trace only lists {system_count} systems")]
+pub struct InvalidExecutionTraceError {
+    /// The index of the frame with the invalid event.
+    pub frame: usize,
+    /// The index of the system the event refers to.
+    pub system: usize,
+    /// The number of systems listed in the trace.
+    pub system_count: usize,
+}
+
+impl fmt::Display for ExecutionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, name) in self.systems.iter().enumerate() {
+            writeln!(f, "system {index} {name}")?;
+        }
+        for frame in &self.frames {
+            writeln!(f, "frame")?;
+            for event in frame {
+                let kind = match event.kind {
+                    TraceEventKind::Started => "start",
+                    TraceEventKind::Finished => "finish",
+                };
+                writeln!(f, "{kind} {}", event.system)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing an [`ExecutionTrace`] fails.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid execution trace at line {line}: `{content}`")]
+pub struct ParseExecutionTraceError {
+    /// The line that could not be parsed, starting at 1.
+    pub line: usize,
+    /// The content of the line.
+    pub content: String,
+}
+
+impl FromStr for ExecutionTrace {
+    type Err = ParseExecutionTraceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut trace = ExecutionTrace::default();
+        for (index, content) in s.lines().enumerate() {
+            let error = || ParseExecutionTraceError {
+                line: index + 1,
+                content: content.to_string(),
+            };
+            let (keyword, rest) = content.split_once(' ').unwrap_or((content, ""));
+            match keyword {
+                "" => {}
+                "system" => {
+                    let (system, name) = rest.split_once(' ').ok_or_else(error)?;
+                    if system.parse() != Ok(trace.systems.len()) {
+                        return Err(error());
+                    }
+                    trace.systems.push(name.to_string());
+                }
+                "frame" => trace.frames.push(Vec::new()),
+                "start" | "finish" => {
+                    let system = rest
+                        .parse()
+                        .ok()
+                        .filter(|&system| system < trace.systems.len())
+                        .ok_or_else(error)?;
+                    let frame = trace.frames.last_mut().ok_or_else(error)?;
+                    frame.push(TraceEvent {
+                        system,
+                        kind: if keyword == "start" {
+                            TraceEventKind::Started
+                        } else {
+                            TraceEventKind::Finished
+                        },
+                    });
+                }
+                _ => return Err(error()),
+            }
+        }
+        Ok(trace)
+    }
+}
+
+/// Collects an [`ExecutionTrace`] and [`SystemTimings`] while a schedule runs, shared between the
+/// schedule and its executor.
+///
+/// The schedule hands its executor a new clone whenever the recorder is initialized, so that the
+/// executor can time systems without going through the shared state.
+#[derive(Clone, Default)]
+pub(crate) struct ExecutionRecorder {
+    shared: Arc<RecorderState>,
+    timer: Option<Arc<SystemTimer>>,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    trace: Mutex<Option<ExecutionTrace>>,
+    /// Whether `trace` is `Some`, so that systems only lock it while a trace is recorded.
+    tracing: AtomicBool,
+    /// Whether the timer should be created the next time the recorder is initialized.
+    timing: AtomicBool,
+}
+
+impl ExecutionRecorder {
+    /// Updates the system names, starting a new trace if the systems of the schedule changed.
+    pub(crate) fn init(
+        &mut self,
+        label: &dyn ScheduleLabel,
+        schedule: &SystemSchedule,
+        graph: &ScheduleGraph,
+    ) {
+        if let Some(trace) = &mut *self.lock_trace() {
+            let changed = trace.systems.len() != schedule.systems.len()
+                || trace
+                    .systems
+                    .iter()
+                    .zip(&schedule.systems)
+                    .any(|(name, system)| *name != system.name());
+            if changed {
+                trace.frames.clear();
+                trace.systems = schedule
+                    .systems
+                    .iter()
+                    .map(|system| system.name().into_owned())
+                    .collect();
+            }
+        }
+        self.timer = self
+            .is_timing()
+            .then(|| Arc::new(SystemTimer::new(label, schedule, graph)));
+    }
+
+    /// Starts a new trace, replacing the one recorded so far.
+    pub(crate) fn start_trace(&self) {
+        *self.lock_trace() = Some(ExecutionTrace::default());
+        self.shared.tracing.store(true, Ordering::Relaxed);
+    }
+
+    /// Stops recording the trace, returning it.
+    pub(crate) fn take_trace(&self) -> Option<ExecutionTrace> {
+        self.shared.tracing.store(false, Ordering::Relaxed);
+        self.lock_trace().take()
+    }
+
+    pub(crate) fn trace(&self) -> Option<ExecutionTrace> {
+        self.lock_trace().clone()
+    }
+
+    /// Whether systems are being timed, or will be once the recorder is initialized.
+    pub(crate) fn is_timing(&self) -> bool {
+        self.shared.timing.load(Ordering::Relaxed)
+    }
+
+    /// Starts or stops timing systems. Takes effect once the recorder is initialized.
+    pub(crate) fn set_timing(&self, timing: bool) {
+        self.shared.timing.store(timing, Ordering::Relaxed);
+    }
+
+    /// Whether the recorder has nothing left to record.
+    pub(crate) fn is_idle(&self) -> bool {
+        !self.shared.tracing.load(Ordering::Relaxed) && !self.is_timing()
+    }
+
+    pub(crate) fn begin_frame(&self) {
+        if let Some(trace) = &mut *self.lock_trace() {
+            trace.frames.push(Vec::new());
+        }
+    }
+
+    pub(crate) fn record(&self, system: usize, kind: TraceEventKind) {
+        if let Some(timer) = &self.timer {
+            let now = Instant::now();
+            match kind {
+                TraceEventKind::Started => timer.start(system, now),
+                TraceEventKind::Finished => timer.finish(system, now),
+            }
+        }
+        if self.shared.tracing.load(Ordering::Relaxed) {
+            if let Some(frame) = self
+                .lock_trace()
+                .as_mut()
+                .and_then(|trace| trace.frames.last_mut())
+            {
+                frame.push(TraceEvent { system, kind });
+            }
+        }
+    }
+
+    /// Adds the timings measured during the last run of the schedule to `timings`.
+    pub(crate) fn flush_timings(&self, timings: &mut SystemTimings) {
+        if let Some(timer) = &self.timer {
+            timer.flush(timings);
+        }
+    }
+
+    fn lock_trace(&self) -> impl core::ops::DerefMut<Target = Option<ExecutionTrace>> + '_ {
+        self.shared
+            .trace
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::Schedule;
+    use alloc::vec;
+
+    #[test]
+    fn trace_round_trip() {
+        let trace = ExecutionTrace {
+            systems: vec!["a".into(), "b<C, D>".into()],
+            frames: vec![
+                vec![
+                    TraceEvent {
+                        system: 1,
+                        kind: TraceEventKind::Started,
+                    },
+                    TraceEvent {
+                        system: 0,
+                        kind: TraceEventKind::Started,
+                    },
+                    TraceEvent {
+                        system: 1,
+                        kind: TraceEventKind::Finished,
+                    },
+                    TraceEvent {
+                        system: 0,
+                        kind: TraceEventKind::Finished,
+                    },
+                ],
+                vec![],
+            ],
+        };
+        let text = trace.to_string();
+        assert_eq!(text.parse::<ExecutionTrace>(), Ok(trace.clone()));
+        assert_eq!(trace.start_order(0).collect::<Vec<_>>(), [1, 0]);
+
+        let error = "system 0 a\nstart 0".parse::<ExecutionTrace>().unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn reject_unknown_systems() {
+        let error = "system 0 a\nframe\nstart 0\nfinish 1"
+            .parse::<ExecutionTrace>()
+            .unwrap_err();
+        assert_eq!(error.line, 4);
+
+        // Traces loaded another way, such as with `serde`, are checked before being replayed.
+        let trace = ExecutionTrace {
+            systems: vec!["a".into()],
+            frames: vec![
+                vec![],
+                vec![TraceEvent {
+                    system: 1,
+                    kind: TraceEventKind::Started,
+                }],
+            ],
+        };
+        let error = InvalidExecutionTraceError {
+            frame: 1,
+            system: 1,
+            system_count: 1,
+        };
+        assert_eq!(trace.validate(), Err(error.clone()));
+        assert_eq!(Schedule::default().replay(trace).err(), Some(error));
+    }
+}
@@ -31,7 +31,7 @@ use crate::{
     resource::Resource,
     schedule::*,
     system::ScheduleSystem,
-    world::World,
+    world::{World, WorldId},
 };
 
 use crate::{query::AccessConflicts, storage::SparseSetIndex};
@@ -222,6 +222,7 @@ fn make_executor(kind: ExecutorKind) -> Box<dyn SystemExecutor> {
         ExecutorKind::SingleThreaded => Box::new(SingleThreadedExecutor::new()),
         #[cfg(feature = "std")]
         ExecutorKind::MultiThreaded => Box::new(MultiThreadedExecutor::new()),
+        ExecutorKind::Replay => Box::new(ReplayExecutor::default()),
     }
 }
 
@@ -332,6 +333,9 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    recorder: Option<ExecutionRecorder>,
+    /// The id of the [`SystemTimings`] resource in the world this schedule last ran in.
+    timings_id: Option<(WorldId, ComponentId)>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -356,6 +360,8 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            recorder: None,
+            timings_id: None,
         };
         // Call `set_build_settings` to add any default build passes
         this.set_build_settings(Default::default());
@@ -466,11 +472,98 @@ impl Schedule {
     pub fn set_executor_kind(&mut self, executor: ExecutorKind) -> &mut Self {
         if executor != self.executor.kind() {
             self.executor = make_executor(executor);
+            self.executor.set_recorder(self.recorder.clone());
             self.executor_initialized = false;
         }
         self
     }
 
+    /// Starts recording the order in which systems start and finish each time this schedule runs,
+    /// replacing any trace recorded so far.
+    ///
+    /// Recording works with every [`ExecutorKind`], and the trace can be retrieved with
+    /// [`recorded_trace`](Self::recorded_trace) and replayed with [`replay`](Self::replay).
+    /// The trace starts over if systems are added to the schedule while recording.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # fn a() {}
+    /// # fn b() {}
+    /// let mut world = World::new();
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems((a, b));
+    ///
+    /// schedule.start_recording();
+    /// schedule.run(&mut world);
+    /// let trace = schedule.stop_recording().unwrap();
+    ///
+    /// // Save the trace to a file with `trace.to_string()` and load it back with `str::parse`.
+    /// let trace = trace.to_string().parse().unwrap();
+    ///
+    /// // Run the schedule again, with the systems in the same order as in the recorded run.
+    /// schedule.replay(trace).unwrap();
+    /// schedule.run(&mut world);
+    /// ```
+    pub fn start_recording(&mut self) -> &mut Self {
+        self.recorder().start_trace();
+        self.executor_initialized = false;
+        self
+    }
+
+    /// Stops recording, returning the trace recorded since [`start_recording`](Self::start_recording)
+    /// was called, or `None` if the schedule wasn't being recorded.
+    pub fn stop_recording(&mut self) -> Option<ExecutionTrace> {
+        let trace = self.recorder.as_ref()?.take_trace();
+        self.remove_idle_recorder();
+        trace
+    }
+
+    /// Returns a copy of the trace recorded so far, or `None` if the schedule isn't being recorded.
+    pub fn recorded_trace(&self) -> Option<ExecutionTrace> {
+        self.recorder.as_ref().and_then(ExecutionRecorder::trace)
+    }
+
+    /// Returns the recorder shared with the executor, creating it if needed.
+    fn recorder(&mut self) -> &ExecutionRecorder {
+        if self.recorder.is_none() {
+            let recorder = ExecutionRecorder::default();
+            self.executor.set_recorder(Some(recorder.clone()));
+            self.recorder = Some(recorder);
+        }
+        self.recorder.as_ref().unwrap()
+    }
+
+    /// Removes the recorder once there is neither a trace to record nor systems to time,
+    /// so the executor doesn't pay for it.
+    fn remove_idle_recorder(&mut self) {
+        if self
+            .recorder
+            .as_ref()
+            .is_some_and(ExecutionRecorder::is_idle)
+        {
+            self.executor.set_recorder(None);
+            self.recorder = None;
+        }
+    }
+
+    /// Switches to a [`ReplayExecutor`] that runs the systems in the order recorded in `trace`,
+    /// one frame of the trace per run of the schedule.
+    ///
+    /// See [`start_recording`](Self::start_recording) for how to record a trace.
+    ///
+    /// Fails without changing the executor if an event of the trace refers to a system
+    /// the trace doesn't list.
+    pub fn replay(
+        &mut self,
+        trace: ExecutionTrace,
+    ) -> Result<&mut Self, InvalidExecutionTraceError> {
+        trace.validate()?;
+        self.executor = Box::new(ReplayExecutor::new(trace));
+        self.executor.set_recorder(self.recorder.clone());
+        self.executor_initialized = false;
+        Ok(self)
+    }
+
     /// Set whether the schedule applies deferred system buffers on final time or not. This is a catch-all
     /// in case a system uses commands but was not explicitly ordered before an instance of
     /// [`ApplyDeferred`]. By default this
@@ -486,11 +579,37 @@ impl Schedule {
         let _span = info_span!("schedule", name = ?self.label).entered();
 
         world.check_change_ticks();
+
+        let timings_id = match self.timings_id {
+            Some((world_id, timings_id)) if world_id == world.id() => Some(timings_id),
+            _ => {
+                // The resource is registered when it is first inserted, which enables timing.
+                let timings_id = world.components().resource_id::<SystemTimings>();
+                self.timings_id = timings_id.map(|timings_id| (world.id(), timings_id));
+                timings_id
+            }
+        };
+        let timing = timings_id.is_some_and(|timings_id| world.contains_resource_by_id(timings_id));
+        if timing
+            != self
+                .recorder
+                .as_ref()
+                .is_some_and(ExecutionRecorder::is_timing)
+        {
+            self.recorder().set_timing(timing);
+            self.remove_idle_recorder();
+            self.executor_initialized = false;
+        }
+
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
         let error_handler = world.default_error_handler();
 
+        if let Some(recorder) = &self.recorder {
+            recorder.begin_frame();
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor
             .run(&mut self.executable, world, None, error_handler);
@@ -509,6 +628,12 @@ impl Schedule {
                 error_handler,
             );
         }
+
+        if let Some(recorder) = &self.recorder {
+            if let Some(mut timings) = world.get_resource_mut::<SystemTimings>() {
+                recorder.flush_timings(&mut timings);
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -534,6 +659,10 @@ impl Schedule {
 
         if !self.executor_initialized {
             self.executor.init(&self.executable);
+            if let Some(recorder) = &mut self.recorder {
+                recorder.init(&*self.label, &self.executable, &self.graph);
+                self.executor.set_recorder(Some(recorder.clone()));
+            }
             self.executor_initialized = true;
         }
 
@@ -146,6 +146,7 @@ impl<const SEND: bool> ResourceData<SEND> {
                 TickCells {
                     added: &self.added_ticks,
                     changed: &self.changed_ticks,
+                    storage: None,
                 },
                 self.changed_by.as_ref(),
             )
@@ -1,6 +1,6 @@
 use crate::{
     change_detection::MaybeLocation,
-    component::{ComponentId, ComponentInfo, ComponentTicks, Tick, TickCells},
+    component::{ComponentId, ComponentInfo, ComponentTicks, StorageTicks, Tick, TickCells},
     entity::{Entity, EntityRow},
     storage::{Column, TableRow},
 };
@@ -155,6 +155,20 @@ impl ComponentSparseSet {
         self.dense.len() == 0
     }
 
+    /// Returns the number of component values the sparse set can store without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.dense.capacity()
+    }
+
+    /// Returns the number of bytes allocated to map entities to their component values.
+    pub(crate) fn index_bytes(&self) -> usize {
+        fn vec_bytes<T>(vec: &Vec<T>) -> usize {
+            vec.capacity() * size_of::<T>()
+        }
+        vec_bytes(&self.entities) + vec_bytes(&self.sparse.values)
+    }
+
     /// Inserts the `entity` key and component `value` pair into this sparse
     /// set.
     ///
@@ -244,12 +258,23 @@ impl ComponentSparseSet {
                 TickCells {
                     added: self.dense.get_added_tick_unchecked(dense_index),
                     changed: self.dense.get_changed_tick_unchecked(dense_index),
+                    storage: Some(self.dense.get_storage_ticks()),
                 },
                 self.dense.get_changed_by_unchecked(dense_index),
             ))
         }
     }
 
+    /// Returns the newest [`ticks`](StorageTicks) of all component values in the sparse set.
+    ///
+    /// There is a single set of ticks for the whole sparse set, not one per chunk of values, so a
+    /// single value being added or changed makes the [`Added`](crate::query::Added) and
+    /// [`Changed`](crate::query::Changed) filters check every value of the sparse set.
+    #[inline]
+    pub fn get_storage_ticks(&self) -> &StorageTicks {
+        self.dense.get_storage_ticks()
+    }
+
     /// Returns a reference to the "added" tick of the entity's component value.
     ///
     /// Returns `None` if `entity` does not have a component in the sparse set.
@@ -0,0 +1,393 @@
+use alloc::{string::String, vec, vec::Vec};
+use bevy_platform::collections::HashMap;
+use core::{cell::UnsafeCell, panic::Location};
+
+use crate::{
+    archetype::ArchetypeId,
+    component::{ComponentId, Components, StorageType, Tick},
+    entity::Entity,
+    storage::TableId,
+    world::World,
+};
+
+/// Bytes stored next to every component value for change detection.
+const TICK_BYTES: usize = 2 * size_of::<UnsafeCell<Tick>>()
+    + if cfg!(feature = "track_location") {
+        size_of::<UnsafeCell<&'static Location<'static>>>()
+    } else {
+        0
+    };
+
+/// A snapshot of the memory used by the storages of a [`World`], returned by [`World::memory_stats`].
+///
+/// Byte counts cover the heap allocations of the component and resource storages, including
+/// change detection ticks. "Allocated" bytes include unused capacity while "used" bytes only
+/// count live values, so the difference between the two is memory held by over-allocated storages.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStats {
+    /// Per-component statistics, in order of [`ComponentId`].
+    pub components: Vec<ComponentMemoryStats>,
+    /// Per-table statistics, in order of [`TableId`].
+    pub tables: Vec<TableMemoryStats>,
+    /// Per-archetype statistics, in order of [`ArchetypeId`].
+    pub archetypes: Vec<ArchetypeStats>,
+    /// Per-resource statistics, including `!Send` resources.
+    pub resources: Vec<ResourceMemoryStats>,
+}
+
+impl MemoryStats {
+    /// Returns the total number of bytes allocated by the component and resource storages.
+    pub fn allocated_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(|table| table.allocated_bytes)
+            .chain(
+                self.components
+                    .iter()
+                    .filter(|component| component.storage_type == StorageType::SparseSet)
+                    .map(|component| component.allocated_bytes),
+            )
+            .sum::<usize>()
+            + self.resource_bytes()
+    }
+
+    /// Returns the number of allocated bytes that hold live values.
+    pub fn used_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(|table| table.used_bytes)
+            .chain(
+                self.components
+                    .iter()
+                    .filter(|component| component.storage_type == StorageType::SparseSet)
+                    .map(|component| component.used_bytes),
+            )
+            .sum::<usize>()
+            + self.resource_bytes()
+    }
+
+    /// Returns the number of bytes used by resources.
+    pub fn resource_bytes(&self) -> usize {
+        self.resources.iter().map(|resource| resource.bytes).sum()
+    }
+
+    /// Returns the number of entities stored in archetypes.
+    pub fn entity_count(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(|archetype| archetype.entity_count)
+            .sum()
+    }
+
+    /// Returns the number of archetypes without any entities.
+    ///
+    /// Archetypes are never removed, so a large number of empty archetypes usually points to
+    /// entities frequently moving between many different component combinations.
+    pub fn empty_archetype_count(&self) -> usize {
+        self.archetypes
+            .iter()
+            .filter(|archetype| archetype.entity_count == 0)
+            .count()
+    }
+
+    /// Returns the fraction of the memory allocated by tables that is unused, between `0.0` and `1.0`.
+    pub fn table_fragmentation(&self) -> f64 {
+        let (allocated, used) = self.tables.iter().fold((0, 0), |(allocated, used), table| {
+            (allocated + table.allocated_bytes, used + table.used_bytes)
+        });
+        unused_fraction(allocated, used)
+    }
+
+    /// Returns the component statistics sorted by allocated bytes, largest first.
+    pub fn largest_components(&self) -> Vec<&ComponentMemoryStats> {
+        let mut components: Vec<_> = self.components.iter().collect();
+        components.sort_by_key(|component| core::cmp::Reverse(component.allocated_bytes));
+        components
+    }
+}
+
+/// Memory used by all values of a single component, see [`MemoryStats`].
+#[derive(Debug, Clone)]
+pub struct ComponentMemoryStats {
+    /// The id of the component.
+    pub id: ComponentId,
+    /// The name of the component.
+    pub name: String,
+    /// How the component is stored.
+    pub storage_type: StorageType,
+    /// The number of values of the component.
+    pub len: usize,
+    /// The number of values the storages of the component can hold without reallocating.
+    pub capacity: usize,
+    /// The number of tables with a column for this component, `0` for sparse set components.
+    pub table_count: usize,
+    /// The number of bytes allocated for the component, including sparse set bookkeeping.
+    pub allocated_bytes: usize,
+    /// The number of allocated bytes that hold live values.
+    pub used_bytes: usize,
+}
+
+/// Memory used by a single [`Table`](crate::storage::Table), see [`MemoryStats`].
+#[derive(Debug, Clone)]
+pub struct TableMemoryStats {
+    /// The id of the table.
+    pub id: TableId,
+    /// The number of entities in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can hold without reallocating.
+    pub capacity: usize,
+    /// The number of columns of the table.
+    pub component_count: usize,
+    /// The number of archetypes storing their table components in this table.
+    pub archetype_count: usize,
+    /// The number of bytes allocated by the table.
+    pub allocated_bytes: usize,
+    /// The number of allocated bytes that hold live values.
+    pub used_bytes: usize,
+}
+
+impl TableMemoryStats {
+    /// Returns the fraction of the memory allocated by the table that is unused, between `0.0` and `1.0`.
+    pub fn fragmentation(&self) -> f64 {
+        unused_fraction(self.allocated_bytes, self.used_bytes)
+    }
+}
+
+/// Statistics of a single [`Archetype`](crate::archetype::Archetype), see [`MemoryStats`].
+#[derive(Debug, Clone)]
+pub struct ArchetypeStats {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The table the archetype stores its table components in.
+    pub table_id: TableId,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The number of components of the archetype.
+    pub component_count: usize,
+}
+
+/// Memory used by a single resource, see [`MemoryStats`].
+#[derive(Debug, Clone)]
+pub struct ResourceMemoryStats {
+    /// The id of the resource.
+    pub id: ComponentId,
+    /// The name of the resource.
+    pub name: String,
+    /// The number of bytes used by the resource, `0` if it is not present.
+    pub bytes: usize,
+    /// Whether the resource is `Send`.
+    pub send: bool,
+}
+
+fn unused_fraction(allocated: usize, used: usize) -> f64 {
+    if allocated == 0 {
+        0.0
+    } else {
+        1.0 - used as f64 / allocated as f64
+    }
+}
+
+fn component_entry<'a>(
+    stats: &'a mut HashMap<ComponentId, ComponentMemoryStats>,
+    components: &Components,
+    id: ComponentId,
+    storage_type: StorageType,
+) -> &'a mut ComponentMemoryStats {
+    stats.entry(id).or_insert_with(|| ComponentMemoryStats {
+        id,
+        name: components
+            .get_name(id)
+            .map(String::from)
+            .unwrap_or_default(),
+        storage_type,
+        len: 0,
+        capacity: 0,
+        table_count: 0,
+        allocated_bytes: 0,
+        used_bytes: 0,
+    })
+}
+
+impl World {
+    /// Collects [`MemoryStats`] describing the memory used by the storages of this world.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position([f32; 3]);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_batch((0..100).map(|_| Position([0.0; 3])));
+    ///
+    /// let stats = world.memory_stats();
+    /// let position = stats.largest_components()[0];
+    /// assert_eq!(position.len, 100);
+    /// assert!(position.used_bytes >= 100 * size_of::<Position>());
+    /// ```
+    pub fn memory_stats(&self) -> MemoryStats {
+        let components = self.components();
+        let storages = self.storages();
+
+        let mut archetype_counts = vec![0; storages.tables.len()];
+        let archetypes = self
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                archetype_counts[archetype.table_id().as_usize()] += 1;
+                ArchetypeStats {
+                    id: archetype.id(),
+                    table_id: archetype.table_id(),
+                    entity_count: archetype.len() as usize,
+                    component_count: archetype.component_count(),
+                }
+            })
+            .collect();
+
+        let mut component_stats = HashMap::<ComponentId, ComponentMemoryStats>::default();
+        let tables = storages
+            .tables
+            .iter()
+            .enumerate()
+            .map(|(index, table)| {
+                let id = TableId::from_usize(index);
+                let len = table.entity_count() as usize;
+                let capacity = table.capacity();
+                let mut row_bytes = size_of::<Entity>();
+                for component in table.component_ids() {
+                    let slot_bytes = components
+                        .get_info(component)
+                        .map_or(0, |info| info.layout().size())
+                        + TICK_BYTES;
+                    row_bytes += slot_bytes;
+
+                    let stats = component_entry(
+                        &mut component_stats,
+                        components,
+                        component,
+                        StorageType::Table,
+                    );
+                    stats.len += len;
+                    stats.capacity += capacity;
+                    stats.table_count += 1;
+                    stats.allocated_bytes += capacity * slot_bytes;
+                    stats.used_bytes += len * slot_bytes;
+                }
+                TableMemoryStats {
+                    id,
+                    entity_count: len,
+                    capacity,
+                    component_count: table.component_count(),
+                    archetype_count: archetype_counts[index],
+                    allocated_bytes: capacity * row_bytes,
+                    used_bytes: len * row_bytes,
+                }
+            })
+            .collect();
+
+        for (component, sparse_set) in storages.sparse_sets.iter() {
+            let slot_bytes = components
+                .get_info(component)
+                .map_or(0, |info| info.layout().size())
+                + TICK_BYTES;
+            let stats = component_entry(
+                &mut component_stats,
+                components,
+                component,
+                StorageType::SparseSet,
+            );
+            stats.len = sparse_set.len();
+            stats.capacity = sparse_set.capacity();
+            stats.allocated_bytes = sparse_set.capacity() * slot_bytes + sparse_set.index_bytes();
+            stats.used_bytes = sparse_set.len() * slot_bytes;
+        }
+
+        let mut components_stats: Vec<_> = component_stats.into_values().collect();
+        components_stats.sort_by_key(|stats| stats.id);
+
+        let resource_stats = |id: ComponentId, present: bool, send: bool| ResourceMemoryStats {
+            id,
+            name: components
+                .get_name(id)
+                .map(String::from)
+                .unwrap_or_default(),
+            bytes: if present {
+                components
+                    .get_info(id)
+                    .map_or(0, |info| info.layout().size())
+            } else {
+                0
+            },
+            send,
+        };
+        let resources = storages
+            .resources
+            .iter()
+            .map(|(id, data)| resource_stats(id, data.is_present(), true))
+            .chain(
+                storages
+                    .non_send_resources
+                    .iter()
+                    .map(|(id, data)| resource_stats(id, data.is_present(), false)),
+            )
+            .collect();
+
+        MemoryStats {
+            components: components_stats,
+            tables,
+            archetypes,
+            resources,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{component::StorageType, prelude::*};
+
+    #[derive(Component)]
+    struct A(#[expect(dead_code, reason = "only used for its size")] u64);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct S(#[expect(dead_code, reason = "only used for its size")] u32);
+
+    #[derive(Resource)]
+    struct R(#[expect(dead_code, reason = "only used for its size")] [u8; 16]);
+
+    #[test]
+    fn memory_stats() {
+        let mut world = World::new();
+        world.insert_resource(R([0; 16]));
+        for i in 0..10 {
+            world.spawn((A(i), S(0)));
+        }
+        let a_only = world.spawn(A(0)).id();
+
+        let stats = world.memory_stats();
+        let a_id = world.component_id::<A>().unwrap();
+        let a = stats.components.iter().find(|c| c.id == a_id).unwrap();
+        assert_eq!(a.storage_type, StorageType::Table);
+        assert_eq!(a.len, 11);
+        assert_eq!(a.table_count, 1);
+        assert!(a.capacity >= 11);
+        assert!(a.used_bytes >= 11 * size_of::<A>());
+        assert!(a.allocated_bytes >= a.used_bytes);
+
+        let s_id = world.component_id::<S>().unwrap();
+        let s = stats.components.iter().find(|c| c.id == s_id).unwrap();
+        assert_eq!(s.storage_type, StorageType::SparseSet);
+        assert_eq!(s.len, 10);
+        assert_eq!(s.table_count, 0);
+
+        let r_id = world.resource_id::<R>().unwrap();
+        let r = stats.resources.iter().find(|r| r.id == r_id).unwrap();
+        assert_eq!(r.bytes, 16);
+
+        assert_eq!(stats.entity_count(), 11);
+        assert!(stats.allocated_bytes() >= stats.used_bytes());
+        assert!((0.0..=1.0).contains(&stats.table_fragmentation()));
+        let table_id = world.entity(a_only).location().table_id;
+        let table = &stats.tables[table_id.as_usize()];
+        assert_eq!(table.archetype_count, 2);
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
     change_detection::MaybeLocation,
-    component::TickCells,
+    component::{StorageTicks, TickCells},
     storage::{blob_array::BlobArray, thin_array_ptr::ThinArrayPtr},
 };
 use alloc::vec::Vec;
@@ -20,6 +20,7 @@ pub struct ThinColumn {
     pub(super) added_ticks: ThinArrayPtr<UnsafeCell<Tick>>,
     pub(super) changed_ticks: ThinArrayPtr<UnsafeCell<Tick>>,
     pub(super) changed_by: MaybeLocation<ThinArrayPtr<UnsafeCell<&'static Location<'static>>>>,
+    pub(super) storage_ticks: StorageTicks,
 }
 
 impl ThinColumn {
@@ -33,6 +34,7 @@ impl ThinColumn {
             added_ticks: ThinArrayPtr::with_capacity(capacity),
             changed_ticks: ThinArrayPtr::with_capacity(capacity),
             changed_by: MaybeLocation::new_with(|| ThinArrayPtr::with_capacity(capacity)),
+            storage_ticks: StorageTicks::default(),
         }
     }
 
@@ -151,6 +153,7 @@ impl ThinColumn {
         self.data.initialize_unchecked(row.index(), data);
         *self.added_ticks.get_unchecked_mut(row.index()).get_mut() = tick;
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = tick;
+        self.storage_ticks.merge(ComponentTicks::new(tick), tick);
         self.changed_by
             .as_mut()
             .map(|changed_by| changed_by.get_unchecked_mut(row.index()).get_mut())
@@ -172,6 +175,7 @@ impl ThinColumn {
     ) {
         self.data.replace_unchecked(row.index(), data);
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = change_tick;
+        self.storage_ticks.merge_changed(change_tick, change_tick);
         self.changed_by
             .as_mut()
             .map(|changed_by| changed_by.get_unchecked_mut(row.index()).get_mut())
@@ -182,6 +186,8 @@ impl ThinColumn {
     /// into the current column to initialize the values at `dst_row`.
     /// Does not do any bounds checking.
     ///
+    /// `change_tick` is the current tick of the world.
+    ///
     /// # Safety
     ///  - `other` must have the same data layout as `self`
     ///  - `src_row` must be in bounds for `other`
@@ -195,6 +201,7 @@ impl ThinColumn {
         other_last_element_index: usize,
         src_row: TableRow,
         dst_row: TableRow,
+        change_tick: Tick,
     ) {
         debug_assert!(self.data.layout() == other.data.layout());
         // Init the data
@@ -205,15 +212,24 @@ impl ThinColumn {
         // Init added_ticks
         let added_tick = other
             .added_ticks
-            .swap_remove_unchecked(src_row.index(), other_last_element_index);
+            .swap_remove_unchecked(src_row.index(), other_last_element_index)
+            .into_inner();
         self.added_ticks
-            .initialize_unchecked(dst_row.index(), added_tick);
+            .initialize_unchecked(dst_row.index(), UnsafeCell::new(added_tick));
         // Init changed_ticks
         let changed_tick = other
             .changed_ticks
-            .swap_remove_unchecked(src_row.index(), other_last_element_index);
+            .swap_remove_unchecked(src_row.index(), other_last_element_index)
+            .into_inner();
         self.changed_ticks
-            .initialize_unchecked(dst_row.index(), changed_tick);
+            .initialize_unchecked(dst_row.index(), UnsafeCell::new(changed_tick));
+        self.storage_ticks.merge(
+            ComponentTicks {
+                added: added_tick,
+                changed: changed_tick,
+            },
+            change_tick,
+        );
         self.changed_by.as_mut().zip(other.changed_by.as_mut()).map(
             |(self_changed_by, other_changed_by)| {
                 let changed_by = other_changed_by
@@ -243,6 +259,7 @@ impl ThinColumn {
                 .get_mut()
                 .check_tick(change_tick);
         }
+        self.storage_ticks.check_change_ticks(change_tick);
     }
 
     /// Clear all the components from this column.
@@ -314,6 +331,12 @@ impl ThinColumn {
         self.changed_ticks.as_slice(len)
     }
 
+    /// Get the newest [`ticks`](StorageTicks) of all values in this [`ThinColumn`].
+    #[inline]
+    pub fn get_storage_ticks(&self) -> &StorageTicks {
+        &self.storage_ticks
+    }
+
     /// Get a slice to the calling locations that last changed each value in this [`ThinColumn`]
     ///
     /// # Safety
@@ -344,6 +367,7 @@ pub struct Column {
     pub(super) added_ticks: Vec<UnsafeCell<Tick>>,
     pub(super) changed_ticks: Vec<UnsafeCell<Tick>>,
     changed_by: MaybeLocation<Vec<UnsafeCell<&'static Location<'static>>>>,
+    storage_ticks: StorageTicks,
 }
 
 impl Column {
@@ -356,6 +380,7 @@ impl Column {
             added_ticks: Vec::with_capacity(capacity),
             changed_ticks: Vec::with_capacity(capacity),
             changed_by: MaybeLocation::new_with(|| Vec::with_capacity(capacity)),
+            storage_ticks: StorageTicks::default(),
         }
     }
 
@@ -381,6 +406,7 @@ impl Column {
         debug_assert!(row.index() < self.len());
         self.data.replace_unchecked(row.index(), data);
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = change_tick;
+        self.storage_ticks.merge_changed(change_tick, change_tick);
         self.changed_by
             .as_mut()
             .map(|changed_by| changed_by.get_unchecked_mut(row.index()).get_mut())
@@ -393,6 +419,12 @@ impl Column {
         self.data.len()
     }
 
+    /// Gets the number of elements the column can store without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
     /// Checks if the column is empty. Returns `true` if there are no elements, `false` otherwise.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -447,6 +479,8 @@ impl Column {
 
     /// Pushes a new value onto the end of the [`Column`].
     ///
+    /// `ticks.changed` must be the current tick of the world.
+    ///
     /// # Safety
     /// `ptr` must point to valid data of this column's component type
     pub(crate) unsafe fn push(
@@ -458,6 +492,7 @@ impl Column {
         self.data.push(ptr);
         self.added_ticks.push(UnsafeCell::new(ticks.added));
         self.changed_ticks.push(UnsafeCell::new(ticks.changed));
+        self.storage_ticks.merge(ticks, ticks.changed);
         self.changed_by
             .as_mut()
             .zip(caller)
@@ -506,6 +541,12 @@ impl Column {
         &self.changed_ticks
     }
 
+    /// Fetches the newest [`ticks`](StorageTicks) of all values in the [`Column`].
+    #[inline]
+    pub fn get_storage_ticks(&self) -> &StorageTicks {
+        &self.storage_ticks
+    }
+
     /// Fetches a reference to the data and change detection ticks at `row`.
     ///
     /// Returns `None` if `row` is out of bounds.
@@ -520,6 +561,7 @@ impl Column {
                     TickCells {
                         added: self.added_ticks.get_unchecked(row.index()),
                         changed: self.changed_ticks.get_unchecked(row.index()),
+                        storage: Some(&self.storage_ticks),
                     },
                 )
             })
@@ -653,6 +695,7 @@ impl Column {
         for component_ticks in &mut self.changed_ticks {
             component_ticks.get_mut().check_tick(change_tick);
         }
+        self.storage_ticks.check_change_ticks(change_tick);
     }
 
     /// Fetches the calling location that last changed the value at `row`.
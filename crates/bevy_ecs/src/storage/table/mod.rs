@@ -1,6 +1,6 @@
 use crate::{
     change_detection::MaybeLocation,
-    component::{ComponentId, ComponentInfo, ComponentTicks, Components, Tick},
+    component::{ComponentId, ComponentInfo, ComponentTicks, Components, StorageTicks, Tick},
     entity::Entity,
     query::DebugCheckedUnwrap,
     storage::{blob_vec::BlobVec, ImmutableSparseSet, SparseSet},
@@ -253,12 +253,15 @@ impl Table {
     /// the caller's responsibility to drop them.  Failure to do so may result in resources not
     /// being released (i.e. files handles not being released, memory leaks, etc.)
     ///
+    /// `change_tick` is the current tick of the world.
+    ///
     /// # Safety
     /// - `row` must be in-bounds
     pub(crate) unsafe fn move_to_and_forget_missing_unchecked(
         &mut self,
         row: TableRow,
         new_table: &mut Table,
+        change_tick: Tick,
     ) -> TableMoveResult {
         debug_assert!(row.index_u32() < self.entity_count());
         let last_element_index = self.entity_count() - 1;
@@ -271,6 +274,7 @@ impl Table {
                     last_element_index as usize,
                     row,
                     new_row,
+                    change_tick,
                 );
             } else {
                 // It's the caller's responsibility to drop these cases.
@@ -292,12 +296,15 @@ impl Table {
     /// Returns the index of the new row in `new_table` and the entity in this table swapped in
     /// to replace it (if an entity was swapped in).
     ///
+    /// `change_tick` is the current tick of the world.
+    ///
     /// # Safety
     /// row must be in-bounds
     pub(crate) unsafe fn move_to_and_drop_missing_unchecked(
         &mut self,
         row: TableRow,
         new_table: &mut Table,
+        change_tick: Tick,
     ) -> TableMoveResult {
         debug_assert!(row.index_u32() < self.entity_count());
         let last_element_index = self.entity_count() - 1;
@@ -310,6 +317,7 @@ impl Table {
                     last_element_index as usize,
                     row,
                     new_row,
+                    change_tick,
                 );
             } else {
                 column.swap_remove_and_drop_unchecked(last_element_index as usize, row);
@@ -330,6 +338,8 @@ impl Table {
     /// Returns the index of the new row in `new_table` and the entity in this table swapped in
     /// to replace it (if an entity was swapped in).
     ///
+    /// `change_tick` is the current tick of the world.
+    ///
     /// # Safety
     /// - `row` must be in-bounds
     /// - `new_table` must contain every component this table has
@@ -337,6 +347,7 @@ impl Table {
         &mut self,
         row: TableRow,
         new_table: &mut Table,
+        change_tick: Tick,
     ) -> TableMoveResult {
         debug_assert!(row.index_u32() < self.entity_count());
         let last_element_index = self.entity_count() - 1;
@@ -346,7 +357,13 @@ impl Table {
             new_table
                 .get_column_mut(*component_id)
                 .debug_checked_unwrap()
-                .initialize_from_unchecked(column, last_element_index as usize, row, new_row);
+                .initialize_from_unchecked(
+                    column,
+                    last_element_index as usize,
+                    row,
+                    new_row,
+                    change_tick,
+                );
         }
         TableMoveResult {
             new_row,
@@ -392,6 +409,12 @@ impl Table {
             .map(|col| unsafe { col.get_changed_ticks_slice(self.entity_count() as usize) })
     }
 
+    /// Get the newest [`ticks`](StorageTicks) of the column matching `component_id`.
+    pub fn get_storage_ticks_for(&self, component_id: ComponentId) -> Option<&StorageTicks> {
+        self.get_column(component_id)
+            .map(ThinColumn::get_storage_ticks)
+    }
+
     /// Fetches the calling locations that last changed the each component
     pub fn get_changed_by_slice_for(
         &self,
@@ -642,6 +665,11 @@ impl Table {
         self.columns.values()
     }
 
+    /// Iterates over the [`ComponentId`]s of the [`ThinColumn`]s of the [`Table`].
+    pub fn component_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.columns.indices()
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         let len = self.entity_count() as usize;
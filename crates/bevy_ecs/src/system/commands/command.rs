@@ -17,6 +17,24 @@ use crate::{
     world::{FromWorld, SpawnBatchIter, World},
 };
 
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::{
+        archetype::ArchetypeEntity,
+        component::Tick,
+        error::BevyError,
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        world::{CommandQueue, WorldSnapshot},
+    },
+    alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    },
+    bevy_platform::sync::{Arc, Mutex, MutexGuard, PoisonError},
+    bevy_reflect::TypeRegistry,
+    log::warn,
+};
+
 /// A [`World`] mutation.
 ///
 /// Should be used with [`Commands::queue`](crate::system::Commands::queue).
@@ -237,3 +255,164 @@ pub fn send_event<E: Event>(event: E) -> impl Command {
         events.send_with_caller(event, caller);
     }
 }
+
+/// The first error returned by the commands of a [`transaction`] and the entities they spawned,
+/// shared between them.
+#[cfg(feature = "bevy_reflect")]
+#[doc(hidden)]
+#[derive(Clone, Default)]
+pub struct TransactionState(Arc<TransactionData>);
+
+#[cfg(feature = "bevy_reflect")]
+#[derive(Default)]
+struct TransactionData {
+    error: Mutex<Option<BevyError>>,
+    spawned: Mutex<Vec<Entity>>,
+    parent: Option<TransactionState>,
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl TransactionState {
+    /// Creates the state of a transaction nested in `parent`, if any.
+    pub(crate) fn new(parent: Option<&TransactionState>) -> Self {
+        Self(Arc::new(TransactionData {
+            parent: parent.cloned(),
+            ..Default::default()
+        }))
+    }
+
+    fn error(&self) -> MutexGuard<'_, Option<BevyError>> {
+        self.0.error.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn spawned(&self) -> MutexGuard<'_, Vec<Entity>> {
+        self.0
+            .spawned
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns `true` if one of the commands of the transaction failed.
+    pub(crate) fn failed(&self) -> bool {
+        self.error().is_some()
+    }
+
+    /// Fails the transaction with `error`, unless it already failed.
+    pub(crate) fn fail(&self, error: BevyError) {
+        self.error().get_or_insert(error);
+    }
+
+    /// Records that `entity` was spawned by this transaction and the transactions it is nested in,
+    /// so that a rollback of any of them despawns it.
+    pub(crate) fn spawn(&self, entity: Entity) {
+        let mut state = Some(self);
+        while let Some(current) = state {
+            current.spawned().push(entity);
+            state = current.0.parent.as_ref();
+        }
+    }
+}
+
+/// A [`Command`] that applies the commands in `queue` atomically.
+///
+/// The commands must have been queued with a [`Commands`](crate::system::Commands) sharing `state`,
+/// so that the first command returning an error marks the transaction as failed and the remaining
+/// commands are skipped.
+/// The state of the world is captured in a [`WorldSnapshot`] before applying the commands, and
+/// restored if the transaction failed, in which case the entities spawned by the transaction are
+/// despawned and the error is returned.
+///
+/// See [`Commands::transaction`](crate::system::Commands::transaction) for more details.
+#[cfg(feature = "bevy_reflect")]
+pub(crate) fn transaction(
+    mut queue: CommandQueue,
+    state: TransactionState,
+) -> impl Command<Result> {
+    move |world: &mut World| -> Result {
+        if queue.is_empty() {
+            return Ok(());
+        }
+
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .cloned()
+            .unwrap_or_default();
+        let snapshot = WorldSnapshot::capture(world, &registry.read());
+        let last_run = world.increment_change_tick();
+        queue.apply(world);
+
+        let error = state.error().take();
+        let Some(error) = error else {
+            return Ok(());
+        };
+
+        let registry = registry.read();
+        let spawned = core::mem::take(&mut *state.spawned());
+        let unrestored = unrestored_changes(world, &registry, &spawned, last_run);
+        if !unrestored.is_empty() {
+            warn!(
+                "A transaction was rolled back, but changes to {} could not be undone, since they are not registered for reflection with `ReflectComponent` or `ReflectResource`.",
+                unrestored.join(", ")
+            );
+        }
+
+        snapshot.restore(world, &registry);
+        for entity in spawned {
+            if let Ok(entity) = world.get_entity_mut(entity) {
+                entity.despawn();
+            }
+        }
+        Err(error)
+    }
+}
+
+/// Returns the names of the components and resources that are not registered for reflection,
+/// and were added or changed since `last_run` outside of the `spawned` entities.
+#[cfg(feature = "bevy_reflect")]
+fn unrestored_changes(
+    world: &World,
+    registry: &TypeRegistry,
+    spawned: &[Entity],
+    last_run: Tick,
+) -> Vec<String> {
+    let this_run = world.read_change_tick();
+    let mut names = Vec::new();
+    for archetype in world.archetypes().iter() {
+        for component_id in archetype.components() {
+            let Some(info) = world.components().get_info(component_id) else {
+                continue;
+            };
+            let reflected = info.type_id().is_some_and(|type_id| {
+                registry
+                    .get_type_data::<ReflectComponent>(type_id)
+                    .is_some()
+            });
+            if reflected || names.iter().any(|name| name == info.name()) {
+                continue;
+            }
+            let changed = archetype
+                .entities()
+                .iter()
+                .map(ArchetypeEntity::id)
+                .filter(|entity| !spawned.contains(entity))
+                .filter_map(|entity| world.entity(entity).get_change_ticks_by_id(component_id))
+                .any(|ticks| ticks.is_changed(last_run, this_run));
+            if changed {
+                names.push(info.name().to_string());
+            }
+        }
+    }
+    for (info, _) in world.iter_resources() {
+        let reflected = info
+            .type_id()
+            .is_some_and(|type_id| registry.get_type_data::<ReflectResource>(type_id).is_some());
+        if !reflected
+            && world
+                .get_resource_change_ticks_by_id(info.id())
+                .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+        {
+            names.push(info.name().to_string());
+        }
+    }
+    names
+}
@@ -100,6 +100,9 @@ use crate::{
 pub struct Commands<'w, 's> {
     queue: InternalQueue<'s>,
     entities: &'w Entities,
+    /// The state of the [transaction](Commands::transaction) the commands are queued into, if any.
+    #[cfg(feature = "bevy_reflect")]
+    transaction: Option<&'s command::TransactionState>,
 }
 
 // SAFETY: All commands [`Command`] implement [`Send`]
@@ -180,6 +183,8 @@ const _: () = {
             Commands {
                 queue: InternalQueue::CommandQueue(f0),
                 entities: f1,
+                #[cfg(feature = "bevy_reflect")]
+                transaction: None,
             }
         }
     }
@@ -208,6 +213,8 @@ impl<'w, 's> Commands<'w, 's> {
         Self {
             queue: InternalQueue::CommandQueue(Deferred(queue)),
             entities,
+            #[cfg(feature = "bevy_reflect")]
+            transaction: None,
         }
     }
 
@@ -225,6 +232,8 @@ impl<'w, 's> Commands<'w, 's> {
         Self {
             queue: InternalQueue::RawCommandQueue(queue),
             entities,
+            #[cfg(feature = "bevy_reflect")]
+            transaction: None,
         }
     }
 
@@ -256,6 +265,8 @@ impl<'w, 's> Commands<'w, 's> {
                 }
             },
             entities: self.entities,
+            #[cfg(feature = "bevy_reflect")]
+            transaction: self.transaction,
         }
     }
 
@@ -305,6 +316,10 @@ impl<'w, 's> Commands<'w, 's> {
     #[track_caller]
     pub fn spawn_empty(&mut self) -> EntityCommands {
         let entity = self.entities.reserve_entity();
+        #[cfg(feature = "bevy_reflect")]
+        if let Some(transaction) = self.transaction {
+            transaction.spawn(entity);
+        }
         let mut entity_commands = EntityCommands {
             entity,
             commands: self.reborrow(),
@@ -367,6 +382,10 @@ impl<'w, 's> Commands<'w, 's> {
     #[track_caller]
     pub fn spawn<T: Bundle>(&mut self, bundle: T) -> EntityCommands {
         let entity = self.entities.reserve_entity();
+        #[cfg(feature = "bevy_reflect")]
+        if let Some(transaction) = self.transaction {
+            transaction.spawn(entity);
+        }
         let mut entity_commands = EntityCommands {
             entity,
             commands: self.reborrow(),
@@ -573,6 +592,11 @@ impl<'w, 's> Commands<'w, 's> {
     /// # bevy_ecs::system::assert_is_system(add_twenty_five_to_counter_system);
     /// ```
     pub fn queue<C: Command<T> + HandleError<T>, T>(&mut self, command: C) {
+        #[cfg(feature = "bevy_reflect")]
+        if let Some(transaction) = self.transaction {
+            self.queue_internal(command.handle_error_in(transaction.clone()));
+            return;
+        }
         self.queue_internal(command.handle_error());
     }
 
@@ -632,6 +656,22 @@ impl<'w, 's> Commands<'w, 's> {
     }
 
     fn queue_internal(&mut self, command: impl Command) {
+        #[cfg(feature = "bevy_reflect")]
+        if let Some(transaction) = self.transaction {
+            // Commands queued after a failing command of the transaction are skipped.
+            let transaction = transaction.clone();
+            let command = move |world: &mut World| {
+                if !transaction.failed() {
+                    command.apply(world);
+                }
+            };
+            self.push_internal(command);
+            return;
+        }
+        self.push_internal(command);
+    }
+
+    fn push_internal(&mut self, command: impl Command) {
         match &mut self.queue {
             InternalQueue::CommandQueue(queue) => {
                 queue.push(command);
@@ -1173,6 +1213,82 @@ impl<'w, 's> Commands<'w, 's> {
     pub fn run_schedule(&mut self, label: impl ScheduleLabel) {
         self.queue(command::run_schedule(label).handle_error_with(warn));
     }
+
+    /// Queues the commands pushed by `f` to be applied atomically: either all of them succeed,
+    /// or the [`World`] is rolled back to its state from before the first of them was applied.
+    ///
+    /// A command fails when it returns an error that would be passed to the default error handler,
+    /// such as inserting a component on an entity that no longer exists. The first failure skips
+    /// the remaining commands, restores the world from a [`WorldSnapshot`](crate::world::WorldSnapshot)
+    /// and passes the error to the default error handler.
+    /// Commands queued with their own error handler, like [`EntityCommands::try_insert`] or
+    /// [`EntityCommands::despawn`], handle their errors as usual and never fail the transaction.
+    ///
+    /// # Caveats
+    ///
+    /// The rollback only covers the state that a [`WorldSnapshot`](crate::world::WorldSnapshot)
+    /// captures:
+    /// - Only components and resources registered for reflection in the
+    ///   [`AppTypeRegistry`](crate::reflect::AppTypeRegistry), with [`ReflectComponent`] or
+    ///   [`ReflectResource`], are restored. Changes to any other component or resource made by the
+    ///   commands applied before the failure are kept, and a warning names the affected types.
+    ///   Removals of such components and effects outside of the world are kept silently.
+    /// - Entities spawned by the transaction are despawned, along with all of their components.
+    /// - The whole world is captured before the commands are applied, so the cost of a transaction
+    ///   grows with the size of the world.
+    ///
+    /// [`ReflectComponent`]: crate::reflect::ReflectComponent
+    /// [`ReflectResource`]: crate::reflect::ReflectResource
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::error::{ignore, DefaultErrorHandler};
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Component, Reflect, PartialEq, Debug)]
+    /// #[reflect(Component)]
+    /// struct Gold(u32);
+    ///
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Sword;
+    ///
+    /// fn buy_sword(commands: &mut Commands, player: Entity, shop: Entity) {
+    ///     commands.transaction(|commands| {
+    ///         commands.entity(player).insert((Gold(0), Sword));
+    ///         commands.entity(shop).insert(Gold(100));
+    ///     });
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// # world.insert_resource(DefaultErrorHandler(ignore));
+    /// world.init_resource::<AppTypeRegistry>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Gold>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Sword>();
+    ///
+    /// let player = world.spawn(Gold(100)).id();
+    /// let shop = world.spawn_empty().id();
+    /// world.despawn(shop);
+    ///
+    /// buy_sword(&mut world.commands(), player, shop);
+    /// world.flush();
+    ///
+    /// // The shop is gone, so the player keeps their gold and doesn't get the sword.
+    /// assert_eq!(world.get::<Gold>(player), Some(&Gold(100)));
+    /// assert!(!world.entity(player).contains::<Sword>());
+    /// ```
+    #[cfg(feature = "bevy_reflect")]
+    pub fn transaction(&mut self, f: impl FnOnce(&mut Commands)) {
+        let state = command::TransactionState::new(self.transaction);
+        let mut queue = CommandQueue::default();
+        f(&mut Commands {
+            queue: InternalQueue::CommandQueue(Deferred(&mut queue)),
+            entities: self.entities,
+            transaction: Some(&state),
+        });
+        self.queue(command::transaction(queue, state));
+    }
 }
 
 /// A list of commands that will be run to modify an [`Entity`].
@@ -2630,4 +2746,85 @@ mod tests {
             world.entities().entity_get_spawned_or_despawned_at(id)
         );
     }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn transaction() {
+        use crate::{
+            error::{BevyError, DefaultErrorHandler, ErrorContext},
+            reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        };
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Resource, Reflect, PartialEq, Debug)]
+        #[reflect(Component, Resource)]
+        struct Gold(u32);
+
+        #[derive(Resource)]
+        struct Silver;
+
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count_errors(_: BevyError, _: ErrorContext) {
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        world.insert_resource(DefaultErrorHandler(count_errors));
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Gold>();
+        world.insert_resource(Gold(10));
+        let player = world.spawn(Gold(1)).id();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let mut commands = world.commands();
+        commands.transaction(|commands| {
+            commands.insert_resource(Gold(0));
+            commands.entity(player).insert(Gold(2));
+            commands.entity(missing).insert(Gold(3));
+            commands.entity(player).despawn();
+            commands.insert_resource(Silver);
+        });
+        world.flush();
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+        assert_eq!(world.resource::<Gold>(), &Gold(10));
+        assert_eq!(world.get::<Gold>(player), Some(&Gold(1)));
+        // The commands after the failing one were skipped.
+        assert!(!world.contains_resource::<Silver>());
+
+        // A failing inner transaction fails the outer one.
+        // Entities spawned by the transactions are despawned by the rollback.
+        let mut commands = world.commands();
+        let mut spawned = Vec::new();
+        commands.transaction(|commands| {
+            commands.entity(player).insert(Gold(2));
+            spawned.push(commands.spawn(Gold(4)).id());
+            commands.transaction(|commands| {
+                spawned.push(commands.spawn_empty().id());
+            });
+            commands.transaction(|commands| {
+                commands.entity(missing).insert(Gold(3));
+            });
+        });
+        world.flush();
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+        assert_eq!(world.get::<Gold>(player), Some(&Gold(1)));
+        for entity in spawned {
+            assert!(world.get_entity(entity).is_err());
+        }
+
+        let mut commands = world.commands();
+        commands.transaction(|commands| {
+            commands.insert_resource(Gold(0));
+            commands.entity(player).insert(Gold(2));
+            commands.entity(missing).try_insert(Gold(3));
+        });
+        world.flush();
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+        assert_eq!(world.resource::<Gold>(), &Gold(0));
+        assert_eq!(world.get::<Gold>(player), Some(&Gold(2)));
+    }
 }
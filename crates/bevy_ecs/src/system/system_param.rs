@@ -856,6 +856,7 @@ unsafe impl<'a, T: Resource> SystemParam for ResMut<'a, T> {
             ticks: TicksMut {
                 added: value.ticks.added,
                 changed: value.ticks.changed,
+                storage: value.ticks.storage,
                 last_run: system_meta.last_run,
                 this_run: change_tick,
             },
@@ -14,15 +14,19 @@ use core::marker::PhantomData;
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
+///
+/// This is the component holding the system of a [`SystemId`] on its entity, so an entity has a
+/// `RegisteredSystem<I, O>` if it holds a system taking `I` and returning `O`. The component is
+/// taken out of the entity while the system runs.
 #[derive(Component)]
 #[require(SystemIdMarker)]
-pub(crate) struct RegisteredSystem<I, O> {
+pub struct RegisteredSystem<I, O> {
     initialized: bool,
     system: BoxedSystem<I, O>,
 }
 
 impl<I, O> RegisteredSystem<I, O> {
-    pub fn new(system: BoxedSystem<I, O>) -> Self {
+    pub(crate) fn new(system: BoxedSystem<I, O>) -> Self {
         RegisteredSystem {
             initialized: false,
             system,
@@ -0,0 +1,505 @@
+//! Access to a [`World`] from asynchronous tasks.
+//!
+//! An [`AsyncWorld`] is a cheap, cloneable handle that futures can hold on to. Requests made
+//! through it are queued and executed on the thread that owns the [`World`] at its next sync
+//! point, which is after every schedule of the main schedule order, and at the end of every frame
+//! in [`World::clear_trackers`]. They can also be applied manually with [`World::apply_async_requests`].
+//!
+//! Every request resolves to a [`Result`] that is [`AsyncWorldClosed`] once the world stops
+//! accepting requests, for example because the [`App`] exited and the world was dropped, so
+//! futures never wait forever on a world that is gone.
+//!
+//! [`App`]: https://docs.rs/bevy/latest/bevy/app/struct.App.html
+
+use alloc::{boxed::Box, vec::Vec};
+use bevy_platform::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+use concurrent_queue::ConcurrentQueue;
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    bundle::{Bundle, InsertMode},
+    entity::Entity,
+    error::{CommandWithEntity, HandleError},
+    event::Event,
+    query::{QueryData, QueryFilter},
+    resource::Resource,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    system::{command, entity_command, Command, Query},
+    world::{error::AsyncWorldClosed, CommandQueue, World},
+};
+
+#[cfg(feature = "std")]
+use bevy_tasks::{AsyncComputeTaskPool, Task, TaskPool};
+
+type AsyncRunFn = Box<dyn FnOnce(&mut World) + Send>;
+
+enum AsyncRequest {
+    Run(AsyncRunFn),
+    NextFrame(AsyncReplySender<()>),
+    Schedule(InternedScheduleLabel, AsyncReplySender<()>),
+}
+
+struct AsyncShared {
+    requests: ConcurrentQueue<AsyncRequest>,
+}
+
+impl AsyncShared {
+    fn push(&self, request: AsyncRequest) {
+        // If the queue is closed the request is dropped here, which resolves its reply with
+        // `AsyncWorldClosed`.
+        let _ = self.requests.push(request);
+    }
+}
+
+/// The [`Resource`] that receives the requests of every [`AsyncWorld`] handle of a [`World`].
+///
+/// Dropping it closes all handles: pending and future requests resolve to [`AsyncWorldClosed`],
+/// and tasks spawned with [`World::spawn_async`] are cancelled.
+#[derive(Resource)]
+pub struct AsyncWorldQueue {
+    shared: Arc<AsyncShared>,
+    frame_waiters: Vec<AsyncReplySender<()>>,
+    schedule_waiters: HashMap<InternedScheduleLabel, Vec<AsyncReplySender<()>>>,
+    #[cfg(feature = "std")]
+    tasks: Vec<Task<()>>,
+}
+
+impl Default for AsyncWorldQueue {
+    fn default() -> Self {
+        Self {
+            shared: Arc::new(AsyncShared {
+                requests: ConcurrentQueue::unbounded(),
+            }),
+            frame_waiters: Vec::new(),
+            schedule_waiters: HashMap::default(),
+            #[cfg(feature = "std")]
+            tasks: Vec::new(),
+        }
+    }
+}
+
+impl AsyncWorldQueue {
+    /// Creates a new [`AsyncWorld`] handle sending its requests to this queue.
+    pub fn handle(&self) -> AsyncWorld {
+        AsyncWorld {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Returns the number of requests waiting for the next sync point.
+    pub fn pending(&self) -> usize {
+        self.shared.requests.len()
+    }
+}
+
+impl Drop for AsyncWorldQueue {
+    fn drop(&mut self) {
+        self.shared.requests.close();
+        // Drop queued requests so their replies resolve instead of waiting on a closed queue.
+        while self.shared.requests.pop().is_ok() {}
+    }
+}
+
+/// A handle to a [`World`] that can be held by futures running on another thread.
+///
+/// Created with [`World::async_world`] or [`AsyncWorldQueue::handle`]. See the
+/// [module docs](crate::world::async_world) for when requests are executed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_tasks::block_on;
+/// #[derive(Component)]
+/// struct Score(u32);
+///
+/// let mut world = World::new();
+/// world.spawn(Score(3));
+/// let async_world = world.async_world();
+///
+/// let total = async_world.query(|query: Query<&Score>| query.iter().map(|s| s.0).sum::<u32>());
+/// // Usually the main thread reaches a sync point on its own while the task waits.
+/// world.apply_async_requests();
+/// assert_eq!(block_on(total), Ok(3));
+/// ```
+#[derive(Clone)]
+pub struct AsyncWorld {
+    shared: Arc<AsyncShared>,
+}
+
+impl AsyncWorld {
+    /// Runs `f` with exclusive access to the [`World`] at its next sync point and returns the result.
+    pub fn run<R, F>(&self, f: F) -> AsyncReply<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut World) -> R + Send + 'static,
+    {
+        let (sender, reply) = async_reply();
+        self.shared.push(AsyncRequest::Run(Box::new(move |world| {
+            sender.send(f(world));
+        })));
+        reply
+    }
+
+    /// Runs a [`Query`] at the next sync point and returns whatever `f` extracts from it.
+    pub fn query<D, F, R>(&self, f: impl FnOnce(Query<D, F>) -> R + Send + 'static) -> AsyncReply<R>
+    where
+        D: QueryData + 'static,
+        F: QueryFilter + 'static,
+        R: Send + 'static,
+    {
+        self.run(move |world| {
+            let mut state = world.query_filtered::<D, F>();
+            f(state.query_mut(world))
+        })
+    }
+
+    /// Spawns `bundle` at the next sync point and returns the new [`Entity`].
+    pub fn spawn<B: Bundle>(&self, bundle: B) -> AsyncReply<Entity> {
+        self.run(move |world| world.spawn(bundle).id())
+    }
+
+    /// Returns an [`AsyncCommands`] buffer that is submitted to this world as a single batch.
+    pub fn commands(&self) -> AsyncCommands {
+        AsyncCommands {
+            queue: CommandQueue::default(),
+            world: self.clone(),
+        }
+    }
+
+    /// Resolves at the end of the frame in which the request is processed,
+    /// i.e. the next time the world calls [`World::clear_trackers`].
+    pub fn next_frame(&self) -> AsyncReply<()> {
+        let (sender, reply) = async_reply();
+        self.shared.push(AsyncRequest::NextFrame(sender));
+        reply
+    }
+
+    /// Resolves after the next run of the schedule with the given `label`, once the request was
+    /// processed at a sync point.
+    ///
+    /// Any schedule run through the world counts, such as with [`World::run_schedule`] or
+    /// [`World::schedule_scope`], including schedules outside of the main schedule order.
+    pub fn schedule(&self, label: impl ScheduleLabel) -> AsyncReply<()> {
+        let (sender, reply) = async_reply();
+        self.shared
+            .push(AsyncRequest::Schedule(label.intern(), sender));
+        reply
+    }
+
+    /// Returns `true` if the world no longer accepts requests.
+    pub fn is_closed(&self) -> bool {
+        self.shared.requests.is_closed()
+    }
+}
+
+/// A buffer of [`Command`]s built off the main thread and applied to an [`AsyncWorld`] as a batch.
+///
+/// The commands are submitted with [`AsyncCommands::submit`], or when the buffer is dropped.
+pub struct AsyncCommands {
+    queue: CommandQueue,
+    world: AsyncWorld,
+}
+
+impl AsyncCommands {
+    /// Pushes a [`Command`] to the buffer.
+    ///
+    /// Errors returned by the command are handled by the default error handler.
+    pub fn queue<C: Command<T> + HandleError<T>, T>(&mut self, command: C) -> &mut Self {
+        self.queue.push(command.handle_error());
+        self
+    }
+
+    /// Spawns an entity with the given `bundle`.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            world.spawn(bundle);
+        })
+    }
+
+    /// Inserts `bundle` on `entity`, replacing existing components.
+    pub fn insert(&mut self, entity: Entity, bundle: impl Bundle) -> &mut Self {
+        self.queue(entity_command::insert(bundle, InsertMode::Replace).with_entity(entity))
+    }
+
+    /// Removes the components of the [`Bundle`] `B` from `entity`.
+    pub fn remove<B: Bundle>(&mut self, entity: Entity) -> &mut Self {
+        self.queue(entity_command::remove::<B>().with_entity(entity))
+    }
+
+    /// Despawns `entity`.
+    pub fn despawn(&mut self, entity: Entity) -> &mut Self {
+        self.queue(entity_command::despawn().with_entity(entity))
+    }
+
+    /// Inserts the resource `R`, replacing any existing value.
+    pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
+        self.queue(command::insert_resource(resource))
+    }
+
+    /// Triggers the given [`Event`], running any observers watching for it.
+    pub fn trigger(&mut self, event: impl Event) -> &mut Self {
+        self.queue(command::trigger(event))
+    }
+
+    /// Sends the given [`Event`] to its [`Events`](crate::event::Events) resource.
+    pub fn send_event<E: Event>(&mut self, event: E) -> &mut Self {
+        self.queue(command::send_event(event))
+    }
+
+    /// Submits the buffered commands, returning a reply that resolves once they were applied.
+    pub fn submit(mut self) -> AsyncReply<()> {
+        let mut queue = mem::take(&mut self.queue);
+        self.world.run(move |world| queue.apply(world))
+    }
+}
+
+impl Drop for AsyncCommands {
+    fn drop(&mut self) {
+        if !self.queue.is_empty() {
+            let mut queue = mem::take(&mut self.queue);
+            self.world
+                .shared
+                .push(AsyncRequest::Run(Box::new(move |world| queue.apply(world))));
+        }
+    }
+}
+
+struct AsyncReplyState<T> {
+    value: Option<T>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+struct AsyncReplySender<T> {
+    state: Arc<Mutex<AsyncReplyState<T>>>,
+}
+
+impl<T> AsyncReplySender<T> {
+    fn send(self, value: T) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .value = Some(value);
+    }
+}
+
+impl<T> Drop for AsyncReplySender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            state.closed = true;
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A [`Future`] resolving to the result of a request made through an [`AsyncWorld`].
+///
+/// Dropping it cancels nothing: the request is still executed, its result is discarded.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AsyncReply<T> {
+    state: Arc<Mutex<AsyncReplyState<T>>>,
+}
+
+fn async_reply<T>() -> (AsyncReplySender<T>, AsyncReply<T>) {
+    let state = Arc::new(Mutex::new(AsyncReplyState {
+        value: None,
+        closed: false,
+        waker: None,
+    }));
+    (
+        AsyncReplySender {
+            state: state.clone(),
+        },
+        AsyncReply { state },
+    )
+}
+
+impl<T> Future for AsyncReply<T> {
+    type Output = Result<T, AsyncWorldClosed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(value) = state.value.take() {
+            Poll::Ready(Ok(value))
+        } else if state.closed {
+            Poll::Ready(Err(AsyncWorldClosed))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl World {
+    /// Returns an [`AsyncWorld`] handle to this world, initializing [`AsyncWorldQueue`] if needed.
+    pub fn async_world(&mut self) -> AsyncWorld {
+        self.get_resource_or_init::<AsyncWorldQueue>().handle()
+    }
+
+    /// Spawns the future returned by `f` on the [`AsyncComputeTaskPool`], giving it an
+    /// [`AsyncWorld`] handle to this world.
+    ///
+    /// The task is owned by the [`AsyncWorldQueue`] and cancelled when it is dropped.
+    #[cfg(feature = "std")]
+    pub fn spawn_async<F, Fut>(&mut self, f: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let future = f(self.async_world());
+        let task = AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(future);
+        let mut queue = self.resource_mut::<AsyncWorldQueue>();
+        queue.tasks.retain(|task| !task.is_finished());
+        queue.tasks.push(task);
+    }
+
+    /// Executes the requests queued by [`AsyncWorld`] handles to this world.
+    ///
+    /// This is called automatically at every sync point, see the
+    /// [module docs](crate::world::async_world).
+    pub fn apply_async_requests(&mut self) {
+        let Some(shared) = self
+            .get_resource::<AsyncWorldQueue>()
+            .map(|queue| queue.shared.clone())
+        else {
+            return;
+        };
+
+        // Requests queued while applying these are left for the next sync point.
+        for _ in 0..shared.requests.len() {
+            let Ok(request) = shared.requests.pop() else {
+                break;
+            };
+            match request {
+                AsyncRequest::Run(f) => {
+                    f(self);
+                    self.flush();
+                }
+                AsyncRequest::NextFrame(sender) => {
+                    if let Some(mut queue) = self.get_resource_mut::<AsyncWorldQueue>() {
+                        queue.frame_waiters.push(sender);
+                    }
+                }
+                AsyncRequest::Schedule(label, sender) => {
+                    if let Some(mut queue) = self.get_resource_mut::<AsyncWorldQueue>() {
+                        queue
+                            .schedule_waiters
+                            .entry(label)
+                            .or_default()
+                            .push(sender);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves the replies of [`AsyncWorld::schedule`] waiting for `label`.
+    ///
+    /// Called by [`World::try_schedule_scope`] for every schedule.
+    pub(crate) fn async_schedule_finished(&mut self, label: InternedScheduleLabel) {
+        let waiting = self
+            .get_resource::<AsyncWorldQueue>()
+            .is_some_and(|queue| queue.schedule_waiters.contains_key(&label));
+        if !waiting {
+            return;
+        }
+        if let Some(mut queue) = self.get_resource_mut::<AsyncWorldQueue>() {
+            for sender in queue.schedule_waiters.remove(&label).unwrap_or_default() {
+                sender.send(());
+            }
+        }
+    }
+
+    /// Sync point at the end of a frame.
+    pub(crate) fn async_frame_ended(&mut self) {
+        if !self.contains_resource::<AsyncWorldQueue>() {
+            return;
+        }
+        self.apply_async_requests();
+        if let Some(mut queue) = self.get_resource_mut::<AsyncWorldQueue>() {
+            for sender in mem::take(&mut queue.frame_waiters) {
+                sender.send(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_tasks::{block_on, poll_once};
+
+    use crate::{
+        prelude::*,
+        schedule::ScheduleLabel,
+        world::{async_world::AsyncWorldQueue, error::AsyncWorldClosed},
+    };
+
+    #[derive(Component, Debug, PartialEq)]
+    struct A(u32);
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Update;
+
+    #[test]
+    fn async_world_requests_run_at_sync_points() {
+        let mut world = World::new();
+        world.add_schedule(Schedule::new(Update));
+        let async_world = world.async_world();
+
+        let mut commands = async_world.commands();
+        commands.spawn(A(1)).spawn(A(2));
+        let applied = commands.submit();
+        let mut sum = async_world.query(|query: Query<&A>| query.iter().map(|a| a.0).sum::<u32>());
+        assert_eq!(block_on(poll_once(&mut sum)), None);
+
+        world.run_schedule(Update);
+        assert_eq!(block_on(poll_once(&mut sum)), None);
+        world.apply_async_requests();
+        assert_eq!(block_on(applied), Ok(()));
+        assert_eq!(block_on(sum), Ok(3));
+    }
+
+    #[test]
+    fn async_world_waits_for_frame_and_schedule() {
+        let mut world = World::new();
+        world.add_schedule(Schedule::new(Update));
+        let async_world = world.async_world();
+
+        let mut frame = async_world.next_frame();
+        let mut update = async_world.schedule(Update);
+        world.apply_async_requests();
+        assert_eq!(block_on(poll_once(&mut frame)), None);
+        assert_eq!(block_on(poll_once(&mut update)), None);
+
+        // Any run of the schedule resolves the reply, not only those of the main schedule order.
+        world.run_schedule(Update);
+        assert_eq!(block_on(poll_once(&mut frame)), None);
+        assert_eq!(block_on(update), Ok(()));
+
+        world.clear_trackers();
+        assert_eq!(block_on(frame), Ok(()));
+    }
+
+    #[test]
+    fn async_world_closes_with_world() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+        let pending = async_world.spawn(A(1));
+
+        world.remove_resource::<AsyncWorldQueue>();
+        assert!(async_world.is_closed());
+        assert_eq!(block_on(pending), Err(AsyncWorldClosed));
+        assert_eq!(block_on(async_world.next_frame()), Err(AsyncWorldClosed));
+    }
+}
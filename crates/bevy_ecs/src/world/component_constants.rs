@@ -1,6 +1,7 @@
 //! Internal components used by bevy with a fixed component id.
 //! Constants are used to skip [`TypeId`] lookups in hot paths.
 use super::*;
+use crate::hierarchy::ChildOf;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 
@@ -14,6 +15,8 @@ pub const ON_REPLACE: ComponentId = ComponentId::new(2);
 pub const ON_REMOVE: ComponentId = ComponentId::new(3);
 /// [`ComponentId`] for [`OnDespawn`]
 pub const ON_DESPAWN: ComponentId = ComponentId::new(4);
+/// [`ComponentId`] for [`OnMutate`]
+pub const ON_MUTATE: ComponentId = ComponentId::new(5);
 
 /// Trigger emitted when a component is inserted onto an entity that does not already have that
 /// component. Runs before `OnInsert`.
@@ -53,3 +56,23 @@ pub struct OnRemove;
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 #[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
 pub struct OnDespawn;
+
+/// Trigger emitted for the components of an entity that were mutated in place since the last
+/// mutation flush.
+///
+/// Mutations are batched rather than reported on every [`DerefMut`](core::ops::DerefMut): each
+/// call to [`World::trigger_mutations`] emits at most one `OnMutate` per entity, targeting every
+/// watched component whose change tick advanced since the previous call. Components that were
+/// added during that window are reported by [`OnAdd`] instead, unless they were changed again at
+/// a later change tick, while re-inserting a component that was already present counts as a
+/// mutation.
+///
+/// Only components watched by a component-targeted `OnMutate` observer are checked, so a global
+/// observer without components will only see mutations of components some other observer watches.
+/// Observers can call [`Trigger::propagate`](crate::observer::Trigger::propagate) to forward the
+/// event up the [`ChildOf`] hierarchy.
+#[derive(Event, Debug)]
+#[event(traversal = &'static ChildOf)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
+pub struct OnMutate;
@@ -15,6 +15,14 @@ use crate::{
 #[error("The schedule with the label {0:?} was not found.")]
 pub struct TryRunScheduleError(pub InternedScheduleLabel);
 
+/// The error type returned by requests made through an [`AsyncWorld`] whose world no longer
+/// accepts requests.
+///
+/// [`AsyncWorld`]: crate::world::AsyncWorld
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The world behind this `AsyncWorld` no longer accepts requests.")]
+pub struct AsyncWorldClosed;
+
 /// The error type returned by [`World::try_insert_batch`] and [`World::try_insert_batch_if_new`]
 /// if any of the provided entities do not exist.
 ///
@@ -1,5 +1,6 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+pub mod async_world;
 pub(crate) mod command_queue;
 mod component_constants;
 mod deferred_world;
@@ -13,12 +14,15 @@ pub mod unsafe_world_cell;
 
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+#[cfg(feature = "bevy_reflect")]
+mod snapshot;
 
 use crate::error::{DefaultErrorHandler, ErrorHandler};
 pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
     world::command_queue::CommandQueue,
 };
+pub use async_world::{AsyncCommands, AsyncReply, AsyncWorld, AsyncWorldQueue};
 pub use bevy_ecs_macros::FromWorld;
 pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
@@ -29,6 +33,8 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+#[cfg(feature = "bevy_reflect")]
+pub use snapshot::{EntitySnapshot, WorldSnapshot};
 pub use spawn_batch::*;
 
 use crate::{
@@ -41,7 +47,7 @@ use crate::{
     component::{
         Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentIds, ComponentInfo,
         ComponentTicks, Components, ComponentsQueuedRegistrator, ComponentsRegistrator, Mutable,
-        RequiredComponents, RequiredComponentsError, Tick,
+        RequiredComponents, RequiredComponentsError, StorageType, Tick,
     },
     entity::{Entities, Entity, EntityDoesNotExistError},
     entity_disabling::DefaultQueryFilters,
@@ -52,7 +58,7 @@ use crate::{
     removal_detection::RemovedComponentEvents,
     resource::Resource,
     schedule::{Schedule, ScheduleLabel, Schedules},
-    storage::{ResourceData, Storages},
+    storage::{ComponentSparseSet, ResourceData, Storages},
     system::Commands,
     world::{
         command_queue::RawCommandQueue,
@@ -99,6 +105,7 @@ pub struct World {
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    pub(crate) last_mutation_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
 }
@@ -119,6 +126,7 @@ impl Default for World {
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
+            last_mutation_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
             component_ids: ComponentIds::default(),
@@ -162,6 +170,9 @@ impl World {
         let on_despawn = OnDespawn::register_component_id(self);
         assert_eq!(ON_DESPAWN, on_despawn);
 
+        let on_mutate = OnMutate::register_component_id(self);
+        assert_eq!(ON_MUTATE, on_mutate);
+
         // This sets up `Disabled` as a disabling component, via the FromWorld impl
         self.init_resource::<DefaultQueryFilters>();
     }
@@ -1422,6 +1433,8 @@ impl World {
     /// by `bevy_app::App::update` and `bevy_app::SubApp::update`, so you don't need to call it manually.
     /// When using `bevy_ecs` as a separate standalone crate however, you do need to call this manually.
     ///
+    /// This also marks the end of a frame for [`AsyncWorld::next_frame`].
+    ///
     /// ```
     /// # use bevy_ecs::prelude::*;
     /// # #[derive(Component, Default)]
@@ -1448,6 +1461,7 @@ impl World {
     pub fn clear_trackers(&mut self) {
         self.removed_components.update();
         self.last_change_tick = self.increment_change_tick();
+        self.async_frame_ended();
     }
 
     /// Returns [`QueryState`] for the given [`QueryData`], which is used to efficiently
@@ -2572,6 +2586,7 @@ impl World {
             ticks: TicksMut {
                 added: &mut ticks.added,
                 changed: &mut ticks.changed,
+                storage: None,
                 last_run: last_change_tick,
                 this_run: change_tick,
             },
@@ -2770,6 +2785,118 @@ impl World {
         self.flush_commands();
     }
 
+    /// Triggers [`OnMutate`] for every entity whose watched components were changed in place since
+    /// the last call to this method.
+    ///
+    /// This is the flush point for mutation observers: however many times a component was mutably
+    /// dereferenced in between, each entity receives at most one [`OnMutate`] trigger, targeting all
+    /// of its mutated components at once. Only components with at least one component-targeted
+    /// [`OnMutate`] observer are checked, and components added since the last call are skipped
+    /// unless they were also changed at a later tick. This does nothing while no such observer
+    /// exists.
+    ///
+    /// `bevy_app` calls this after every schedule in the main schedule order.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::OnMutate;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.add_observer(|trigger: Trigger<OnMutate, Health>, query: Query<&Health>| {
+    ///     let health = query.get(trigger.target()).unwrap();
+    ///     println!("health is now {}", health.0);
+    /// });
+    ///
+    /// let entity = world.spawn(Health(10)).id();
+    /// world.trigger_mutations();
+    ///
+    /// world.get_mut::<Health>(entity).unwrap().0 -= 1;
+    /// world.get_mut::<Health>(entity).unwrap().0 -= 1;
+    /// // Fires a single `OnMutate` for `entity`.
+    /// world.trigger_mutations();
+    /// ```
+    #[track_caller]
+    pub fn trigger_mutations(&mut self) {
+        let caller = MaybeLocation::caller();
+        let Some(observers) = self
+            .observers
+            .try_get_observers(ON_MUTATE)
+            .filter(|observers| !observers.component_observers().is_empty())
+        else {
+            return;
+        };
+        let last_run = self.last_mutation_tick;
+        let this_run = self.read_change_tick();
+
+        let mut mutated: Vec<(Entity, ComponentId)> = Vec::new();
+        for &component_id in observers.component_observers().keys() {
+            let Some(archetypes) = self.archetypes.component_index().get(&component_id) else {
+                continue;
+            };
+            let sparse_set = self.storages.sparse_sets.get(component_id);
+            for &archetype_id in archetypes.keys() {
+                let archetype = &self.archetypes[archetype_id];
+                let table = &self.storages.tables[archetype.table_id()];
+                let storage_ticks = match archetype.get_storage_type(component_id) {
+                    Some(StorageType::Table) => table.get_storage_ticks_for(component_id),
+                    Some(StorageType::SparseSet) => {
+                        sparse_set.map(ComponentSparseSet::get_storage_ticks)
+                    }
+                    None => None,
+                };
+                if !storage_ticks.is_some_and(|ticks| ticks.is_changed(last_run, this_run)) {
+                    continue;
+                }
+                for archetype_entity in archetype.entities() {
+                    let ticks = match archetype.get_storage_type(component_id) {
+                        // SAFETY: The table row of an entity in this archetype is in bounds.
+                        Some(StorageType::Table) => unsafe {
+                            table.get_ticks_unchecked(component_id, archetype_entity.table_row())
+                        },
+                        Some(StorageType::SparseSet) => {
+                            sparse_set.and_then(|set| set.get_ticks(archetype_entity.id()))
+                        }
+                        None => None,
+                    };
+                    // Components added in this window were reported by `OnAdd`, unless they were
+                    // changed again afterwards.
+                    if ticks.is_some_and(|ticks| {
+                        ticks.is_changed(last_run, this_run)
+                            && (!ticks.is_added(last_run, this_run)
+                                || ticks.changed.is_newer_than(ticks.added, this_run))
+                    }) {
+                        mutated.push((archetype_entity.id(), component_id));
+                    }
+                }
+            }
+        }
+        self.last_mutation_tick = self.increment_change_tick();
+
+        if mutated.is_empty() {
+            return;
+        }
+        mutated.sort_unstable();
+
+        let mut world = DeferredWorld::from(&mut *self);
+        for group in mutated.chunk_by(|(a, _), (b, _)| a == b) {
+            let entity = group[0].0;
+            // SAFETY: `OnMutate` is the type represented by `ON_MUTATE`.
+            unsafe {
+                world.trigger_observers_with_data::<_, <OnMutate as Event>::Traversal>(
+                    ON_MUTATE,
+                    entity,
+                    group.iter().map(|&(_, component_id)| component_id),
+                    &mut OnMutate,
+                    false,
+                    caller,
+                );
+            }
+        }
+        self.flush();
+    }
+
     /// Increments the world's current change tick and returns the old value.
     ///
     /// If you need to call this method, but do not have `&mut` access to the world,
@@ -2959,6 +3086,7 @@ impl World {
         resources.check_change_ticks(change_tick);
         non_send_resources.check_change_ticks(change_tick);
         self.entities.check_change_ticks(change_tick);
+        self.last_mutation_tick.check_tick(change_tick);
 
         if let Some(mut schedules) = self.get_resource_mut::<Schedules>() {
             schedules.check_change_ticks(change_tick);
@@ -3431,6 +3559,7 @@ impl World {
         if old.is_some() {
             warn!("Schedule `{label:?}` was inserted during a call to `World::schedule_scope`: its value has been overwritten");
         }
+        self.async_schedule_finished(label);
 
         Ok(value)
     }
@@ -0,0 +1,477 @@
+//! Capturing and restoring the reflected state of a [`World`], for use in rollback and replay.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::any::TypeId;
+
+use bevy_reflect::{PartialReflect, Reflect, TypeRegistry};
+
+use crate::{
+    archetype::{Archetype, ArchetypeEntity},
+    change_detection::MaybeLocation,
+    component::{ComponentId, ComponentInfo},
+    entity::{EntitiesSnapshot, Entity, EntityHashSet},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    relationship::RelationshipHookMode,
+    world::{EntityWorldMut, World},
+};
+
+/// An in-memory copy of the state of a [`World`], which can later be written back with
+/// [`WorldSnapshot::restore`].
+///
+/// A snapshot contains:
+/// - the state of the [`Entities`](crate::entity::Entities) allocator, so that every [`Entity`]
+///   keeps its id across a restore and entities spawned afterwards are assigned the same ids again,
+/// - a copy of every component that has [`ReflectComponent`] registered, for every spawned entity,
+/// - a copy of every resource that has [`ReflectResource`] registered.
+///
+/// Components and resources that are not registered for reflection are not captured. Entities that
+/// exist both when the snapshot is taken and when it is restored keep those untouched, but an entity
+/// that has to be spawned again by a restore will only receive its reflected components.
+///
+/// Unlike a [`DynamicScene`], a snapshot is not meant to be serialized or loaded into another world:
+/// it is a cheap way to rewind the same world, e.g. to resimulate frames for rollback networking.
+///
+/// [`DynamicScene`]: https://docs.rs/bevy/latest/bevy/scene/struct.DynamicScene.html
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect, PartialEq, Debug)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Health>();
+///
+/// let player = world.spawn(Health(10)).id();
+/// let snapshot = world.snapshot();
+///
+/// world.entity_mut(player).insert(Health(3));
+/// let enemy = world.spawn(Health(5)).id();
+///
+/// world.restore_snapshot(&snapshot);
+/// assert_eq!(world.get::<Health>(player), Some(&Health(10)));
+/// assert!(world.get_entity(enemy).is_err());
+///
+/// // The allocator was rewound too, so the same id is handed out again.
+/// assert_eq!(world.spawn_empty().id(), enemy);
+/// ```
+pub struct WorldSnapshot {
+    entities: EntitiesSnapshot,
+    spawned: Vec<EntitySnapshot>,
+    resources: Vec<(TypeId, Box<dyn PartialReflect>)>,
+}
+
+/// The reflected components of a single [`Entity`] captured in a [`WorldSnapshot`].
+pub struct EntitySnapshot {
+    entity: Entity,
+    components: Vec<(TypeId, Box<dyn PartialReflect>)>,
+}
+
+impl EntitySnapshot {
+    /// The captured entity.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Iterates over the captured components of this entity.
+    pub fn components(&self) -> impl Iterator<Item = &dyn PartialReflect> {
+        self.components.iter().map(|(_, value)| &**value)
+    }
+
+    fn contains(&self, type_id: TypeId) -> bool {
+        self.components.iter().any(|(id, _)| *id == type_id)
+    }
+}
+
+impl WorldSnapshot {
+    /// Captures the current state of `world`, using `registry` to find the components and resources
+    /// that can be reflected.
+    ///
+    /// The world is [flushed](World::flush) first, so that no reserved entities are pending.
+    pub fn capture(world: &mut World, registry: &TypeRegistry) -> Self {
+        world.flush();
+        let entities = world.entities.snapshot();
+
+        let mut spawned = Vec::with_capacity(world.entities().len() as usize);
+        for archetype in world.archetypes().iter() {
+            let reflected = archetype
+                .components()
+                .filter_map(|component_id| {
+                    let type_id = world.components().get_info(component_id)?.type_id()?;
+                    let reflect_component = registry.get_type_data::<ReflectComponent>(type_id)?;
+                    Some((type_id, reflect_component))
+                })
+                .collect::<Vec<_>>();
+
+            for archetype_entity in archetype.entities() {
+                let entity = world.entity(archetype_entity.id());
+                let components = reflected
+                    .iter()
+                    .filter_map(|(type_id, reflect_component)| {
+                        let value = reflect_component.reflect(entity)?;
+                        Some((*type_id, clone_value(value.as_partial_reflect())))
+                    })
+                    .collect();
+                spawned.push(EntitySnapshot {
+                    entity: archetype_entity.id(),
+                    components,
+                });
+            }
+        }
+
+        let resources = world
+            .iter_resources()
+            .filter_map(|(info, _)| {
+                let type_id = info.type_id()?;
+                let reflect_resource = registry.get_type_data::<ReflectResource>(type_id)?;
+                let value = reflect_resource.reflect(&*world).ok()?;
+                Some((type_id, clone_value(value.as_partial_reflect())))
+            })
+            .collect();
+
+        Self {
+            entities,
+            spawned,
+            resources,
+        }
+    }
+
+    /// Returns `world` to the state captured in this snapshot.
+    ///
+    /// - Entities spawned since the snapshot was taken are despawned.
+    /// - Entities despawned since the snapshot was taken are spawned again with their original id.
+    /// - Reflected components and resources are inserted, overwritten or removed to match the snapshot.
+    ///   Values that are already equal to the captured ones are left untouched,
+    ///   so they won't be reported as changed.
+    /// - The entity allocator is rewound, so that entities spawned afterwards reuse the same ids.
+    ///
+    /// Component values are written back without running relationship hooks, since the snapshot already
+    /// contains both sides of every relationship. Other hooks and observers run as usual.
+    ///
+    /// `world` should be the world this snapshot was captured from.
+    pub fn restore(&self, world: &mut World, registry: &TypeRegistry) {
+        world.flush();
+
+        // Removals run before any value is written back, so that hooks triggered by them
+        // (such as those maintaining relationships) can't clobber restored values.
+        for snapshot in &self.spawned {
+            let Ok(mut entity) = world.get_entity_mut(snapshot.entity) else {
+                continue;
+            };
+            let to_remove = entity
+                .archetype()
+                .components()
+                .filter(|&component_id| {
+                    is_reflected_component(entity.world(), registry, component_id)
+                        .is_some_and(|type_id| !snapshot.contains(type_id))
+                })
+                .collect::<Vec<_>>();
+            if !to_remove.is_empty() {
+                entity.remove_by_ids(&to_remove);
+            }
+        }
+
+        // Hooks and observers of despawned entities may spawn new ones, so repeat until only
+        // entities from the snapshot remain.
+        let alive = self
+            .spawned
+            .iter()
+            .map(EntitySnapshot::entity)
+            .collect::<EntityHashSet>();
+        loop {
+            let extra = world
+                .archetypes()
+                .iter()
+                .flat_map(Archetype::entities)
+                .map(ArchetypeEntity::id)
+                .filter(|entity| !alive.contains(entity))
+                .collect::<Vec<_>>();
+            if extra.is_empty() {
+                break;
+            }
+            for entity in extra {
+                if let Ok(entity) = world.get_entity_mut(entity) {
+                    entity.despawn();
+                }
+            }
+            world.flush();
+        }
+
+        world.entities.restore(&self.entities);
+        let caller = MaybeLocation::caller();
+        for snapshot in &self.spawned {
+            if world.entities.get(snapshot.entity).is_none() {
+                // SAFETY: `Entities::restore` left every entity alive in the snapshot allocated,
+                // and this one has no location yet.
+                unsafe { world.spawn_at_empty_internal(snapshot.entity, caller) };
+            }
+        }
+
+        for snapshot in &self.spawned {
+            let mut entity = world.entity_mut(snapshot.entity);
+            for (type_id, value) in &snapshot.components {
+                let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(*type_id)
+                else {
+                    continue;
+                };
+                restore_component(&mut entity, *type_id, reflect_component, &**value, registry);
+            }
+        }
+
+        let to_remove = world
+            .iter_resources()
+            .filter_map(|(info, _)| info.type_id())
+            .filter(|type_id| self.resources.iter().all(|(id, _)| id != type_id))
+            .filter_map(|type_id| registry.get_type_data::<ReflectResource>(type_id))
+            .collect::<Vec<_>>();
+        for reflect_resource in to_remove {
+            reflect_resource.remove(world);
+        }
+
+        for (type_id, value) in &self.resources {
+            let Some(reflect_resource) = registry.get_type_data::<ReflectResource>(*type_id) else {
+                continue;
+            };
+            match reflect_resource.reflect_mut(&mut *world) {
+                Ok(mut current) => {
+                    if current.reflect_partial_eq(&**value) != Some(true) {
+                        set_value(&mut *current, &**value);
+                    }
+                }
+                Err(_) => reflect_resource.insert(world, &**value, registry),
+            }
+        }
+    }
+
+    /// The state of the entity allocator captured in this snapshot.
+    pub fn entities(&self) -> &EntitiesSnapshot {
+        &self.entities
+    }
+
+    /// Iterates over the entities captured in this snapshot.
+    pub fn spawned(&self) -> impl Iterator<Item = &EntitySnapshot> {
+        self.spawned.iter()
+    }
+
+    /// Iterates over the resources captured in this snapshot.
+    pub fn resources(&self) -> impl Iterator<Item = &dyn PartialReflect> {
+        self.resources.iter().map(|(_, value)| &**value)
+    }
+}
+
+impl World {
+    /// Captures the state of this world into a [`WorldSnapshot`], using the [`AppTypeRegistry`]
+    /// to find the components and resources that can be reflected.
+    ///
+    /// See [`WorldSnapshot::capture`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AppTypeRegistry`] resource does not exist.
+    pub fn snapshot(&mut self) -> WorldSnapshot {
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        WorldSnapshot::capture(self, &registry.read())
+    }
+
+    /// Returns this world to the state captured in `snapshot`, using the [`AppTypeRegistry`].
+    ///
+    /// See [`WorldSnapshot::restore`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AppTypeRegistry`] resource does not exist.
+    pub fn restore_snapshot(&mut self, snapshot: &WorldSnapshot) {
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        snapshot.restore(self, &registry.read());
+    }
+}
+
+/// Returns the [`TypeId`] of the component if it is registered with [`ReflectComponent`].
+fn is_reflected_component(
+    world: &World,
+    registry: &TypeRegistry,
+    component_id: ComponentId,
+) -> Option<TypeId> {
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    registry
+        .get_type_data::<ReflectComponent>(type_id)
+        .map(|_| type_id)
+}
+
+/// Copies `value`, preferring a concrete clone so that it can later be [set](Reflect::set)
+/// without going through [`PartialReflect::apply`].
+fn clone_value(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    value
+        .reflect_clone()
+        .map(PartialReflect::into_partial_reflect)
+        .unwrap_or_else(|_| value.to_dynamic())
+}
+
+/// Overwrites `current` with `value`.
+///
+/// [`Reflect::set`] is used whenever possible, since [`PartialReflect::apply`] merges
+/// rather than replaces collections.
+fn set_value(current: &mut dyn Reflect, value: &dyn PartialReflect) {
+    let result = match value.reflect_clone() {
+        Ok(clone) => current.set(clone).map_err(|_| ()),
+        Err(_) => Err(()),
+    };
+    if result.is_err() {
+        current.apply(value);
+    }
+}
+
+fn restore_component(
+    entity: &mut EntityWorldMut,
+    type_id: TypeId,
+    reflect_component: &ReflectComponent,
+    value: &dyn PartialReflect,
+    registry: &TypeRegistry,
+) {
+    if let Some(current) = reflect_component.reflect(&*entity) {
+        if current.reflect_partial_eq(value) == Some(true) {
+            return;
+        }
+        let mutable = entity
+            .world()
+            .components()
+            .get_valid_id(type_id)
+            .and_then(|component_id| entity.world().components().get_info(component_id))
+            .is_some_and(ComponentInfo::mutable);
+        if mutable {
+            if let Some(mut current) = reflect_component.reflect_mut(&mut *entity) {
+                set_value(&mut *current, value);
+            }
+            return;
+        }
+    }
+
+    // Immutable components are replaced rather than mutated. Relationship hooks are skipped,
+    // since the other side of the relationship is restored from the snapshot as well.
+    reflect_component.apply_or_insert_mapped(
+        entity,
+        value,
+        registry,
+        &mut (),
+        RelationshipHookMode::Skip,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::Entity,
+        hierarchy::{ChildOf, Children},
+        prelude::*,
+        reflect::AppTypeRegistry,
+    };
+    use alloc::{vec, vec::Vec};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Clone, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Position(Vec<f32>);
+
+    #[derive(Component, Reflect, Clone, PartialEq, Debug)]
+    #[reflect(Component)]
+    #[component(immutable)]
+    struct Team(u8);
+
+    #[derive(Component)]
+    struct NotReflected;
+
+    #[derive(Resource, Reflect, Clone, PartialEq, Debug, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Team>();
+            registry.register::<Score>();
+            registry.register::<ChildOf>();
+            registry.register::<Children>();
+        }
+        world
+    }
+
+    #[test]
+    fn restore_components_and_resources() {
+        let mut world = world();
+        let a = world.spawn((Position(vec![1.0, 2.0]), Team(1))).id();
+        let b = world.spawn((Position(vec![3.0]), NotReflected)).id();
+        world.insert_resource(Score(5));
+
+        let snapshot = world.snapshot();
+
+        world.entity_mut(a).insert(Team(2)).remove::<Position>();
+        world.entity_mut(b).insert((Position(vec![]), Team(3)));
+        world.insert_resource(Score(100));
+
+        world.restore_snapshot(&snapshot);
+
+        assert_eq!(world.get::<Position>(a), Some(&Position(vec![1.0, 2.0])));
+        assert_eq!(world.get::<Team>(a), Some(&Team(1)));
+        assert_eq!(world.get::<Position>(b), Some(&Position(vec![3.0])));
+        assert!(world.get::<Team>(b).is_none());
+        assert!(world.entity(b).contains::<NotReflected>());
+        assert_eq!(world.resource::<Score>(), &Score(5));
+
+        world.remove_resource::<Score>();
+        world.restore_snapshot(&snapshot);
+        assert_eq!(world.resource::<Score>(), &Score(5));
+    }
+
+    #[test]
+    fn restore_preserves_entity_ids() {
+        let mut world = world();
+        let a = world.spawn(Position(vec![1.0])).id();
+        let b = world.spawn(Position(vec![2.0])).id();
+        world.despawn(b);
+
+        let snapshot = world.snapshot();
+        let next = world.spawn_empty().id();
+        world.despawn(a);
+        world.spawn(Team(4));
+
+        world.restore_snapshot(&snapshot);
+
+        assert_eq!(world.get::<Position>(a), Some(&Position(vec![1.0])));
+        assert_eq!(world.entities().len(), 1);
+        assert!(world.get_entity(b).is_err());
+        assert_eq!(world.spawn_empty().id(), next);
+    }
+
+    #[test]
+    fn restore_hierarchy() {
+        let mut world = world();
+        let parent = world.spawn_empty().id();
+        let child_a = world.spawn(ChildOf(parent)).id();
+        let child_b = world.spawn(ChildOf(parent)).id();
+
+        let snapshot = world.snapshot();
+
+        world.entity_mut(child_a).remove::<ChildOf>();
+        world.entity_mut(child_b).despawn();
+        world.spawn(ChildOf(parent));
+
+        world.restore_snapshot(&snapshot);
+
+        let children = world
+            .get::<Children>(parent)
+            .unwrap()
+            .iter()
+            .collect::<Vec<Entity>>();
+        assert_eq!(children, vec![child_a, child_b]);
+        assert_eq!(world.get::<ChildOf>(child_a), Some(&ChildOf(parent)));
+        assert_eq!(world.get::<ChildOf>(child_b), Some(&ChildOf(parent)));
+        assert_eq!(world.entities().len(), 3);
+    }
+}
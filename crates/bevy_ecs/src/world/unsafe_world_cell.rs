@@ -1272,6 +1272,7 @@ unsafe fn get_component_and_ticks(
                     changed: table
                         .get_changed_tick(component_id, location.table_row)
                         .debug_checked_unwrap(),
+                    storage: table.get_storage_ticks_for(component_id),
                 },
                 table
                     .get_changed_by(component_id, location.table_row)
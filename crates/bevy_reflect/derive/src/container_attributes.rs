@@ -14,7 +14,7 @@ use proc_macro2::{Ident, Span};
 use quote::quote_spanned;
 use syn::{
     ext::IdentExt, parenthesized, parse::ParseStream, spanned::Spanned, token, Expr, LitBool,
-    MetaList, MetaNameValue, Path, Token, WhereClause,
+    LitInt, MetaList, MetaNameValue, Path, Token, WhereClause,
 };
 
 mod kw {
@@ -26,6 +26,7 @@ mod kw {
     syn::custom_keyword!(Clone);
     syn::custom_keyword!(no_field_bounds);
     syn::custom_keyword!(opaque);
+    syn::custom_keyword!(version);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -186,6 +187,7 @@ pub(crate) struct ContainerAttributes {
     no_field_bounds: bool,
     custom_attributes: CustomAttributes,
     is_opaque: bool,
+    version: Option<LitInt>,
     idents: Vec<Ident>,
 }
 
@@ -238,6 +240,8 @@ impl ContainerAttributes {
             self.parse_opaque(input)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::version) {
+            self.parse_version(input)
         } else if lookahead.peek(kw::Clone) {
             self.parse_clone(input)
         } else if lookahead.peek(kw::Debug) {
@@ -378,6 +382,24 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `version` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(version = 2)]`
+    fn parse_version(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::version>()?;
+        input.parse::<Token![=]>()?;
+
+        let version = input.parse::<LitInt>()?;
+        version.base10_parse::<u32>()?;
+        if self.version.is_some() {
+            return Err(syn::Error::new(version.span(), "version already specified"));
+        }
+        self.version = Some(version);
+
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -587,6 +609,11 @@ impl ContainerAttributes {
     pub fn is_opaque(&self) -> bool {
         self.is_opaque
     }
+
+    /// The version of the type, used to migrate its serialized data.
+    pub fn version(&self) -> Option<&LitInt> {
+        self.version.as_ref()
+    }
 }
 
 /// Adds an identifier to a vector of identifiers if it is not already present.
@@ -277,6 +277,17 @@ impl<'a> ReflectDerive<'a> {
         #[cfg(feature = "documentation")]
         let meta = meta.with_docs(doc);
 
+        let has_named_fields =
+            matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Named(..)));
+        if let Some(version) = meta.attrs().version()
+            && (meta.attrs().is_opaque() || !has_named_fields)
+        {
+            return Err(syn::Error::new(
+                version.span(),
+                "a version can only be specified on structs with named fields",
+            ));
+        }
+
         if meta.attrs().is_opaque() {
             return Ok(Self::Opaque(meta));
         }
@@ -369,6 +380,13 @@ impl<'a> ReflectDerive<'a> {
                 |(declaration_index, field)| -> Result<StructField, syn::Error> {
                     let attrs = FieldAttributes::parse_attributes(&field.attrs)?;
 
+                    if let (None, Some(alias)) = (&field.ident, attrs.aliases.first()) {
+                        return Err(syn::Error::new(
+                            alias.span(),
+                            "aliases can only be specified on named fields",
+                        ));
+                    }
+
                     let reflection_index = if attrs.ignore.is_ignored() {
                         None
                     } else {
@@ -404,9 +422,17 @@ impl<'a> ReflectDerive<'a> {
                     Fields::Unnamed(..) => EnumVariantFields::Unnamed(fields),
                     Fields::Unit => EnumVariantFields::Unit,
                 };
+                let attrs = FieldAttributes::parse_attributes(&variant.attrs)?;
+                if let Some(alias) = attrs.aliases.first() {
+                    return Err(syn::Error::new(
+                        alias.span(),
+                        "aliases can only be specified on named fields",
+                    ));
+                }
+
                 Ok(EnumVariant {
                     fields,
-                    attrs: FieldAttributes::parse_attributes(&variant.attrs)?,
+                    attrs,
                     data: variant,
                     #[cfg(feature = "documentation")]
                     doc: crate::documentation::Documentation::from_attributes(&variant.attrs),
@@ -516,17 +542,17 @@ impl<'a> StructField<'a> {
         let ty = self.reflected_type();
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[cfg_attr(
-            not(feature = "documentation"),
-            expect(
-                unused_mut,
-                reason = "Needs to be mutable if `documentation` feature is enabled.",
-            )
-        )]
         let mut info = quote! {
             #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
         };
 
+        let aliases = &self.attrs.aliases;
+        if !aliases.is_empty() {
+            info.extend(quote! {
+                .with_aliases(&[#(#aliases),*])
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
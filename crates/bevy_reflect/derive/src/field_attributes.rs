@@ -17,6 +17,7 @@ mod kw {
     syn::custom_keyword!(clone);
     syn::custom_keyword!(default);
     syn::custom_keyword!(remote);
+    syn::custom_keyword!(alias);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -92,6 +93,8 @@ pub(crate) struct FieldAttributes {
     pub custom_attributes: CustomAttributes,
     /// For defining the remote wrapper type that should be used in place of the field for reflection logic.
     pub remote: Option<Type>,
+    /// Other names this field can be deserialized from.
+    pub aliases: Vec<LitStr>,
 }
 
 impl FieldAttributes {
@@ -139,6 +142,8 @@ impl FieldAttributes {
             self.parse_default(input)
         } else if lookahead.peek(kw::remote) {
             self.parse_remote(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
         } else {
             Err(lookahead.error())
         }
@@ -255,6 +260,26 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `alias` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(alias = "old_name")]`
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+
+        let alias = input.parse::<LitStr>()?;
+        if self.aliases.contains(&alias) {
+            return Err(syn::Error::new(
+                alias.span(),
+                format!("alias {:?} already specified", alias.value()),
+            ));
+        }
+        self.aliases.push(alias);
+
+        Ok(())
+    }
+
     /// Returns `Some(true)` if the field has a generic remote type.
     ///
     /// If the remote type is not generic, returns `Some(false)`.
@@ -209,6 +209,15 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// This is useful for when a type can't or shouldn't implement `TypePath`,
 /// or if a manual implementation is desired.
 ///
+/// ## `#[reflect(version = 2)]`
+///
+/// This attribute sets the version of a struct with named fields, so its serialized data can be
+/// migrated when its layout changes.
+///
+/// What this does is register the `TypeMigrations` type within the `GetTypeRegistration` implementation.
+/// The version is then serialized with the fields of the struct, and migrations from older versions
+/// can be registered with `TypeRegistry::register_migration`.
+///
 /// ## `#[reflect(no_field_bounds)]`
 ///
 /// This attribute will opt-out of the default trait bounds added to all field types
@@ -344,6 +353,11 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(alias = "old_name")]`
+///
+/// This attribute adds another name a named field can be deserialized from, such as the name it had
+/// before being renamed. It may be specified multiple times.
+///
 /// ## `#[reflect(clone)]`
 ///
 /// This attribute affects the `Reflect::reflect_clone` implementation.
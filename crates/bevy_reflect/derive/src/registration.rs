@@ -45,6 +45,12 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    let migrations = meta.attrs().version().map(|version| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::serde::TypeMigrations>(#bevy_reflect_path::serde::TypeMigrations::new(#version));
+        }
+    });
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
@@ -53,6 +59,7 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #migrations
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
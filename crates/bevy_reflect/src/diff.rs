@@ -0,0 +1,882 @@
+use crate::{
+    Enum, FieldId, List, ListInfo, Map, MapInfo, PartialReflect, ReflectKind, ReflectMut,
+    ReflectRef, Set, Struct, Type, TypeInfo, VariantType,
+};
+use alloc::{borrow::Cow, boxed::Box, format, string::String, vec, vec::Vec};
+use thiserror::Error;
+
+/// The difference between two reflected values, as computed by [`diff`].
+///
+/// A diff only describes the parts of a value that changed, down to the smallest value that can be
+/// compared, and can be [applied](Self::apply) to turn the first value into the second one.
+/// It can also be applied to another instance of the same type, in which case only the parts that
+/// changed are overwritten.
+///
+/// Diffs can be serialized with [`ReflectDiffSerializer`] and deserialized with
+/// [`ReflectDiffDeserializer`], using the [`TypeRegistry`] for the values they contain.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff, FieldId, Reflect, ReflectDiff};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     inventory: Vec<String>,
+/// }
+///
+/// let before = Player {
+///     name: "Ferris".into(),
+///     health: 10,
+///     inventory: vec!["sword".into(), "shield".into()],
+/// };
+/// let mut after = before.clone();
+/// after.health = 7;
+/// after.inventory.remove(0);
+///
+/// let diff = diff(&before, &after);
+/// let ReflectDiff::Fields(fields) = &diff else {
+///     panic!("expected changed fields");
+/// };
+/// // `name` didn't change.
+/// assert_eq!(fields.len(), 2);
+/// assert_eq!(fields[0].0, FieldId::Named("health".into()));
+///
+/// let mut patched = before.clone();
+/// diff.apply(&mut patched).unwrap();
+/// assert_eq!(patched, after);
+/// ```
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+/// [`TypeRegistry`]: crate::TypeRegistry
+#[derive(Debug)]
+pub enum ReflectDiff {
+    /// The values are equal.
+    Unchanged,
+    /// The value changed as a whole, and is replaced by the contained value.
+    ///
+    /// This is used for [opaque] values, for values whose types or shapes differ, and for lists that
+    /// differ in too many places. Applying it removes the list elements, map entries and set values
+    /// that the contained value doesn't have.
+    ///
+    /// [opaque]: ReflectKind::Opaque
+    Replaced(Box<dyn PartialReflect>),
+    /// The enum changed to another variant, and is replaced by the contained value.
+    Variant(Box<dyn PartialReflect>),
+    /// Some fields of a struct, tuple struct, tuple, array or enum variant changed.
+    ///
+    /// Only changed fields are listed, in order. Fields of tuples, tuple structs, arrays and tuple
+    /// variants are [unnamed](FieldId::Unnamed), and identified by their index.
+    Fields(Vec<(FieldId, ReflectDiff)>),
+    /// Elements of a list were inserted, removed or changed.
+    ///
+    /// The changes are applied in order, so each index refers to the list as modified by the
+    /// previous changes.
+    List(Vec<ListDiff>),
+    /// Entries of a map were inserted, removed or changed.
+    Map(Vec<MapDiff>),
+    /// Values of a set were inserted or removed.
+    Set(Vec<SetDiff>),
+}
+
+/// A change to a list, as part of a [`ReflectDiff`].
+#[derive(Debug)]
+pub enum ListDiff {
+    /// The value was inserted at the index.
+    Insert(usize, Box<dyn PartialReflect>),
+    /// The element at the index was removed.
+    Remove(usize),
+    /// The element at the index changed.
+    Modify(usize, ReflectDiff),
+}
+
+/// A change to a map, as part of a [`ReflectDiff`].
+#[derive(Debug)]
+pub enum MapDiff {
+    /// The key was inserted with the value, or its value was replaced.
+    Insert(Box<dyn PartialReflect>, Box<dyn PartialReflect>),
+    /// The key was removed.
+    Remove(Box<dyn PartialReflect>),
+    /// The value of the key changed.
+    Modify(Box<dyn PartialReflect>, ReflectDiff),
+}
+
+/// A change to a set, as part of a [`ReflectDiff`].
+#[derive(Debug)]
+pub enum SetDiff {
+    /// The value was inserted.
+    Insert(Box<dyn PartialReflect>),
+    /// The value was removed.
+    Remove(Box<dyn PartialReflect>),
+}
+
+/// An error that occurs when [applying](ReflectDiff::apply) a [`ReflectDiff`] to a value.
+#[derive(Error, Debug)]
+pub enum DiffApplyError {
+    /// A replacement value could not be applied.
+    #[error(transparent)]
+    Apply(#[from] crate::ApplyError),
+    /// The diff describes another kind of value.
+    #[error("cannot apply a diff of {diff} to a value of kind `{kind}`")]
+    MismatchedKinds {
+        /// What the diff applies to.
+        diff: &'static str,
+        /// The kind of the value.
+        kind: ReflectKind,
+    },
+    /// A changed field does not exist on the value.
+    #[error("the value has no field `{0}`")]
+    MissingField(FieldId),
+    /// A changed index is out of bounds of the list.
+    #[error("index {index} is out of bounds of a list of length {len}")]
+    OutOfBounds {
+        /// The changed index.
+        index: usize,
+        /// The length of the list.
+        len: usize,
+    },
+    /// A changed or removed map key, or a removed set value, does not exist in the value.
+    #[error("the value does not contain `{0}`")]
+    MissingKey(String),
+    /// An inserted list element, map key or value, or set value is not of the type the collection
+    /// contains.
+    #[error("cannot insert a value of type `{found}` where `{expected}` is expected")]
+    MismatchedType {
+        /// The type path of the values the collection contains.
+        expected: &'static str,
+        /// The type path of the inserted value.
+        found: String,
+    },
+}
+
+/// The largest number of element pairs compared when diffing two lists, once their common start
+/// and end are left out. Lists with more differences are [replaced](ReflectDiff::Replaced) as a
+/// whole rather than diffed element by element.
+const MAX_LIST_DIFF_PAIRS: usize = 1 << 16;
+
+/// Computes the difference between `a` and `b`.
+///
+/// Applying the result to `a` turns it into `b`. See [`ReflectDiff`] for more information.
+///
+/// Values are compared field by field, element by element and entry by entry, down to [opaque]
+/// values, which are compared with [`PartialReflect::reflect_partial_eq`]. Opaque values that don't
+/// support comparison are always considered changed. Elements of lists are matched with
+/// [`PartialReflect::reflect_partial_eq`] as well, so the diff contains the fewest insertions and
+/// removals, unless the lists differ in too many places, in which case the whole list is replaced.
+///
+/// # Panics
+///
+/// Panics if a changed value must be stored in the diff, but it is opaque and cannot be cloned,
+/// as with [`PartialReflect::to_dynamic`].
+///
+/// [opaque]: ReflectKind::Opaque
+pub fn diff(a: &dyn PartialReflect, b: &dyn PartialReflect) -> ReflectDiff {
+    let same_type = match (a.get_represented_type_info(), b.get_represented_type_info()) {
+        (Some(a), Some(b)) => a.type_id() == b.type_id(),
+        _ => true,
+    };
+
+    let diff = match (a.reflect_ref(), b.reflect_ref()) {
+        _ if !same_type => None,
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => diff_struct(a, b),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => diff_fields(
+            a.field_len(),
+            b.field_len(),
+            |index| a.field(index),
+            |index| b.field(index),
+        ),
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => diff_fields(
+            a.field_len(),
+            b.field_len(),
+            |index| a.field(index),
+            |index| b.field(index),
+        ),
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            diff_fields(a.len(), b.len(), |index| a.get(index), |index| b.get(index))
+        }
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => Some(diff_enum(a, b)),
+        (ReflectRef::List(a), ReflectRef::List(b)) => diff_list(a, b),
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => Some(diff_map(a, b)),
+        (ReflectRef::Set(a), ReflectRef::Set(b)) => Some(diff_set(a, b)),
+        (ReflectRef::Opaque(a), ReflectRef::Opaque(b)) if a.reflect_partial_eq(b) == Some(true) => {
+            Some(ReflectDiff::Unchanged)
+        }
+        _ => None,
+    };
+    diff.unwrap_or_else(|| ReflectDiff::Replaced(b.to_dynamic()))
+}
+
+/// Returns [`ReflectDiff::Unchanged`] if there are no `changes`, or wraps them with `diff`.
+fn changes<T>(changes: Vec<T>, diff: fn(Vec<T>) -> ReflectDiff) -> ReflectDiff {
+    if changes.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        diff(changes)
+    }
+}
+
+fn diff_struct(a: &dyn Struct, b: &dyn Struct) -> Option<ReflectDiff> {
+    if a.field_len() != b.field_len() {
+        return None;
+    }
+    let mut fields = Vec::new();
+    for (index, b_field) in b.iter_fields().enumerate() {
+        let name = b.name_at(index)?;
+        let diff = diff(a.field(name)?, b_field);
+        if !diff.is_unchanged() {
+            fields.push((FieldId::Named(Cow::Owned(name.into())), diff));
+        }
+    }
+    Some(changes(fields, ReflectDiff::Fields))
+}
+
+fn diff_fields<'a>(
+    a_len: usize,
+    b_len: usize,
+    a: impl Fn(usize) -> Option<&'a dyn PartialReflect>,
+    b: impl Fn(usize) -> Option<&'a dyn PartialReflect>,
+) -> Option<ReflectDiff> {
+    if a_len != b_len {
+        return None;
+    }
+    let mut fields = Vec::new();
+    for index in 0..b_len {
+        let diff = diff(a(index)?, b(index)?);
+        if !diff.is_unchanged() {
+            fields.push((FieldId::Unnamed(index), diff));
+        }
+    }
+    Some(changes(fields, ReflectDiff::Fields))
+}
+
+fn diff_enum(a: &dyn Enum, b: &dyn Enum) -> ReflectDiff {
+    let fields = if a.variant_name() != b.variant_name() || a.field_len() != b.field_len() {
+        None
+    } else if b.variant_type() == VariantType::Struct {
+        diff_struct_variant(a, b)
+    } else {
+        diff_fields(
+            a.field_len(),
+            b.field_len(),
+            |index| a.field_at(index),
+            |index| b.field_at(index),
+        )
+    };
+    fields.unwrap_or_else(|| ReflectDiff::Variant(b.to_dynamic()))
+}
+
+fn diff_struct_variant(a: &dyn Enum, b: &dyn Enum) -> Option<ReflectDiff> {
+    let mut fields = Vec::new();
+    for (index, b_field) in b.iter_fields().enumerate() {
+        let name = b.name_at(index)?;
+        let diff = diff(a.field(name)?, b_field.value());
+        if !diff.is_unchanged() {
+            fields.push((FieldId::Named(Cow::Owned(name.into())), diff));
+        }
+    }
+    Some(changes(fields, ReflectDiff::Fields))
+}
+
+fn diff_list(a: &dyn List, b: &dyn List) -> Option<ReflectDiff> {
+    let a: Vec<_> = a.iter().collect();
+    let b: Vec<_> = b.iter().collect();
+    let same =
+        |a: &dyn PartialReflect, b: &dyn PartialReflect| a.reflect_partial_eq(b) == Some(true);
+
+    // Only the part between the common start and end needs to be matched.
+    let start = a.iter().zip(&b).take_while(|(a, b)| same(**a, **b)).count();
+    let end = a[start..]
+        .iter()
+        .rev()
+        .zip(b[start..].iter().rev())
+        .take_while(|(a, b)| same(**a, **b))
+        .count();
+    let (a, b) = (&a[start..a.len() - end], &b[start..b.len() - end]);
+    if (a.len() + 1).saturating_mul(b.len() + 1) > MAX_LIST_DIFF_PAIRS {
+        return None;
+    }
+    let equal = |i: usize, j: usize| same(a[i], b[j]);
+
+    // The length of the longest common subsequence of each pair of suffixes.
+    let width = b.len() + 1;
+    let mut common = vec![0usize; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            common[i * width + j] = if equal(i, j) {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut list = Vec::new();
+    let (mut i, mut j, mut index) = (0, 0, start);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() {
+            if equal(i, j) {
+                i += 1;
+                j += 1;
+                index += 1;
+                continue;
+            }
+            // Replacing the element loses nothing, so it's changed in place.
+            if common[(i + 1) * width + j + 1] == common[i * width + j] {
+                list.push(ListDiff::Modify(index, diff(a[i], b[j])));
+                i += 1;
+                j += 1;
+                index += 1;
+                continue;
+            }
+        }
+        if i < a.len() && (j == b.len() || common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            list.push(ListDiff::Remove(index));
+            i += 1;
+        } else {
+            list.push(ListDiff::Insert(index, b[j].to_dynamic()));
+            j += 1;
+            index += 1;
+        }
+    }
+    Some(changes(list, ReflectDiff::List))
+}
+
+fn diff_map(a: &dyn Map, b: &dyn Map) -> ReflectDiff {
+    let mut map = Vec::new();
+    for (key, b_value) in b.iter() {
+        match a.get(key) {
+            Some(a_value) => {
+                let diff = diff(a_value, b_value);
+                if !diff.is_unchanged() {
+                    map.push(MapDiff::Modify(key.to_dynamic(), diff));
+                }
+            }
+            None => map.push(MapDiff::Insert(key.to_dynamic(), b_value.to_dynamic())),
+        }
+    }
+    for (key, _) in a.iter() {
+        if b.get(key).is_none() {
+            map.push(MapDiff::Remove(key.to_dynamic()));
+        }
+    }
+    changes(map, ReflectDiff::Map)
+}
+
+fn diff_set(a: &dyn Set, b: &dyn Set) -> ReflectDiff {
+    let removed = a
+        .iter()
+        .filter(|value| !b.contains(*value))
+        .map(|value| SetDiff::Remove(value.to_dynamic()));
+    let inserted = b
+        .iter()
+        .filter(|value| !a.contains(*value))
+        .map(|value| SetDiff::Insert(value.to_dynamic()));
+    changes(removed.chain(inserted).collect(), ReflectDiff::Set)
+}
+
+impl ReflectDiff {
+    /// Returns `true` if this is [`ReflectDiff::Unchanged`].
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// Applies the changes to `target`.
+    ///
+    /// If `target` is the first value passed to [`diff`], it becomes equal to the second one.
+    /// Otherwise, only the parts of `target` that the diff describes are changed.
+    ///
+    /// If an error is returned, the changes that come before the error have been applied.
+    ///
+    /// Inserted list elements, map entries and set values must represent the type the collection
+    /// contains, or [`DiffApplyError::MismatchedType`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an inserted value represents the type the collection contains, but can't be
+    /// converted to it, as with [`List::insert`]. This can only happen with a dynamic value that
+    /// is missing fields, which neither [`diff`] nor [`ReflectDiffDeserializer`] produce.
+    ///
+    /// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), DiffApplyError> {
+        let kind = target.reflect_kind();
+        let mismatch = |diff| DiffApplyError::MismatchedKinds { diff, kind };
+        match self {
+            Self::Unchanged => {}
+            Self::Replaced(value) | Self::Variant(value) => replace(target, value.as_ref())?,
+            Self::Fields(fields) => {
+                for (id, diff) in fields {
+                    let field = match (target.reflect_mut(), id) {
+                        (ReflectMut::Struct(value), FieldId::Named(name)) => value.field_mut(name),
+                        (ReflectMut::TupleStruct(value), FieldId::Unnamed(index)) => {
+                            value.field_mut(*index)
+                        }
+                        (ReflectMut::Tuple(value), FieldId::Unnamed(index)) => {
+                            value.field_mut(*index)
+                        }
+                        (ReflectMut::Array(value), FieldId::Unnamed(index)) => {
+                            value.get_mut(*index)
+                        }
+                        (ReflectMut::Enum(value), FieldId::Named(name)) => value.field_mut(name),
+                        (ReflectMut::Enum(value), FieldId::Unnamed(index)) => {
+                            value.field_at_mut(*index)
+                        }
+                        (
+                            ReflectMut::Struct(_)
+                            | ReflectMut::TupleStruct(_)
+                            | ReflectMut::Tuple(_)
+                            | ReflectMut::Array(_),
+                            _,
+                        ) => None,
+                        _ => return Err(mismatch("fields")),
+                    };
+                    diff.apply(field.ok_or_else(|| DiffApplyError::MissingField(id.clone()))?)?;
+                }
+            }
+            Self::List(changes) => {
+                let ReflectMut::List(list) = target.reflect_mut() else {
+                    return Err(mismatch("a list"));
+                };
+                let item_ty = list.get_represented_list_info().map(ListInfo::item_ty);
+                for change in changes {
+                    let len = list.len();
+                    let out_of_bounds = |index| DiffApplyError::OutOfBounds { index, len };
+                    match change {
+                        ListDiff::Insert(index, value) => {
+                            if *index > len {
+                                return Err(out_of_bounds(*index));
+                            }
+                            check_type(value.as_ref(), item_ty)?;
+                            list.insert(*index, value.to_dynamic());
+                        }
+                        ListDiff::Remove(index) => {
+                            if *index >= len {
+                                return Err(out_of_bounds(*index));
+                            }
+                            list.remove(*index);
+                        }
+                        ListDiff::Modify(index, diff) => {
+                            let element =
+                                list.get_mut(*index).ok_or_else(|| out_of_bounds(*index))?;
+                            diff.apply(element)?;
+                        }
+                    }
+                }
+            }
+            Self::Map(changes) => {
+                let ReflectMut::Map(map) = target.reflect_mut() else {
+                    return Err(mismatch("a map"));
+                };
+                let info = map.get_represented_map_info();
+                for change in changes {
+                    match change {
+                        MapDiff::Insert(key, value) => {
+                            check_type(key.as_ref(), info.map(MapInfo::key_ty))?;
+                            check_type(value.as_ref(), info.map(MapInfo::value_ty))?;
+                            map.insert_boxed(key.to_dynamic(), value.to_dynamic());
+                        }
+                        MapDiff::Remove(key) => {
+                            map.remove(key.as_ref())
+                                .ok_or_else(|| missing_key(key.as_ref()))?;
+                        }
+                        MapDiff::Modify(key, diff) => {
+                            let value = map
+                                .get_mut(key.as_ref())
+                                .ok_or_else(|| missing_key(key.as_ref()))?;
+                            diff.apply(value)?;
+                        }
+                    }
+                }
+            }
+            Self::Set(changes) => {
+                let ReflectMut::Set(set) = target.reflect_mut() else {
+                    return Err(mismatch("a set"));
+                };
+                let value_ty = match set.get_represented_type_info() {
+                    Some(TypeInfo::Set(info)) => Some(info.value_ty()),
+                    _ => None,
+                };
+                for change in changes {
+                    match change {
+                        SetDiff::Insert(value) => {
+                            check_type(value.as_ref(), value_ty)?;
+                            set.insert_boxed(value.to_dynamic());
+                        }
+                        SetDiff::Remove(value) => {
+                            if !set.remove(value.as_ref()) {
+                                return Err(missing_key(value.as_ref()));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Overwrites `target` with `value`.
+///
+/// Unlike [`PartialReflect::try_apply`], which only overwrites and adds, list elements, map
+/// entries and set values of `target` that `value` doesn't have are removed, at any depth.
+fn replace(
+    target: &mut dyn PartialReflect,
+    value: &dyn PartialReflect,
+) -> Result<(), DiffApplyError> {
+    match (target.reflect_mut(), value.reflect_ref()) {
+        (ReflectMut::Struct(target), ReflectRef::Struct(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap_or_default();
+                let target = target.field_mut(name).ok_or_else(|| {
+                    DiffApplyError::MissingField(FieldId::Named(Cow::Owned(name.into())))
+                })?;
+                replace(target, field)?;
+            }
+        }
+        (ReflectMut::TupleStruct(target), ReflectRef::TupleStruct(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let target = target
+                    .field_mut(index)
+                    .ok_or(DiffApplyError::MissingField(FieldId::Unnamed(index)))?;
+                replace(target, field)?;
+            }
+        }
+        (ReflectMut::Tuple(target), ReflectRef::Tuple(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let target = target
+                    .field_mut(index)
+                    .ok_or(DiffApplyError::MissingField(FieldId::Unnamed(index)))?;
+                replace(target, field)?;
+            }
+        }
+        (ReflectMut::Array(target), ReflectRef::Array(value)) => {
+            for (index, element) in value.iter().enumerate() {
+                let target = target
+                    .get_mut(index)
+                    .ok_or(DiffApplyError::MissingField(FieldId::Unnamed(index)))?;
+                replace(target, element)?;
+            }
+        }
+        (ReflectMut::Enum(target), ReflectRef::Enum(value))
+            if target.variant_name() == value.variant_name()
+                && target.field_len() == value.field_len() =>
+        {
+            for (index, field) in value.iter_fields().enumerate() {
+                let target = target
+                    .field_at_mut(index)
+                    .ok_or(DiffApplyError::MissingField(FieldId::Unnamed(index)))?;
+                replace(target, field.value())?;
+            }
+        }
+        (ReflectMut::List(target), ReflectRef::List(value)) => {
+            let item_ty = target.get_represented_list_info().map(ListInfo::item_ty);
+            while target.len() > value.len() {
+                target.pop();
+            }
+            for (index, element) in value.iter().enumerate() {
+                match target.get_mut(index) {
+                    Some(target) => replace(target, element)?,
+                    None => {
+                        check_type(element, item_ty)?;
+                        target.push(element.to_dynamic());
+                    }
+                }
+            }
+        }
+        (ReflectMut::Map(target), ReflectRef::Map(value)) => {
+            let info = target.get_represented_map_info();
+            let removed: Vec<_> = target
+                .iter()
+                .filter(|(key, _)| value.get(*key).is_none())
+                .map(|(key, _)| key.to_dynamic())
+                .collect();
+            for key in removed {
+                target.remove(key.as_ref());
+            }
+            for (key, element) in value.iter() {
+                match target.get_mut(key) {
+                    Some(target) => replace(target, element)?,
+                    None => {
+                        check_type(key, info.map(MapInfo::key_ty))?;
+                        check_type(element, info.map(MapInfo::value_ty))?;
+                        target.insert_boxed(key.to_dynamic(), element.to_dynamic());
+                    }
+                }
+            }
+        }
+        (ReflectMut::Set(target), ReflectRef::Set(value)) => {
+            let value_ty = match target.get_represented_type_info() {
+                Some(TypeInfo::Set(info)) => Some(info.value_ty()),
+                _ => None,
+            };
+            let removed: Vec<_> = target
+                .iter()
+                .filter(|element| !value.contains(*element))
+                .map(PartialReflect::to_dynamic)
+                .collect();
+            for element in removed {
+                target.remove(element.as_ref());
+            }
+            for element in value.iter() {
+                if !target.contains(element) {
+                    check_type(element, value_ty)?;
+                    target.insert_boxed(element.to_dynamic());
+                }
+            }
+        }
+        _ => target.try_apply(value)?,
+    }
+    Ok(())
+}
+
+fn missing_key(key: &dyn PartialReflect) -> DiffApplyError {
+    DiffApplyError::MissingKey(format!("{key:?}"))
+}
+
+/// Checks that `value` can be inserted in a collection containing values of the `expected` type,
+/// if the collection has one.
+fn check_type(value: &dyn PartialReflect, expected: Option<Type>) -> Result<(), DiffApplyError> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    match value.get_represented_type_info() {
+        Some(info) if info.type_id() == expected.id() => Ok(()),
+        _ => Err(DiffApplyError::MismatchedType {
+            expected: expected.path(),
+            found: value.reflect_type_path().into(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_reflect,
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+        Reflect, TypeRegistry,
+    };
+    use alloc::string::ToString;
+    use bevy_platform::collections::{HashMap, HashSet};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Data {
+        id: u32,
+        position: (f32, f32),
+        shape: Shape,
+        items: Vec<Item>,
+        stats: HashMap<String, u32>,
+        tags: HashSet<String>,
+    }
+
+    fn data() -> Data {
+        Data {
+            id: 1,
+            position: (0.0, 0.0),
+            shape: Shape::Rect {
+                width: 1.0,
+                height: 2.0,
+            },
+            items: vec![
+                Item {
+                    name: "a".to_string(),
+                    count: 1,
+                },
+                Item {
+                    name: "b".to_string(),
+                    count: 2,
+                },
+                Item {
+                    name: "c".to_string(),
+                    count: 3,
+                },
+            ],
+            stats: HashMap::from_iter([("hp".to_string(), 10), ("mp".to_string(), 5)]),
+            tags: HashSet::from_iter(["x".to_string(), "y".to_string()]),
+        }
+    }
+
+    fn modified() -> Data {
+        let mut data = data();
+        data.position.1 = 4.0;
+        data.shape = Shape::Rect {
+            width: 1.0,
+            height: 3.0,
+        };
+        data.items.remove(0);
+        data.items[1].count = 30;
+        data.items.push(Item {
+            name: "d".to_string(),
+            count: 4,
+        });
+        data.stats.insert("hp".to_string(), 8);
+        data.stats.remove("mp");
+        data.stats.insert("xp".to_string(), 1);
+        data.tags.remove("x");
+        data.tags.insert("z".to_string());
+        data
+    }
+
+    #[test]
+    fn should_diff_and_apply() {
+        let (a, b) = (data(), modified());
+        assert!(diff(&a, &a).is_unchanged());
+
+        let diff = diff(&a, &b);
+        let ReflectDiff::Fields(fields) = &diff else {
+            panic!("expected field changes, got {diff:?}");
+        };
+        let names: Vec<_> = fields.iter().map(|(id, _)| id.to_string()).collect();
+        assert_eq!(names, ["position", "shape", "items", "stats", "tags"]);
+
+        let mut patched = a.clone();
+        diff.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+
+    #[test]
+    fn should_only_change_diffed_fields() {
+        let mut b = data();
+        b.id = 2;
+        b.items[0].count = 10;
+        let diff = diff(&data(), &b);
+
+        let mut other = modified();
+        diff.apply(&mut other).unwrap();
+        assert_eq!(other.id, 2);
+        assert_eq!(other.items[0].count, 10);
+        assert_eq!(other.items[1], modified().items[1]);
+        assert_eq!(other.shape, modified().shape);
+    }
+
+    #[test]
+    fn should_replace_enum_variant() {
+        let a = Shape::Circle(1.0);
+        let b = Shape::Rect {
+            width: 1.0,
+            height: 1.0,
+        };
+        let diff = diff(&a, &b);
+        assert!(matches!(diff, ReflectDiff::Variant(_)));
+
+        let mut patched = a.clone();
+        diff.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+
+    #[test]
+    fn should_fail_to_apply_to_mismatched_value() {
+        let diff = diff(&vec![1, 2, 3], &vec![1, 2]);
+        assert!(matches!(
+            diff.apply(&mut Vec::<i32>::new()),
+            Err(DiffApplyError::OutOfBounds { index: 2, len: 0 })
+        ));
+        assert!(matches!(
+            diff.apply(&mut data()),
+            Err(DiffApplyError::MismatchedKinds { .. })
+        ));
+    }
+
+    #[test]
+    fn should_reject_inserted_values_of_other_types() {
+        let changes = diff(
+            &vec!["a".to_string()],
+            &vec!["a".to_string(), "b".to_string()],
+        );
+        assert!(matches!(
+            changes.apply(&mut vec![1_u32]),
+            Err(DiffApplyError::MismatchedType { .. })
+        ));
+
+        let changes = diff(
+            &HashMap::<String, u32>::new(),
+            &HashMap::<String, u32>::from_iter([("a".to_string(), 1)]),
+        );
+        assert!(matches!(
+            changes.apply(&mut HashMap::<String, String>::new()),
+            Err(DiffApplyError::MismatchedType { .. })
+        ));
+
+        let changes = diff(&HashSet::<u32>::new(), &HashSet::<u32>::from_iter([1]));
+        assert!(matches!(
+            changes.apply(&mut HashSet::<String>::new()),
+            Err(DiffApplyError::MismatchedType { .. })
+        ));
+    }
+
+    #[test]
+    fn should_limit_list_diff_size() {
+        let a: Vec<u32> = (0..1000).collect();
+
+        // Only the changed part of the list is compared.
+        let mut b = a.clone();
+        b.insert(500, 0);
+        let changes = diff(&a, &b);
+        assert!(matches!(&changes, ReflectDiff::List(changes) if changes.len() == 1));
+
+        let b: Vec<u32> = (0..1000).rev().collect();
+        let changes = diff(&a, &b);
+        assert!(matches!(changes, ReflectDiff::Replaced(_)));
+        let mut patched = a.clone();
+        changes.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+
+    #[test]
+    fn should_remove_extra_elements_when_replacing() {
+        let a: Vec<u32> = (0..1000).collect();
+        let b: Vec<u32> = (0..600).rev().collect();
+        let changes = diff(&a, &b);
+        assert!(matches!(changes, ReflectDiff::Replaced(_)));
+        let mut patched = a.clone();
+        changes.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+
+        // Nested collections of the replaced value are replaced as well.
+        let a: Vec<HashMap<u32, Vec<u32>>> = (0..300)
+            .map(|i| HashMap::from_iter([(i, vec![i, i]), (i + 1, vec![i])]))
+            .collect();
+        let b: Vec<HashMap<u32, Vec<u32>>> = (0..300)
+            .rev()
+            .map(|i| HashMap::from_iter([(i, vec![i])]))
+            .collect();
+        let changes = diff(&a, &b);
+        assert!(matches!(changes, ReflectDiff::Replaced(_)));
+        let mut patched = a.clone();
+        changes.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+
+    #[test]
+    fn should_roundtrip_serialized_diff() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Data>();
+        let (a, b) = (data(), modified());
+        let diff = diff(&a, &b);
+
+        let serialized = ron::to_string(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut patched = a.clone();
+        deserialized.apply(&mut patched).unwrap();
+        assert_eq!(patched, b);
+    }
+}
@@ -13,6 +13,7 @@ pub struct NamedField {
     name: &'static str,
     type_info: fn() -> Option<&'static TypeInfo>,
     ty: Type,
+    aliases: &'static [&'static str],
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -25,6 +26,7 @@ impl NamedField {
             name,
             type_info: T::maybe_type_info,
             ty: Type::of::<T>(),
+            aliases: &[],
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -45,11 +47,25 @@ impl NamedField {
         }
     }
 
+    /// Sets the other names this field can be deserialized from, such as its previous names.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The other names this field can be deserialized from.
+    ///
+    /// These are set with the `#[reflect(alias = "...")]` attribute when deriving [`Reflect`].
+    ///
+    /// [`Reflect`]: crate::Reflect
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// The [`TypeInfo`] of the field.
     ///
     ///
@@ -568,6 +568,7 @@ extern crate alloc;
 extern crate self as bevy_reflect;
 
 mod array;
+mod diff;
 mod error;
 mod fields;
 mod from_reflect;
@@ -640,6 +641,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use error::*;
 pub use fields::*;
@@ -1,10 +1,12 @@
 //! Representation for individual element accesses within a path.
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box, string::String, vec, vec::Vec};
 use core::fmt;
 
 use super::error::AccessErrorKind;
-use crate::{AccessError, PartialReflect, ReflectKind, ReflectMut, ReflectRef, VariantType};
+use crate::{
+    AccessError, Map, MapInfo, PartialReflect, ReflectKind, ReflectMut, ReflectRef, VariantType,
+};
 
 type InnerResult<T> = Result<T, AccessErrorKind>;
 
@@ -22,6 +24,16 @@ pub enum Access<'a> {
     TupleIndex(usize),
     /// An index-based access on a list.
     ListIndex(usize),
+    /// A key-based access on a map with string keys.
+    ///
+    /// The key is a pattern, where `*` matches any sequence of characters
+    /// and `\` makes the following character match itself.
+    /// A pattern containing an unescaped `*` is a [wildcard](Access::is_wildcard).
+    MapKey(Cow<'a, str>),
+    /// A wildcard access to every element of a list or an array, or every value of a map.
+    ListWildcard,
+    /// A wildcard access to every field of a struct, a tuple struct, a tuple or an enum variant.
+    FieldWildcard,
 }
 
 impl fmt::Display for Access<'_> {
@@ -31,6 +43,9 @@ impl fmt::Display for Access<'_> {
             Access::FieldIndex(index) => write!(f, "#{index}"),
             Access::TupleIndex(index) => write!(f, ".{index}"),
             Access::ListIndex(index) => write!(f, "[{index}]"),
+            Access::MapKey(key) => write!(f, "[\"{key}\"]"),
+            Access::ListWildcard => f.write_str("[*]"),
+            Access::FieldWildcard => f.write_str(".*"),
         }
     }
 }
@@ -47,6 +62,26 @@ impl<'a> Access<'a> {
             Self::FieldIndex(value) => Access::FieldIndex(value),
             Self::TupleIndex(value) => Access::TupleIndex(value),
             Self::ListIndex(value) => Access::ListIndex(value),
+            Self::MapKey(value) => Access::MapKey(Cow::Owned(value.into_owned())),
+            Self::ListWildcard => Access::ListWildcard,
+            Self::FieldWildcard => Access::FieldWildcard,
+        }
+    }
+
+    /// Returns `true` if this [`Access`] can target several elements.
+    ///
+    /// Wildcards can only be used with [`ParsedPath::reflect_elements`] and
+    /// [`ParsedPath::reflect_elements_mut`].
+    ///
+    /// [`ParsedPath::reflect_elements`]: super::ParsedPath::reflect_elements
+    /// [`ParsedPath::reflect_elements_mut`]: super::ParsedPath::reflect_elements_mut
+    pub fn is_wildcard(&self) -> bool {
+        match self {
+            Self::ListWildcard | Self::FieldWildcard => true,
+            Self::MapKey(pattern) => parse_pattern(pattern).contains(&None),
+            Self::Field(_) | Self::FieldIndex(_) | Self::TupleIndex(_) | Self::ListIndex(_) => {
+                false
+            }
         }
     }
 
@@ -69,6 +104,10 @@ impl<'a> Access<'a> {
         let invalid_variant =
             |expected, actual| AccessErrorKind::IncompatibleEnumVariantTypes { expected, actual };
 
+        if self.is_wildcard() {
+            return Err(AccessErrorKind::MultipleTargets);
+        }
+
         match (self, base.reflect_ref()) {
             (Self::Field(field), Struct(struct_ref)) => Ok(struct_ref.field(field.as_ref())),
             (Self::Field(field), Enum(enum_ref)) => match enum_ref.variant_type() {
@@ -100,8 +139,27 @@ impl<'a> Access<'a> {
 
             (&Self::ListIndex(index), List(list)) => Ok(list.get(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get(index)),
-            (Self::ListIndex(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
-                expected: ReflectKind::List,
+            (Self::ListIndex(_) | Self::ListWildcard, actual) => {
+                Err(AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::List,
+                    actual: actual.into(),
+                })
+            }
+
+            (Self::MapKey(pattern), Map(map)) => Ok(match map_key(map, pattern) {
+                Some(key) => map.get(key.as_ref()),
+                None => map
+                    .iter()
+                    .find(|(key, _)| key_matches(pattern, *key))
+                    .map(|(_, value)| value),
+            }),
+            (Self::MapKey(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::Map,
+                actual: actual.into(),
+            }),
+
+            (Self::FieldWildcard, actual) => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::Struct,
                 actual: actual.into(),
             }),
         }
@@ -128,6 +186,10 @@ impl<'a> Access<'a> {
         let invalid_variant =
             |expected, actual| AccessErrorKind::IncompatibleEnumVariantTypes { expected, actual };
 
+        if self.is_wildcard() {
+            return Err(AccessErrorKind::MultipleTargets);
+        }
+
         match (self, base.reflect_mut()) {
             (Self::Field(field), Struct(struct_mut)) => Ok(struct_mut.field_mut(field.as_ref())),
             (Self::Field(field), Enum(enum_mut)) => match enum_mut.variant_type() {
@@ -159,18 +221,118 @@ impl<'a> Access<'a> {
 
             (&Self::ListIndex(index), List(list)) => Ok(list.get_mut(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get_mut(index)),
-            (Self::ListIndex(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
-                expected: ReflectKind::List,
+            (Self::ListIndex(_) | Self::ListWildcard, actual) => {
+                Err(AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::List,
+                    actual: actual.into(),
+                })
+            }
+
+            (Self::MapKey(pattern), Map(map)) => match map_key(map, pattern) {
+                Some(key) => Ok(map.get_mut(key.as_ref())),
+                None => {
+                    let index = map.iter().position(|(key, _)| key_matches(pattern, key));
+                    Ok(index
+                        .and_then(|index| map.get_at_mut(index))
+                        .map(|(_, value)| value))
+                }
+            },
+            (Self::MapKey(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::Map,
                 actual: actual.into(),
             }),
+
+            (Self::FieldWildcard, actual) => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::Struct,
+                actual: actual.into(),
+            }),
+        }
+    }
+
+    /// Returns every element of `base` targeted by this [`Access`], along with its position.
+    ///
+    /// The position can be passed to [`element_at_mut`](Self::element_at_mut) to get the
+    /// element mutably. Accesses that aren't wildcards return a single element.
+    pub(super) fn elements<'r>(
+        &self,
+        base: &'r dyn PartialReflect,
+        offset: Option<usize>,
+    ) -> Result<Vec<(usize, &'r dyn PartialReflect)>, AccessError<'a>> {
+        use ReflectRef::*;
+
+        if !self.is_wildcard() {
+            return Ok(vec![(0, self.element(base, offset)?)]);
+        }
+
+        let elements = match (self, base.reflect_ref()) {
+            (Self::ListWildcard, List(list)) => list.iter().enumerate().collect(),
+            (Self::ListWildcard, Array(array)) => array.iter().enumerate().collect(),
+            (Self::ListWildcard, Map(map)) => {
+                map.iter().map(|(_, value)| value).enumerate().collect()
+            }
+            (Self::MapKey(pattern), Map(map)) => map
+                .iter()
+                .enumerate()
+                .filter(|(_, (key, _))| key_matches(pattern, *key))
+                .map(|(index, (_, value))| (index, value))
+                .collect(),
+            (Self::FieldWildcard, Struct(struct_ref)) => {
+                struct_ref.iter_fields().enumerate().collect()
+            }
+            (Self::FieldWildcard, TupleStruct(tuple)) => tuple.iter_fields().enumerate().collect(),
+            (Self::FieldWildcard, Tuple(tuple)) => tuple.iter_fields().enumerate().collect(),
+            (Self::FieldWildcard, Enum(enum_ref)) => enum_ref
+                .iter_fields()
+                .map(|field| field.value())
+                .enumerate()
+                .collect(),
+            (_, actual) => {
+                let expected = match self {
+                    Self::MapKey(_) => ReflectKind::Map,
+                    Self::FieldWildcard => ReflectKind::Struct,
+                    _ => ReflectKind::List,
+                };
+                return Err(AccessErrorKind::IncompatibleTypes {
+                    expected,
+                    actual: actual.into(),
+                }
+                .with_access(self.clone(), offset));
+            }
+        };
+        Ok(elements)
+    }
+
+    /// Returns a mutable reference to the element at `position`, as returned by
+    /// [`elements`](Self::elements).
+    pub(super) fn element_at_mut<'r>(
+        &self,
+        base: &'r mut dyn PartialReflect,
+        position: usize,
+    ) -> Option<&'r mut dyn PartialReflect> {
+        use ReflectMut::*;
+
+        if !self.is_wildcard() {
+            return self.element_mut(base, None).ok();
+        }
+
+        match base.reflect_mut() {
+            List(list) => list.get_mut(position),
+            Array(array) => array.get_mut(position),
+            Map(map) => map.get_at_mut(position).map(|(_, value)| value),
+            Struct(struct_mut) => struct_mut.field_at_mut(position),
+            TupleStruct(tuple) => tuple.field_mut(position),
+            Tuple(tuple) => tuple.field_mut(position),
+            Enum(enum_mut) => enum_mut.field_at_mut(position),
+            _ => None,
         }
     }
 
     /// Returns a reference to this [`Access`]'s inner value as a [`&dyn Display`](fmt::Display).
     pub fn display_value(&self) -> &dyn fmt::Display {
         match self {
-            Self::Field(value) => value,
+            Self::Field(value) | Self::MapKey(value) => value,
             Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => value,
+            Self::ListWildcard | Self::FieldWildcard => &"*",
         }
     }
 
@@ -179,6 +341,82 @@ impl<'a> Access<'a> {
             Self::Field(_) => "field",
             Self::FieldIndex(_) => "field index",
             Self::TupleIndex(_) | Self::ListIndex(_) => "index",
+            Self::MapKey(_) => "key",
+            Self::ListWildcard => "index wildcard",
+            Self::FieldWildcard => "field wildcard",
         }
     }
 }
+
+/// Converts the key `pattern` of an [`Access::MapKey`] that isn't a wildcard into a key of
+/// the string type `map` is keyed by, so that it can be looked up with [`Map::get`].
+///
+/// Returns `None` for maps keyed by `&'static str`, as such a key can't be created from the path.
+fn map_key(map: &dyn Map, pattern: &str) -> Option<Box<dyn PartialReflect>> {
+    let key = parse_pattern(pattern)
+        .into_iter()
+        .flatten()
+        .collect::<String>();
+    match map.get_represented_map_info().map(MapInfo::key_ty) {
+        Some(key_ty) if key_ty.is::<&'static str>() => None,
+        Some(key_ty) if key_ty.is::<Cow<'static, str>>() => {
+            Some(Box::new(Cow::<'static, str>::Owned(key)))
+        }
+        _ => Some(Box::new(key)),
+    }
+}
+
+/// Returns `true` if `key` is a string matching the key `pattern` of an [`Access::MapKey`].
+fn key_matches(pattern: &str, key: &dyn PartialReflect) -> bool {
+    let key = if let Some(key) = key.try_downcast_ref::<String>() {
+        key.as_str()
+    } else if let Some(key) = key.try_downcast_ref::<&'static str>() {
+        *key
+    } else if let Some(key) = key.try_downcast_ref::<Cow<'static, str>>() {
+        key.as_ref()
+    } else {
+        return false;
+    };
+
+    let pattern = parse_pattern(pattern);
+    let key = key.chars().collect::<Vec<_>>();
+
+    // Greedy matching, backtracking to the last `*` on mismatches.
+    let (mut pattern_index, mut key_index) = (0, 0);
+    let mut backtrack = None;
+    while key_index < key.len() {
+        match pattern.get(pattern_index) {
+            Some(None) => {
+                backtrack = Some((pattern_index, key_index));
+                pattern_index += 1;
+            }
+            Some(Some(char)) if *char == key[key_index] => {
+                pattern_index += 1;
+                key_index += 1;
+            }
+            _ => {
+                let Some((star_index, star_key_index)) = backtrack else {
+                    return false;
+                };
+                pattern_index = star_index + 1;
+                key_index = star_key_index + 1;
+                backtrack = Some((star_index, key_index));
+            }
+        }
+    }
+    pattern[pattern_index..].iter().all(Option::is_none)
+}
+
+/// Splits a key pattern into the characters it matches, with `None` standing for `*`.
+fn parse_pattern(pattern: &str) -> Vec<Option<char>> {
+    let mut chars = pattern.chars();
+    let mut parsed = Vec::new();
+    while let Some(char) = chars.next() {
+        parsed.push(match char {
+            '*' => None,
+            '\\' => Some(chars.next().unwrap_or('\\')),
+            char => Some(char),
+        });
+    }
+    parsed
+}
@@ -27,6 +27,10 @@ pub enum AccessErrorKind {
         /// The actual [`VariantType`] that was found.
         actual: VariantType,
     },
+
+    /// An error that occurs when using a [wildcard](Access::is_wildcard) [`Access`]
+    /// to get a single element.
+    MultipleTargets,
 }
 
 impl AccessErrorKind {
@@ -110,7 +114,16 @@ impl fmt::Display for AccessError<'_> {
                         f,
                         "The {type_accessed} accessed doesn't have index `{}`",
                         access.display_value()
-                    )
+                    ),
+                    Access::MapKey(_) => write!(
+                        f,
+                        "The {type_accessed} accessed doesn't have key `{}`",
+                        access.display_value()
+                    ),
+                    Access::ListWildcard | Access::FieldWildcard => write!(
+                        f,
+                        "The {type_accessed} accessed doesn't have any element"
+                    ),
                 }
             }
             AccessErrorKind::IncompatibleTypes { expected, actual } => write!(
@@ -123,6 +136,11 @@ impl fmt::Display for AccessError<'_> {
                 "Expected variant {} access to access a {expected:?} variant, found a {actual:?} variant instead.",
                 access.kind()
             ),
+            AccessErrorKind::MultipleTargets => write!(
+                f,
+                "The {} access may target several elements, use `ParsedPath::reflect_elements` to access all of them.",
+                access.kind()
+            ),
         }
     }
 }
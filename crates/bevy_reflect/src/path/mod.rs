@@ -9,8 +9,8 @@ pub use parse::ParseError;
 use parse::PathParser;
 
 use crate::{PartialReflect, Reflect};
-use alloc::vec::Vec;
-use core::fmt;
+use alloc::{vec, vec::Vec};
+use core::{fmt, marker::PhantomData, ptr::NonNull};
 use derive_more::derive::From;
 use thiserror::Error;
 
@@ -175,6 +175,19 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// assert_eq!(my_list.path::<u32>("[2]").unwrap(), &3);
 /// ```
 ///
+/// ## Maps
+///
+/// [`Map`] elements with string keys are accessed with a quoted key in brackets: `["key"]`.
+/// Within the quotes, a backslash (`\`) escapes the following character, such as a quote.
+///
+/// ### Example
+/// ```
+/// # use bevy_reflect::{GetPath};
+/// # use std::collections::HashMap;
+/// let my_map = HashMap::from([("bevy".to_string(), 123_u32)]);
+/// assert_eq!(my_map.path::<u32>(r#"["bevy"]"#).unwrap(), &123);
+/// ```
+///
 /// ## Enums
 ///
 /// Pathing for [`Enum`] elements works a bit differently than in normal Rust.
@@ -215,6 +228,17 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// assert!(matches!(tuple_variant.path::<u32>(".value"), Err(_)));
 /// ```
 ///
+/// ## Wildcards
+///
+/// Paths may also target several elements at once:
+/// - Every field of a struct, tuple struct, tuple or enum variant: `.*`
+/// - Every element of a list or an array, or every value of a map: `[*]`
+/// - Every value of a map whose key matches a pattern, where `*` matches any sequence of
+///   characters: `["arm_*"]`. Use `\*` to match a `*` character.
+///
+/// Such paths can't be used with the methods of this trait, which return a single element.
+/// Use [`ParsedPath::reflect_elements`] and [`ParsedPath::reflect_elements_mut`] instead.
+///
 /// # Chaining
 ///
 /// Using the aforementioned syntax, path items may be chained one after another
@@ -242,6 +266,7 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
+/// [`Map`]: crate::Map
 /// [`Enum`]: crate::Enum
 #[diagnostic::on_unimplemented(
     message = "`{Self}` does not implement `GetPath` so cannot be accessed by reflection path",
@@ -380,6 +405,8 @@ impl ParsedPath {
     /// - Unnamed field access (`.1`)
     /// - Field index access (`#0`)
     /// - Sequence access (`[2]`)
+    /// - Map key access (`["key"]`)
+    /// - Wildcards (`.*`, `[*]` and `["key_*"]`), see [`reflect_elements`](Self::reflect_elements)
     ///
     /// # Example
     /// ```
@@ -436,6 +463,155 @@ impl ParsedPath {
         }
         Ok(Self(parts))
     }
+
+    /// Returns `true` if this path contains a [wildcard](Access::is_wildcard),
+    /// and may therefore target several elements.
+    pub fn is_wildcard(&self) -> bool {
+        self.0
+            .iter()
+            .any(|offset_access| offset_access.access.is_wildcard())
+    }
+
+    /// Returns an iterator over every element targeted by this path.
+    ///
+    /// Unlike [`ReflectPath::reflect_element`], this supports [wildcards](Access::is_wildcard).
+    /// A path without wildcards targets a single element.
+    ///
+    /// Returns an error if any of the accesses fails on any of the elements it is applied to,
+    /// for example if one of the elements of a list doesn't have the field that follows `[*]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{ParsedPath, Reflect};
+    /// # use std::collections::BTreeMap;
+    /// #[derive(Reflect)]
+    /// struct Item {
+    ///   durability: u32,
+    /// }
+    ///
+    /// #[derive(Reflect)]
+    /// struct Inventory {
+    ///   items: Vec<Item>,
+    ///   counts: BTreeMap<String, u32>,
+    /// }
+    ///
+    /// let inventory = Inventory {
+    ///   items: vec![Item { durability: 3 }, Item { durability: 7 }],
+    ///   counts: BTreeMap::from([
+    ///     ("arrow_iron".to_string(), 10),
+    ///     ("arrow_wood".to_string(), 20),
+    ///     ("bow".to_string(), 1),
+    ///   ]),
+    /// };
+    ///
+    /// let path = ParsedPath::parse("items[*].durability").unwrap();
+    /// let durabilities = path
+    ///   .reflect_elements(&inventory)
+    ///   .unwrap()
+    ///   .map(|element| *element.try_downcast_ref::<u32>().unwrap())
+    ///   .collect::<Vec<_>>();
+    /// assert_eq!(durabilities, [3, 7]);
+    ///
+    /// let path = ParsedPath::parse(r#"counts["arrow_*"]"#).unwrap();
+    /// assert_eq!(path.reflect_elements(&inventory).unwrap().len(), 2);
+    /// ```
+    pub fn reflect_elements<'r>(
+        &self,
+        root: &'r dyn PartialReflect,
+    ) -> PathResult<'static, ReflectElements<'r>> {
+        let targets = self.resolve(root)?;
+        Ok(ReflectElements {
+            elements: targets
+                .into_iter()
+                .map(|(_, element)| element)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        })
+    }
+
+    /// Returns an iterator over mutable references to every element targeted by this path.
+    ///
+    /// See [`reflect_elements`](Self::reflect_elements) for more details.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{ParsedPath, Reflect};
+    /// #[derive(Reflect)]
+    /// struct Bone {
+    ///   scale: f32,
+    ///   length: f32,
+    /// }
+    ///
+    /// let mut bones = vec![
+    ///   Bone { scale: 1.0, length: 2.0 },
+    ///   Bone { scale: 1.0, length: 3.0 },
+    /// ];
+    ///
+    /// let path = ParsedPath::parse("[*].*").unwrap();
+    /// let mut elements = path.reflect_elements_mut(&mut bones).unwrap();
+    /// while let Some(element) = elements.next() {
+    ///   *element.try_downcast_mut::<f32>().unwrap() *= 2.0;
+    /// }
+    ///
+    /// assert_eq!(bones[1].scale, 2.0);
+    /// assert_eq!(bones[1].length, 6.0);
+    /// ```
+    pub fn reflect_elements_mut<'r>(
+        &self,
+        root: &'r mut dyn PartialReflect,
+    ) -> PathResult<'static, ReflectElementsMut<'r, '_>> {
+        let targets = self.resolve(root)?;
+        Ok(ReflectElementsMut {
+            targets: targets
+                .into_iter()
+                .map(|(positions, _)| positions)
+                .collect::<Vec<_>>()
+                .into_iter(),
+            elements: vec![NonNull::from(root)],
+            positions: Vec::new(),
+            path: self,
+            _root: PhantomData,
+        })
+    }
+
+    /// Returns statically typed references to every element targeted by this path.
+    ///
+    /// Returns [`ReflectPathError::InvalidDowncast`] if any of the elements is not of type `T`.
+    ///
+    /// See [`reflect_elements`](Self::reflect_elements) for more details.
+    pub fn elements<'r, T: Reflect>(
+        &self,
+        root: &'r dyn PartialReflect,
+    ) -> PathResult<'static, Vec<&'r T>> {
+        self.reflect_elements(root)?
+            .map(|element| {
+                element
+                    .try_downcast_ref::<T>()
+                    .ok_or(ReflectPathError::InvalidDowncast)
+            })
+            .collect()
+    }
+
+    /// Returns every element targeted by this path, along with the positions of the elements
+    /// each of the accesses of the path targeted to reach it.
+    fn resolve<'r>(
+        &self,
+        root: &'r dyn PartialReflect,
+    ) -> PathResult<'static, Vec<(Vec<usize>, &'r dyn PartialReflect)>> {
+        let mut targets = vec![(Vec::new(), root)];
+        for OffsetAccess { access, offset } in &self.0 {
+            let mut next_targets = Vec::with_capacity(targets.len());
+            for (positions, element) in targets {
+                for (position, element) in access.elements(element, *offset)? {
+                    let mut positions = positions.clone();
+                    positions.push(position);
+                    next_targets.push((positions, element));
+                }
+            }
+            targets = next_targets;
+        }
+        Ok(targets)
+    }
 }
 impl<'a> ReflectPath<'a> for &'a ParsedPath {
     fn reflect_element(self, mut root: &dyn PartialReflect) -> PathResult<'a, &dyn PartialReflect> {
@@ -454,6 +630,102 @@ impl<'a> ReflectPath<'a> for &'a ParsedPath {
         Ok(root)
     }
 }
+/// An iterator over the elements targeted by a [`ParsedPath`].
+///
+/// This is created by [`ParsedPath::reflect_elements`].
+pub struct ReflectElements<'r> {
+    elements: vec::IntoIter<&'r dyn PartialReflect>,
+}
+
+impl<'r> Iterator for ReflectElements<'r> {
+    type Item = &'r dyn PartialReflect;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ReflectElements<'_> {}
+
+/// A lending iterator over mutable references to the elements targeted by a [`ParsedPath`].
+///
+/// As each reference borrows the iterator, this can't implement [`Iterator`].
+/// Use [`next`](Self::next) in a `while let` loop or [`for_each`](Self::for_each) instead.
+///
+/// This is created by [`ParsedPath::reflect_elements_mut`].
+pub struct ReflectElementsMut<'r, 'p> {
+    path: &'p ParsedPath,
+    targets: vec::IntoIter<Vec<usize>>,
+    /// The root, followed by the elements reached by each access on the way to the last target.
+    elements: Vec<NonNull<dyn PartialReflect>>,
+    /// The positions of the elements after the root in `elements`.
+    positions: Vec<usize>,
+    _root: PhantomData<&'r mut dyn PartialReflect>,
+}
+
+impl ReflectElementsMut<'_, '_> {
+    /// Returns a mutable reference to the next targeted element.
+    #[expect(
+        clippy::should_implement_trait,
+        reason = "The returned reference borrows the iterator, so `Iterator` can't be implemented."
+    )]
+    #[expect(
+        unsafe_code,
+        reason = "Keeps the elements on the way to the previous target to avoid walking from the root."
+    )]
+    pub fn next(&mut self) -> Option<&mut dyn PartialReflect> {
+        let positions = self.targets.next()?;
+
+        // Only walk the accesses after the ones shared with the previous target.
+        let shared = self
+            .positions
+            .iter()
+            .zip(&positions)
+            .take_while(|(previous, position)| previous == position)
+            .count();
+        self.elements.truncate(shared + 1);
+        self.positions.truncate(shared);
+
+        // The targets are disjoint, so mutating one of them can't make the others unreachable.
+        for (OffsetAccess { access, .. }, &position) in
+            self.path.0.iter().zip(&positions).skip(shared)
+        {
+            // SAFETY: The last element was derived from the root (or is the root), which is
+            // borrowed mutably for `'r`. The elements derived from it for the previous target
+            // were truncated, and the reference returned for it borrowed `self`, so it is dead.
+            let parent = unsafe { self.elements.last_mut()?.as_mut() };
+            let element = access.element_at_mut(parent, position)?;
+            self.elements.push(NonNull::from(element));
+            self.positions.push(position);
+        }
+
+        // SAFETY: See above. The returned reference borrows `self`, so no other element
+        // can be derived while it is alive.
+        Some(unsafe { self.elements.last_mut()?.as_mut() })
+    }
+
+    /// Calls `f` with a mutable reference to each of the remaining targeted elements.
+    pub fn for_each(mut self, mut f: impl FnMut(&mut dyn PartialReflect)) {
+        while let Some(element) = self.next() {
+            f(element);
+        }
+    }
+
+    /// Returns the number of remaining targeted elements.
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns `true` if there are no remaining targeted elements.
+    pub fn is_empty(&self) -> bool {
+        self.targets.len() == 0
+    }
+}
+
 impl<const N: usize> From<[OffsetAccess; N]> for ParsedPath {
     fn from(value: [OffsetAccess; N]) -> Self {
         ParsedPath(value.to_vec())
@@ -513,7 +785,12 @@ impl core::ops::IndexMut<usize> for ParsedPath {
 mod tests {
     use super::*;
     use crate::*;
-    use alloc::vec;
+    use alloc::{
+        borrow::Cow,
+        collections::BTreeMap,
+        string::{String, ToString},
+        vec,
+    };
 
     #[derive(Reflect, PartialEq, Debug)]
     struct A {
@@ -825,4 +1102,127 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_wildcards_and_keys() {
+        assert_eq!(
+            ParsedPath::parse("y[*].*").unwrap().0,
+            &[
+                offset(access_field("y"), 1),
+                offset(Access::ListWildcard, 2),
+                offset(Access::FieldWildcard, 5),
+            ]
+        );
+        assert_eq!(
+            ParsedPath::parse(r#"bones["arm.*"].scale"#).unwrap().0,
+            &[
+                offset(access_field("bones"), 5),
+                offset(Access::MapKey("arm.*".into()), 6),
+                offset(access_field("scale"), 15),
+            ]
+        );
+        let path = ParsedPath::parse(r#"["a\"]*"]"#).unwrap();
+        assert_eq!(path.0, &[offset(Access::MapKey(r#"a\"]*"#.into()), 1)]);
+        assert_eq!(path.to_string(), r#"["a\"]*"]"#);
+        assert!(path.is_wildcard());
+        assert!(!ParsedPath::parse(r#"["a\*"]"#).unwrap().is_wildcard());
+        assert!(ParsedPath::parse(r#"["a*]"#).is_err());
+    }
+
+    #[test]
+    fn reflect_elements() {
+        let a = a_sample();
+
+        let path = ParsedPath::parse("y[*].mосква").unwrap();
+        assert_eq!(path.elements::<f32>(&a).unwrap(), [&1.0, &2.0]);
+        let path = ParsedPath::parse("array[*]").unwrap();
+        assert_eq!(path.elements::<i32>(&a).unwrap(), [&86, &75, &309]);
+        let path = ParsedPath::parse("tuple_variant.*").unwrap();
+        assert_eq!(path.elements::<u32>(&a).unwrap(), [&123, &321]);
+        let path = ParsedPath::parse("unit_variant.*").unwrap();
+        assert_eq!(path.reflect_elements(&a).unwrap().len(), 0);
+        let path = ParsedPath::parse("x.foo").unwrap();
+        assert_eq!(path.elements::<usize>(&a).unwrap(), [&10]);
+
+        assert_eq!(
+            ParsedPath::parse("tuple.*").unwrap().elements::<bool>(&a),
+            Err(ReflectPathError::InvalidDowncast)
+        );
+        assert_eq!(
+            ParsedPath::parse("x[*]")
+                .unwrap()
+                .reflect_elements(&a)
+                .err()
+                .unwrap(),
+            invalid_access(2, ReflectKind::Struct, ReflectKind::List, "x[*]")
+        );
+        assert_eq!(
+            a.reflect_path("y[*]").err().unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::MultipleTargets,
+                access: Access::ListWildcard,
+                offset: Some(2),
+            })
+        );
+    }
+
+    #[test]
+    fn reflect_elements_mut_with_keys() {
+        #[derive(Reflect)]
+        struct Skeleton {
+            bones: BTreeMap<String, C>,
+        }
+
+        let mut skeleton = Skeleton {
+            bones: BTreeMap::from([
+                ("arm_left".to_string(), C { mосква: 1.0 }),
+                ("arm_right".to_string(), C { mосква: 2.0 }),
+                ("leg*".to_string(), C { mосква: 3.0 }),
+            ]),
+        };
+
+        let path = ParsedPath::parse(r#"bones["arm_*"].mосква"#).unwrap();
+        let elements = path.reflect_elements_mut(&mut skeleton).unwrap();
+        assert_eq!(elements.len(), 2);
+        elements.for_each(|element| *element.try_downcast_mut::<f32>().unwrap() *= 10.0);
+        assert_eq!(skeleton.bones["arm_left"].mосква, 10.0);
+        assert_eq!(skeleton.bones["arm_right"].mосква, 20.0);
+
+        assert_eq!(
+            skeleton.path::<f32>(r#"bones["leg\*"].mосква"#).unwrap(),
+            &3.0
+        );
+        *skeleton
+            .path_mut::<f32>(r#"bones["arm_left"].mосква"#)
+            .unwrap() = 0.0;
+        assert_eq!(skeleton.bones["arm_left"].mосква, 0.0);
+        assert_eq!(
+            skeleton.reflect_path(r#"bones["leg"]"#).err().unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::MissingField(ReflectKind::Map),
+                access: Access::MapKey("leg".into()),
+                offset: Some(6),
+            })
+        );
+
+        let path = ParsedPath::parse("bones[*].*").unwrap();
+        let mut elements = path.reflect_elements_mut(&mut skeleton).unwrap();
+        while let Some(element) = elements.next() {
+            *element.try_downcast_mut::<f32>().unwrap() = 5.0;
+        }
+        assert!(skeleton.bones.values().all(|bone| bone.mосква == 5.0));
+    }
+
+    #[test]
+    fn map_keys_of_other_string_types() {
+        let mut cows = BTreeMap::from([(Cow::<'static, str>::Borrowed("a*"), 1)]);
+        assert_eq!(cows.path::<i32>(r#"["a\*"]"#).unwrap(), &1);
+        *cows.path_mut::<i32>(r#"["a\*"]"#).unwrap() = 2;
+        assert_eq!(cows["a*"], 2);
+
+        let mut strs = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_eq!(strs.path::<i32>(r#"["b"]"#).unwrap(), &2);
+        *strs.path_mut::<i32>(r#"["a"]"#).unwrap() = 3;
+        assert_eq!(strs["a"], 3);
+    }
 }
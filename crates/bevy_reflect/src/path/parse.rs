@@ -32,6 +32,9 @@ enum Error<'a> {
 
     #[error("a ']' was found before an opening '['")]
     CloseBeforeOpen,
+
+    #[error("a '\"' wasn't closed, reached end of path string before finding a '\"'")]
+    UnclosedKey,
 }
 
 pub(super) struct PathParser<'a> {
@@ -89,7 +92,11 @@ impl<'a> PathParser<'a> {
             Token::Ident(ident) => Ok(ident.field()),
             Token::CloseBracket => Err(Error::CloseBeforeOpen),
             Token::OpenBracket => {
-                let index_ident = self.next_ident()?.list_index()?;
+                let index_ident = if self.remaining.first() == Some(&b'"') {
+                    self.next_key()?
+                } else {
+                    self.next_ident()?.list_index()?
+                };
                 match self.next_token() {
                     Some(Token::CloseBracket) => Ok(index_ident),
                     Some(other) => Err(Error::BadClose(other)),
@@ -99,6 +106,30 @@ impl<'a> PathParser<'a> {
         }
     }
 
+    /// Parses a quoted map key, where `\` escapes the following character.
+    fn next_key(&mut self) -> Result<Access<'a>, Error<'a>> {
+        let start = self.offset() + 1;
+        let mut escaped = false;
+        // NOTE: `"` and `\` are ASCII, so they can't be part of a multi-byte character.
+        let len = self.remaining[1..]
+            .iter()
+            .position(|byte| match byte {
+                _ if escaped => {
+                    escaped = false;
+                    false
+                }
+                b'\\' => {
+                    escaped = true;
+                    false
+                }
+                byte => *byte == b'"',
+            })
+            .ok_or(Error::UnclosedKey)?;
+
+        self.remaining = &self.remaining[len + 2..];
+        Ok(Access::MapKey(self.path[start..start + len].into()))
+    }
+
     fn offset(&self) -> usize {
         self.path.len() - self.remaining.len()
     }
@@ -126,6 +157,9 @@ struct Ident<'a>(&'a str);
 
 impl<'a> Ident<'a> {
     fn field(self) -> Access<'a> {
+        if self.0 == "*" {
+            return Access::FieldWildcard;
+        }
         let field = |_| Access::Field(self.0.into());
         self.0.parse().map(Access::TupleIndex).unwrap_or_else(field)
     }
@@ -133,6 +167,9 @@ impl<'a> Ident<'a> {
         Ok(Access::FieldIndex(self.0.parse()?))
     }
     fn list_index(self) -> Result<Access<'a>, Error<'a>> {
+        if self.0 == "*" {
+            return Ok(Access::ListWildcard);
+        }
         Ok(Access::ListIndex(self.0.parse()?))
     }
 }
@@ -0,0 +1,330 @@
+use crate::{
+    serde::{
+        de::error_utils::make_custom_error, ReflectDeserializerProcessor, StableTypeId,
+        TypedReflectDeserializer,
+    },
+    PartialReflect, TypeRegistration, TypeRegistry,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
+
+/// A deserializer for reflected types serialized by a [`CompactReflectSerializer`].
+///
+/// # Input
+///
+/// This deserializer expects a tuple of two elements,
+/// where the first is the [`StableTypeId`] of the reflected type
+/// and the second is the serialized data, with structs serialized as tuples.
+///
+/// The type is looked up with [`TypeRegistry::get_with_stable_id`], so it must be registered,
+/// and must not share its stable identifier with another registered type.
+///
+/// # Output
+///
+/// Like [`ReflectDeserializer`], this deserializer will return a [`Box<dyn PartialReflect>`]
+/// containing the concrete type for opaque types and types registering [`ReflectDeserialize`],
+/// and the dynamic equivalent of the type otherwise.
+///
+/// If you want to override deserialization for a specific [`TypeRegistration`],
+/// you can pass in a reference to a [`ReflectDeserializerProcessor`] which will
+/// take priority over all other deserialization methods - see [`with_processor`].
+///
+/// [`CompactReflectSerializer`]: crate::serde::CompactReflectSerializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+/// [`ReflectDeserialize`]: crate::ReflectDeserialize
+/// [`TypeRegistration`]: crate::TypeRegistration
+/// [`with_processor`]: Self::with_processor
+pub struct CompactReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'a> CompactReflectDeserializer<'a, ()> {
+    /// Creates a deserializer with no processor.
+    ///
+    /// If you want to add custom logic for deserializing certain types, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> CompactReflectDeserializer<'a, P> {
+    /// Creates a deserializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain types, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
+        Self {
+            registry,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for CompactReflectDeserializer<'_, P>
+{
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CompactReflectDeserializerVisitor<'a, P> {
+            registry: &'a TypeRegistry,
+            processor: Option<&'a mut P>,
+        }
+
+        impl<'de, P: ReflectDeserializerProcessor> Visitor<'de>
+            for CompactReflectDeserializerVisitor<'_, P>
+        {
+            type Value = Box<dyn PartialReflect>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str(
+                    "tuple containing the stable type id and the value of the reflected value",
+                )
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let stable_id = seq
+                    .next_element::<StableTypeId>()?
+                    .ok_or_else(|| Error::invalid_length(0, &"two elements"))?;
+                let registration =
+                    self.registry.get_with_stable_id(stable_id).ok_or_else(|| {
+                        make_custom_error(format_args!("no registration found for `{stable_id:?}`"))
+                    })?;
+
+                seq.next_element_seed(TypedReflectDeserializer::new_internal(
+                    registration,
+                    self.registry,
+                    self.processor,
+                ))?
+                .ok_or_else(|| Error::invalid_length(1, &"two elements"))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            2,
+            CompactReflectDeserializerVisitor {
+                registry: self.registry,
+                processor: self.processor,
+            },
+        )
+    }
+}
+
+/// A deserializer for sequences of reflected values serialized by a
+/// [`CompactReflectSeqSerializer`].
+///
+/// # Input
+///
+/// This deserializer expects a tuple of two elements,
+/// where the first is a table of [`StableTypeId`]s
+/// and the second is a sequence of tuples of the `u32` index of the type of a value in the table
+/// and its serialized data, with structs serialized as tuples.
+///
+/// Every type of the table is looked up with [`TypeRegistry::get_with_stable_id`] before any value
+/// is deserialized, so they must all be registered.
+///
+/// # Output
+///
+/// The values are returned in order, like the ones returned by [`CompactReflectDeserializer`].
+///
+/// [`CompactReflectSeqSerializer`]: crate::serde::CompactReflectSeqSerializer
+pub struct CompactReflectSeqDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'a> CompactReflectSeqDeserializer<'a, ()> {
+    /// Creates a deserializer with no processor.
+    ///
+    /// If you want to add custom logic for deserializing certain types, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> CompactReflectSeqDeserializer<'a, P> {
+    /// Creates a deserializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain types, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
+        Self {
+            registry,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for CompactReflectSeqDeserializer<'_, P>
+{
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CompactReflectSeqDeserializerVisitor<'a, P> {
+            registry: &'a TypeRegistry,
+            processor: Option<&'a mut P>,
+        }
+
+        impl<'de, P: ReflectDeserializerProcessor> Visitor<'de>
+            for CompactReflectSeqDeserializerVisitor<'_, P>
+        {
+            type Value = Vec<Box<dyn PartialReflect>>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter
+                    .write_str("tuple containing the stable type id table and the reflected values")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let table = seq
+                    .next_element::<Vec<StableTypeId>>()?
+                    .ok_or_else(|| Error::invalid_length(0, &"two elements"))?;
+                let registrations = table
+                    .into_iter()
+                    .map(|stable_id| {
+                        self.registry.get_with_stable_id(stable_id).ok_or_else(|| {
+                            make_custom_error(format_args!(
+                                "no registration found for `{stable_id:?}`"
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                seq.next_element_seed(CompactSeqValuesVisitor {
+                    registrations: &registrations,
+                    registry: self.registry,
+                    processor: self.processor,
+                })?
+                .ok_or_else(|| Error::invalid_length(1, &"two elements"))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            2,
+            CompactReflectSeqDeserializerVisitor {
+                registry: self.registry,
+                processor: self.processor,
+            },
+        )
+    }
+}
+
+/// A [`Visitor`] for the values of a [`CompactReflectSeqDeserializer`], resolving the type of each
+/// value through the table of registrations read from the header.
+struct CompactSeqValuesVisitor<'a, P> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for CompactSeqValuesVisitor<'_, P> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for CompactSeqValuesVisitor<'_, P> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of type indices and reflected values")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(CompactSeqValueVisitor {
+            registrations: self.registrations,
+            registry: self.registry,
+            processor: self.processor.as_deref_mut(),
+        })? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// A [`Visitor`] for a single value of a [`CompactReflectSeqDeserializer`] and the index of its
+/// type.
+struct CompactSeqValueVisitor<'a, P> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for CompactSeqValueVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for CompactSeqValueVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple containing a type index and the reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let index = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &"two elements"))?;
+        let registration = self
+            .registrations
+            .get(index as usize)
+            .ok_or_else(|| make_custom_error(format_args!("no type at index `{index}`")))?;
+
+        seq.next_element_seed(TypedReflectDeserializer::new_internal(
+            registration,
+            self.registry,
+            self.processor,
+        ))?
+        .ok_or_else(|| Error::invalid_length(1, &"two elements"))
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::de::error_utils::TYPE_INFO_STACK;
-use crate::serde::{ReflectDeserializeWithRegistry, SerializationData};
+use crate::serde::{ReflectDeserializeWithRegistry, SerializationData, TypeMigrations};
 use crate::{
     serde::{
         de::{
@@ -391,9 +391,13 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
 
             match self.registration.type_info() {
                 TypeInfo::Struct(struct_info) => {
+                    let field_names = match self.registration.data::<TypeMigrations>() {
+                        Some(migrations) => migrations.field_names(struct_info),
+                        None => struct_info.field_names(),
+                    };
                     let mut dynamic_struct = deserializer.deserialize_struct(
                         struct_info.type_path_table().ident().unwrap(),
-                        struct_info.field_names(),
+                        field_names,
                         StructVisitor {
                             struct_info,
                             registration: self.registration,
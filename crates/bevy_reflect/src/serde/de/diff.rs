@@ -0,0 +1,397 @@
+use crate::{
+    serde::ReflectDeserializer, FieldId, ListDiff, MapDiff, ReflectDiff, SetDiff, TypeRegistry,
+};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{fmt, fmt::Formatter, marker::PhantomData};
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, Error, SeqAccess, VariantAccess, Visitor,
+};
+
+/// A deserializer for a [`ReflectDiff`] serialized with [`ReflectDiffSerializer`].
+///
+/// The values contained in the diff are deserialized like with [`ReflectDeserializer`], so their
+/// types must be registered in the [`TypeRegistry`].
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    /// Creates a deserializer for diffs whose values are registered in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+const DIFF_VARIANTS: &[&str] = &[
+    "Unchanged",
+    "Replaced",
+    "Variant",
+    "Fields",
+    "List",
+    "Map",
+    "Set",
+];
+const FIELD_ID_VARIANTS: &[&str] = &["Named", "Unnamed"];
+const LIST_DIFF_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+const MAP_DIFF_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+const SET_DIFF_VARIANTS: &[&str] = &["Insert", "Remove"];
+
+impl<'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'_> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(
+            "ReflectDiff",
+            DIFF_VARIANTS,
+            EnumVisitor::<ReflectDiff>::new(self.registry),
+        )
+    }
+}
+
+/// An enum that is part of a [`ReflectDiff`], deserialized by an [`EnumVisitor`].
+trait DiffEnum: Sized {
+    const NAME: &'static str;
+    const VARIANTS: &'static [&'static str];
+
+    /// Deserializes the content of the variant at `index` in [`Self::VARIANTS`].
+    fn visit_variant<'de, V>(
+        index: usize,
+        variant: V,
+        registry: &TypeRegistry,
+    ) -> Result<Self, V::Error>
+    where
+        V: VariantAccess<'de>;
+}
+
+struct EnumVisitor<'a, T> {
+    registry: &'a TypeRegistry,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> EnumVisitor<'a, T> {
+    fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: DiffEnum> Visitor<'de> for EnumVisitor<'_, T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "enum {}", T::NAME)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, variant) = data.variant_seed(VariantIdentifier(T::VARIANTS))?;
+        T::visit_variant(index, variant, self.registry)
+    }
+}
+
+/// Deserializes a variant identifier, either by name or by index, to its index in the list of
+/// variants.
+struct VariantIdentifier(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantIdentifier {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIdentifier {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "one of the variants {:?}", self.0)
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        usize::try_from(index)
+            .ok()
+            .filter(|&index| index < self.0.len())
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .iter()
+            .position(|&variant| variant == name)
+            .ok_or_else(|| E::unknown_variant(name, self.0))
+    }
+}
+
+impl DiffEnum for ReflectDiff {
+    const NAME: &'static str = "ReflectDiff";
+    const VARIANTS: &'static [&'static str] = DIFF_VARIANTS;
+
+    fn visit_variant<'de, V>(
+        index: usize,
+        variant: V,
+        registry: &TypeRegistry,
+    ) -> Result<Self, V::Error>
+    where
+        V: VariantAccess<'de>,
+    {
+        Ok(match index {
+            0 => {
+                variant.unit_variant()?;
+                ReflectDiff::Unchanged
+            }
+            1 => ReflectDiff::Replaced(
+                variant.newtype_variant_seed(ReflectDeserializer::new(registry))?,
+            ),
+            2 => ReflectDiff::Variant(
+                variant.newtype_variant_seed(ReflectDeserializer::new(registry))?,
+            ),
+            3 => ReflectDiff::Fields(variant.newtype_variant_seed(ChangesSeed::new(registry))?),
+            4 => ReflectDiff::List(variant.newtype_variant_seed(ChangesSeed::new(registry))?),
+            5 => ReflectDiff::Map(variant.newtype_variant_seed(ChangesSeed::new(registry))?),
+            _ => ReflectDiff::Set(variant.newtype_variant_seed(ChangesSeed::new(registry))?),
+        })
+    }
+}
+
+impl DiffEnum for FieldId {
+    const NAME: &'static str = "FieldId";
+    const VARIANTS: &'static [&'static str] = FIELD_ID_VARIANTS;
+
+    fn visit_variant<'de, V>(index: usize, variant: V, _: &TypeRegistry) -> Result<Self, V::Error>
+    where
+        V: VariantAccess<'de>,
+    {
+        Ok(match index {
+            0 => FieldId::Named(Cow::Owned(variant.newtype_variant::<String>()?)),
+            _ => FieldId::Unnamed(variant.newtype_variant()?),
+        })
+    }
+}
+
+impl DiffEnum for ListDiff {
+    const NAME: &'static str = "ListDiff";
+    const VARIANTS: &'static [&'static str] = LIST_DIFF_VARIANTS;
+
+    fn visit_variant<'de, V>(
+        index: usize,
+        variant: V,
+        registry: &TypeRegistry,
+    ) -> Result<Self, V::Error>
+    where
+        V: VariantAccess<'de>,
+    {
+        Ok(match index {
+            0 => {
+                let (index, value) = variant.tuple_variant(
+                    2,
+                    PairVisitor(PhantomData::<usize>, ReflectDeserializer::new(registry)),
+                )?;
+                ListDiff::Insert(index, value)
+            }
+            1 => ListDiff::Remove(variant.newtype_variant()?),
+            _ => {
+                let (index, diff) = variant.tuple_variant(
+                    2,
+                    PairVisitor(PhantomData::<usize>, ReflectDiffDeserializer::new(registry)),
+                )?;
+                ListDiff::Modify(index, diff)
+            }
+        })
+    }
+}
+
+impl DiffEnum for MapDiff {
+    const NAME: &'static str = "MapDiff";
+    const VARIANTS: &'static [&'static str] = MAP_DIFF_VARIANTS;
+
+    fn visit_variant<'de, V>(
+        index: usize,
+        variant: V,
+        registry: &TypeRegistry,
+    ) -> Result<Self, V::Error>
+    where
+        V: VariantAccess<'de>,
+    {
+        Ok(match index {
+            0 => {
+                let (key, value) = variant.tuple_variant(
+                    2,
+                    PairVisitor(
+                        ReflectDeserializer::new(registry),
+                        ReflectDeserializer::new(registry),
+                    ),
+                )?;
+                MapDiff::Insert(key, value)
+            }
+            1 => MapDiff::Remove(variant.newtype_variant_seed(ReflectDeserializer::new(registry))?),
+            _ => {
+                let (key, diff) = variant.tuple_variant(
+                    2,
+                    PairVisitor(
+                        ReflectDeserializer::new(registry),
+                        ReflectDiffDeserializer::new(registry),
+                    ),
+                )?;
+                MapDiff::Modify(key, diff)
+            }
+        })
+    }
+}
+
+impl DiffEnum for SetDiff {
+    const NAME: &'static str = "SetDiff";
+    const VARIANTS: &'static [&'static str] = SET_DIFF_VARIANTS;
+
+    fn visit_variant<'de, V>(
+        index: usize,
+        variant: V,
+        registry: &TypeRegistry,
+    ) -> Result<Self, V::Error>
+    where
+        V: VariantAccess<'de>,
+    {
+        let value = variant.newtype_variant_seed(ReflectDeserializer::new(registry))?;
+        Ok(match index {
+            0 => SetDiff::Insert(value),
+            _ => SetDiff::Remove(value),
+        })
+    }
+}
+
+/// Deserializes a single change of a [`ReflectDiff`].
+struct ChangeSeed<'a, T> {
+    registry: &'a TypeRegistry,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: DiffEnum> DeserializeSeed<'de> for ChangeSeed<'_, T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(T::NAME, T::VARIANTS, EnumVisitor::new(self.registry))
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ChangeSeed<'_, (FieldId, ReflectDiff)> {
+    type Value = (FieldId, ReflectDiff);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            2,
+            PairVisitor(
+                ChangeSeed::<FieldId> {
+                    registry: self.registry,
+                    marker: PhantomData,
+                },
+                ReflectDiffDeserializer::new(self.registry),
+            ),
+        )
+    }
+}
+
+/// Deserializes a sequence of changes of a [`ReflectDiff`].
+struct ChangesSeed<'a, T> {
+    registry: &'a TypeRegistry,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> ChangesSeed<'a, T> {
+    fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for ChangesSeed<'_, T>
+where
+    for<'a> ChangeSeed<'a, T>: DeserializeSeed<'de, Value = T>,
+{
+    type Value = Vec<T>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T> Visitor<'de> for ChangesSeed<'_, T>
+where
+    for<'a> ChangeSeed<'a, T>: DeserializeSeed<'de, Value = T>,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of changes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut changes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(change) = seq.next_element_seed(ChangeSeed {
+            registry: self.registry,
+            marker: PhantomData,
+        })? {
+            changes.push(change);
+        }
+        Ok(changes)
+    }
+}
+
+/// Deserializes a tuple of two elements with the given seeds.
+struct PairVisitor<A, B>(A, B);
+
+impl<'de, A, B> Visitor<'de> for PairVisitor<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a tuple of 2 elements")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"a tuple of 2 elements"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"a tuple of 2 elements"))?;
+        Ok((first, second))
+    }
+}
@@ -168,6 +168,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor
             self.struct_info,
             self.registration,
             self.registry,
+            None,
             self.processor,
         )
     }
@@ -181,6 +182,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor
             self.struct_info,
             self.registration,
             self.registry,
+            None,
             self.processor,
         )
     }
@@ -1,11 +1,15 @@
+pub use compact::*;
 pub use deserialize_with_registry::*;
 pub use deserializer::*;
+pub use diff::*;
 pub use processor::*;
 pub use registrations::*;
 
 mod arrays;
+mod compact;
 mod deserialize_with_registry;
 mod deserializer;
+mod diff;
 mod enums;
 mod error_utils;
 mod helpers;
@@ -21,6 +25,7 @@ mod structs;
 mod tuple_structs;
 mod tuple_utils;
 mod tuples;
+mod untyped;
 
 #[cfg(test)]
 mod tests {
@@ -32,17 +37,19 @@ mod tests {
     };
     use core::{any::TypeId, f32::consts::PI, ops::RangeInclusive};
     use serde::{de::DeserializeSeed, Deserialize};
-    use serde::{de::IgnoredAny, Deserializer};
+    use serde::{de::IgnoredAny, Deserializer, Serializer};
 
     use bevy_platform::collections::{HashMap, HashSet};
 
     use crate::{
         serde::{
-            ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializer,
-            TypedReflectDeserializer,
+            CompactReflectDeserializer, CompactReflectSeqDeserializer, CompactReflectSeqSerializer,
+            CompactReflectSerializer, ReflectDeserializer, ReflectDeserializerProcessor,
+            ReflectSerializer, ReflectSerializerProcessor, TypedReflectDeserializer,
+            TypedReflectSerializer,
         },
-        DynamicEnum, FromReflect, PartialReflect, Reflect, ReflectDeserialize, TypeRegistration,
-        TypeRegistry,
+        DynamicEnum, DynamicStruct, FromReflect, PartialReflect, Reflect, ReflectDeserialize,
+        TypeRegistration, TypeRegistry,
     };
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -762,6 +769,269 @@ mod tests {
         assert!(<Foo as FromReflect>::from_reflect(dynamic_output.as_partial_reflect()).is_none());
     }
 
+    /// Version 0 was `(hp: u32, nickname: String, speed_kmh: f64)`, and version 1 renamed
+    /// `nickname` to `name` and `hp` to `hit_points`.
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(version = 2)]
+    struct Versioned {
+        #[reflect(alias = "hp", alias = "hit_points")]
+        health: u32,
+        name: String,
+        speed: f32,
+    }
+
+    fn get_versioned_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Versioned>();
+        registry.register_migration::<Versioned>(0, |data: &mut DynamicStruct| {
+            let nickname = data.remove("nickname").ok_or("missing `nickname`")?;
+            data.insert_boxed("name", nickname);
+            Ok(())
+        });
+        registry.register_migration::<Versioned>(1, |data: &mut DynamicStruct| {
+            let speed_kmh = data.remove("speed_kmh").ok_or("missing `speed_kmh`")?;
+            let speed_kmh = speed_kmh
+                .try_downcast_ref::<f64>()
+                .ok_or("`speed_kmh` is not a float")?;
+            data.insert("speed", (speed_kmh / 3.6) as f32);
+            Ok(())
+        });
+        registry
+    }
+
+    fn deserialize_versioned(input: &str, registry: &TypeRegistry) -> Result<Versioned, String> {
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = TypedReflectDeserializer::of::<Versioned>(registry)
+            .deserialize(&mut deserializer)
+            .map_err(|error| error.to_string())?;
+        Ok(Versioned::from_reflect(output.as_partial_reflect()).unwrap())
+    }
+
+    #[test]
+    fn should_migrate_outdated_data() {
+        let registry = get_versioned_registry();
+        let expected = Versioned {
+            health: 10,
+            name: "Bevy".to_string(),
+            speed: 10.0,
+        };
+
+        let v0 = r#"(hp: 10, nickname: "Bevy", speed_kmh: 36.0)"#;
+        assert_eq!(expected, deserialize_versioned(v0, &registry).unwrap());
+
+        let v1 = r#"(__version: 1, hit_points: 10, name: "Bevy", speed_kmh: 36.0)"#;
+        assert_eq!(expected, deserialize_versioned(v1, &registry).unwrap());
+
+        let v2 = r#"(__version: 2, health: 10, name: "Bevy", speed: 10.0)"#;
+        assert_eq!(expected, deserialize_versioned(v2, &registry).unwrap());
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_versions_and_fields() {
+        let registry = get_versioned_registry();
+
+        let newer = r#"(__version: 3, health: 10, name: "Bevy", speed: 10.0)"#;
+        let error = deserialize_versioned(newer, &registry).unwrap_err();
+        assert!(error.contains("cannot deserialize version 3"), "{error}");
+
+        let malformed = r#"(__version: 1, hit_points: 10, name: "Bevy", speed_kmh: "fast")"#;
+        let error = deserialize_versioned(malformed, &registry).unwrap_err();
+        assert!(error.contains("`speed_kmh` is not a float"), "{error}");
+
+        let unknown = r#"(__version: 2, health: 10, name: "Bevy", speed_kmh: 36.0)"#;
+        let error = deserialize_versioned(unknown, &registry).unwrap_err();
+        assert!(error.contains("unknown field `speed_kmh`"), "{error}");
+    }
+
+    #[test]
+    fn should_roundtrip_versioned_struct() {
+        let registry = get_versioned_registry();
+        let value = Versioned {
+            health: 10,
+            name: "Bevy".to_string(),
+            speed: 10.0,
+        };
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+
+        let output = ron::to_string(&serializer).unwrap();
+        assert_eq!(r#"(__version:2,health:10,name:"Bevy",speed:10.0)"#, output);
+        assert_eq!(value, deserialize_versioned(&output, &registry).unwrap());
+
+        let config = bincode::config::standard().with_fixed_int_encoding();
+        let output = bincode::serde::encode_to_vec(&serializer, config).unwrap();
+        let (output, _read_bytes) = bincode::serde::seed_decode_from_slice(
+            TypedReflectDeserializer::of::<Versioned>(&registry),
+            &output,
+            config,
+        )
+        .unwrap();
+        assert_eq!(value, Versioned::from_reflect(output.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn should_roundtrip_compact_binary() {
+        let expected = get_my_struct();
+        let mut registry = get_registry();
+        registry.register::<Versioned>();
+
+        let serializer = CompactReflectSerializer::new(&expected, &registry);
+        let output = rmp_serde::to_vec(&serializer).unwrap();
+        let full_output = rmp_serde::to_vec(&ReflectSerializer::new(&expected, &registry)).unwrap();
+        assert!(output.len() < full_output.len());
+
+        let dynamic_output = CompactReflectDeserializer::new(&registry)
+            .deserialize(&mut rmp_serde::Deserializer::new(output.as_slice()))
+            .unwrap();
+        assert_eq!(
+            expected,
+            MyStruct::from_reflect(dynamic_output.as_ref()).unwrap()
+        );
+
+        let versioned = Versioned {
+            health: 10,
+            name: "Bevy".to_string(),
+            speed: 10.0,
+        };
+        let config = bincode::config::standard().with_fixed_int_encoding();
+        let output = bincode::serde::encode_to_vec(
+            CompactReflectSerializer::new(&versioned, &registry),
+            config,
+        )
+        .unwrap();
+        let (dynamic_output, _read_bytes) = bincode::serde::seed_decode_from_slice(
+            CompactReflectDeserializer::new(&registry),
+            &output,
+            config,
+        )
+        .unwrap();
+        assert_eq!(
+            versioned,
+            Versioned::from_reflect(dynamic_output.as_ref()).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_use_processors_with_compact_format() {
+        struct I64AsStringSerializer;
+
+        impl ReflectSerializerProcessor for I64AsStringSerializer {
+            fn try_serialize<S>(
+                &self,
+                value: &dyn PartialReflect,
+                _: &TypeRegistry,
+                serializer: S,
+            ) -> Result<Result<S::Ok, S>, S::Error>
+            where
+                S: Serializer,
+            {
+                match value.try_downcast_ref::<i64>() {
+                    Some(value) => serializer.serialize_str(&value.to_string()).map(Ok),
+                    None => Ok(Err(serializer)),
+                }
+            }
+        }
+
+        struct I64AsStringDeserializer;
+
+        impl ReflectDeserializerProcessor for I64AsStringDeserializer {
+            fn try_deserialize<'de, D>(
+                &mut self,
+                registration: &TypeRegistration,
+                _: &TypeRegistry,
+                deserializer: D,
+            ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                if registration.type_id() == TypeId::of::<i64>() {
+                    let value = String::deserialize(deserializer)?;
+                    Ok(Ok(Box::new(
+                        value.parse::<i64>().map_err(serde::de::Error::custom)?,
+                    )))
+                } else {
+                    Ok(Err(deserializer))
+                }
+            }
+        }
+
+        let mut registry = get_registry();
+        registry.register::<(SomeStruct, SomeEnum)>();
+        let nested = (
+            SomeStruct { foo: 42 },
+            SomeEnum::Struct {
+                foo: "Hello".to_string(),
+            },
+        );
+
+        let serializer =
+            CompactReflectSerializer::with_processor(&nested, &registry, &I64AsStringSerializer);
+        let output = rmp_serde::to_vec(&serializer).unwrap();
+
+        let mut processor = I64AsStringDeserializer;
+        let dynamic_output = CompactReflectDeserializer::with_processor(&registry, &mut processor)
+            .deserialize(&mut rmp_serde::Deserializer::new(output.as_slice()))
+            .unwrap();
+        assert_eq!(
+            nested,
+            <(SomeStruct, SomeEnum)>::from_reflect(dynamic_output.as_ref()).unwrap()
+        );
+
+        // Without the deserialization processor, the `i64` can't be read from a string.
+        let result = CompactReflectDeserializer::new(&registry)
+            .deserialize(&mut rmp_serde::Deserializer::new(output.as_slice()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_roundtrip_compact_seq_with_type_table() {
+        let registry = get_registry();
+        let structs = [SomeStruct { foo: 1 }, SomeStruct { foo: 2 }];
+        let values: [&dyn PartialReflect; 3] = [&structs[0], &123_u8, &structs[1]];
+
+        let config = bincode::config::standard().with_fixed_int_encoding();
+        let output = bincode::serde::encode_to_vec(
+            CompactReflectSeqSerializer::new(&values, &registry),
+            config,
+        )
+        .unwrap();
+        // The table holds the two distinct stable type ids, each value is prefixed by an index.
+        let table_len = size_of::<u64>() + 2 * size_of::<u64>();
+        let values_len = size_of::<u64>() + 3 * size_of::<u32>() + 2 * size_of::<i64>() + 1;
+        assert_eq!(table_len + values_len, output.len());
+
+        let (dynamic_output, _read_bytes) = bincode::serde::seed_decode_from_slice(
+            CompactReflectSeqDeserializer::new(&registry),
+            &output,
+            config,
+        )
+        .unwrap();
+        assert_eq!(3, dynamic_output.len());
+        assert_eq!(
+            Some(SomeStruct { foo: 1 }),
+            SomeStruct::from_reflect(dynamic_output[0].as_ref())
+        );
+        assert_eq!(Some(123), u8::from_reflect(dynamic_output[1].as_ref()));
+        assert_eq!(
+            Some(SomeStruct { foo: 2 }),
+            SomeStruct::from_reflect(dynamic_output[2].as_ref())
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_stable_type_id() {
+        let registry = get_registry();
+        let value = SomeStruct { foo: 1 };
+        let output = rmp_serde::to_vec(&CompactReflectSerializer::new(&value, &registry)).unwrap();
+
+        let result = CompactReflectDeserializer::new(&TypeRegistry::new())
+            .deserialize(&mut rmp_serde::Deserializer::new(output.as_slice()));
+        let error = result.err().unwrap().to_string();
+        assert!(
+            error.contains("no registration found for `StableTypeId("),
+            "{error}"
+        );
+    }
+
     #[cfg(feature = "functions")]
     mod functions {
         use super::*;
@@ -4,10 +4,12 @@ use crate::{
             error_utils::make_custom_error,
             helpers::{ExpectedValues, Ident},
             registration_utils::try_get_registration,
+            untyped::UntypedDeserializer,
         },
-        SerializationData, TypedReflectDeserializer,
+        SerializationData, TypeMigrations, TypedReflectDeserializer,
     },
-    DynamicStruct, NamedField, StructInfo, StructVariantInfo, TypeRegistration, TypeRegistry,
+    DynamicStruct, NamedField, Struct, StructInfo, StructVariantInfo, TypeRegistration,
+    TypeRegistry,
 };
 use alloc::string::ToString;
 use core::slice::Iter;
@@ -83,14 +85,31 @@ impl StructLikeInfo for StructVariantInfo {
     }
 }
 
+/// Returns an error for a field that isn't part of a [struct-like] type.
+///
+/// [struct-like]: StructLikeInfo
+fn unknown_field_error<T: StructLikeInfo, E: Error>(info: &T, name: &str) -> E {
+    let fields = info.iter_fields().map(NamedField::name);
+    make_custom_error(format_args!(
+        "unknown field `{}`, expected one of {:?}",
+        name,
+        ExpectedValues::from_iter(fields)
+    ))
+}
+
 /// Deserializes a [struct-like] type from a mapping of fields, returning a [`DynamicStruct`].
 ///
+/// Fields can be identified by their name or one of their [aliases]. If the type has
+/// [`TypeMigrations`], the data is migrated from its serialized version.
+///
 /// [struct-like]: StructLikeInfo
+/// [aliases]: NamedField::aliases
 pub(super) fn visit_struct<'de, T, V, P>(
     map: &mut V,
     info: &'static T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    migrations: Option<&TypeMigrations>,
     mut processor: Option<&mut P>,
 ) -> Result<DynamicStruct, V::Error>
 where
@@ -99,22 +118,60 @@ where
     P: ReflectDeserializerProcessor,
 {
     let mut dynamic_struct = DynamicStruct::default();
+    let mut version = 0;
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.field::<V::Error>(&key).map_err(|_| {
-            let fields = info.iter_fields().map(NamedField::name);
-            make_custom_error(format_args!(
-                "unknown field `{}`, expected one of {:?}",
-                key,
-                ExpectedValues::from_iter(fields)
-            ))
-        })?;
+        if migrations.is_some() && key == TypeMigrations::VERSION_FIELD {
+            version = map.next_value()?;
+            continue;
+        }
+
+        let field = info.field::<V::Error>(&key).ok().or_else(|| {
+            info.iter_fields()
+                .find(|field| field.aliases().contains(&key.as_str()))
+        });
+        let Some(field) = field else {
+            if migrations.is_none() {
+                return Err(unknown_field_error(info, &key));
+            }
+            // The field may be used by a migration, which is checked once the version is known.
+            let value = map.next_value_seed(UntypedDeserializer)?;
+            dynamic_struct.insert_boxed(key, value);
+            continue;
+        };
         let registration = try_get_registration(*field.ty(), registry)?;
         let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
             registration,
             registry,
             processor.as_deref_mut(),
         ))?;
-        dynamic_struct.insert_boxed(&key, value);
+        dynamic_struct.insert_boxed(field.name(), value);
+    }
+
+    if let Some(migrations) = migrations {
+        if version > migrations.version() {
+            return Err(make_custom_error(format_args!(
+                "cannot deserialize version {} of `{}`, as the current version is {}",
+                version,
+                registration.type_info().type_path(),
+                migrations.version(),
+            )));
+        }
+        migrations
+            .migrate(version, &mut dynamic_struct)
+            .map_err(|error| {
+                make_custom_error(format_args!(
+                    "cannot migrate version {} of `{}`: {}",
+                    version,
+                    registration.type_info().type_path(),
+                    error,
+                ))
+            })?;
+        for index in 0..dynamic_struct.field_len() {
+            let name = dynamic_struct.name_at(index).unwrap();
+            if info.field::<V::Error>(name).is_err() {
+                return Err(unknown_field_error(info, name));
+            }
+        }
     }
 
     if let Some(serialization_data) = registration.data::<SerializationData>() {
@@ -140,6 +197,7 @@ pub(super) fn visit_struct_seq<'de, T, V, P>(
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    migrations: Option<&TypeMigrations>,
     mut processor: Option<&mut P>,
 ) -> Result<DynamicStruct, V::Error>
 where
@@ -149,6 +207,21 @@ where
 {
     let mut dynamic_struct = DynamicStruct::default();
 
+    if let Some(migrations) = migrations {
+        // The fields of older versions can't be known without their names.
+        let version = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &"the version of the struct"))?;
+        if version != migrations.version() {
+            return Err(make_custom_error(format_args!(
+                "cannot deserialize version {} of `{}` from a sequence, as the current version is {}",
+                version,
+                registration.type_info().type_path(),
+                migrations.version(),
+            )));
+        }
+    }
+
     let len = info.field_len();
 
     if len == 0 {
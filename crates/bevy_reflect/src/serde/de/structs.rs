@@ -1,5 +1,8 @@
 use crate::{
-    serde::de::struct_utils::{visit_struct, visit_struct_seq},
+    serde::{
+        de::struct_utils::{visit_struct, visit_struct_seq},
+        TypeMigrations,
+    },
     DynamicStruct, StructInfo, TypeRegistration, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
@@ -33,6 +36,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P>
             self.struct_info,
             self.registration,
             self.registry,
+            self.registration.data::<TypeMigrations>(),
             self.processor,
         )
     }
@@ -46,6 +50,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P>
             self.struct_info,
             self.registration,
             self.registry,
+            self.registration.data::<TypeMigrations>(),
             self.processor,
         )
     }
@@ -0,0 +1,126 @@
+use crate::{
+    DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple, DynamicVariant, Map,
+    PartialReflect,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Deserializer, Error, MapAccess, SeqAccess, Visitor};
+
+/// Deserializes a value of unknown type from a self-describing format into a dynamic value.
+///
+/// This is used for the fields of outdated data that are no longer part of their type, so they can
+/// be read by migrations. See [`TypeMigrations`] for the types these values are deserialized as.
+///
+/// [`TypeMigrations`]: crate::serde::TypeMigrations
+pub(super) struct UntypedDeserializer;
+
+impl<'de> DeserializeSeed<'de> for UntypedDeserializer {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for UntypedDeserializer {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Box::new(value))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Box::new(value))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Box::new(value))
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Box::new(value))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Box::new(String::from(value)))
+    }
+
+    fn visit_string<E: Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Box::new(value))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(()))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(DynamicEnum::new("None", ())))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_boxed(deserializer.deserialize_any(self)?);
+        Ok(Box::new(DynamicEnum::new(
+            "Some",
+            DynamicVariant::Tuple(tuple),
+        )))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(UntypedDeserializer)? {
+            list.push_box(value);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        while let Some((key, value)) =
+            map.next_entry_seed(UntypedDeserializer, UntypedDeserializer)?
+        {
+            entries.push((key, value));
+        }
+
+        let names = entries
+            .iter()
+            .map(|(key, _)| key.try_downcast_ref::<String>().cloned())
+            .collect::<Option<Vec<_>>>();
+        if let Some(names) = names {
+            let mut dynamic_struct = DynamicStruct::default();
+            for (name, (_, value)) in names.into_iter().zip(entries) {
+                dynamic_struct.insert_boxed(name, value);
+            }
+            Ok(Box::new(dynamic_struct))
+        } else {
+            let mut dynamic_map = DynamicMap::default();
+            for (key, value) in entries {
+                dynamic_map.insert_boxed(key, value);
+            }
+            Ok(Box::new(dynamic_map))
+        }
+    }
+}
@@ -1,9 +1,11 @@
 mod de;
 mod ser;
+mod stable_type_id;
 mod type_data;
 
 pub use de::*;
 pub use ser::*;
+pub use stable_type_id::*;
 pub use type_data::*;
 
 #[cfg(test)]
@@ -0,0 +1,405 @@
+#[cfg(feature = "debug_stack")]
+use crate::serde::ser::error_utils::TYPE_INFO_STACK;
+use crate::{
+    serde::{
+        ser::error_utils::make_custom_error, ReflectSerializeWithRegistry, SerializationData,
+        StableTypeId, TypeMigrations, TypedReflectSerializer,
+    },
+    PartialReflect, ReflectRef, ReflectSerialize, TypeInfo, TypeRegistry, VariantType,
+};
+use alloc::vec::Vec;
+use serde::{
+    ser::{Error, SerializeSeq, SerializeTuple, SerializeTupleVariant},
+    Serialize, Serializer,
+};
+
+use super::ReflectSerializerProcessor;
+
+/// A serializer for reflected types producing a compact output, meant for binary formats.
+///
+/// This is the serializer counterpart to [`CompactReflectDeserializer`].
+///
+/// # Output
+///
+/// This serializer will output a tuple of two elements,
+/// where the first is the [`StableTypeId`] of the reflected type
+/// and the second is the serialized data.
+///
+/// Compared to [`ReflectSerializer`], the type is identified by a 64-bit hash instead of its
+/// [type path], and structs and struct variants are serialized as tuples, without their field
+/// names. This makes the output a lot smaller with self-describing formats like [MessagePack], and
+/// formats like [postcard] and [bincode] never write field names anyway.
+/// As the field names are not written, the fields of structs cannot be reordered, and
+/// [version migrations] cannot be applied, without breaking previously serialized data.
+/// To serialize many values, use [`CompactReflectSeqSerializer`], which only writes the
+/// identifier of each type once.
+///
+/// Types registering [`ReflectSerialize`] or [`ReflectSerializeWithRegistry`] type data are still
+/// serialized with it, and a [`ReflectSerializerProcessor`] can be passed in with
+/// [`with_processor`], taking priority over all other serialization methods.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectDeserializer, CompactReflectSerializer, ReflectSerializer}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct MyStruct {
+///   value: i32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+///
+/// let input = MyStruct { value: 123 };
+///
+/// let serializer = CompactReflectSerializer::new(&input, &registry);
+/// let output = rmp_serde::to_vec(&serializer).unwrap();
+///
+/// let full_output = rmp_serde::to_vec(&ReflectSerializer::new(&input, &registry)).unwrap();
+/// assert!(output.len() < full_output.len());
+///
+/// let mut deserializer = rmp_serde::Deserializer::new(output.as_slice());
+/// let value = CompactReflectDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(MyStruct::from_reflect(value.as_ref()), Some(input));
+/// ```
+///
+/// [`CompactReflectDeserializer`]: crate::serde::CompactReflectDeserializer
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [type path]: crate::TypePath::type_path
+/// [MessagePack]: https://msgpack.org
+/// [postcard]: https://docs.rs/postcard
+/// [bincode]: https://docs.rs/bincode
+/// [version migrations]: TypeMigrations
+/// [`with_processor`]: Self::with_processor
+pub struct CompactReflectSerializer<'a, P = ()> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<'a> CompactReflectSerializer<'a, ()> {
+    /// Creates a serializer with no processor.
+    ///
+    /// If you want to add custom logic for serializing certain values, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(value: &'a dyn PartialReflect, registry: &'a TypeRegistry) -> Self {
+        Self {
+            value,
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> CompactReflectSerializer<'a, P> {
+    /// Creates a serializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain values, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        value: &'a dyn PartialReflect,
+        registry: &'a TypeRegistry,
+        processor: &'a P,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for CompactReflectSerializer<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_info = represented_type_info(self.value)?;
+
+        let processor = CompactProcessor {
+            processor: self.processor,
+        };
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&StableTypeId::of_type_path(type_info.type_path()))?;
+        state.serialize_element(&TypedReflectSerializer::new_internal(
+            self.value,
+            self.registry,
+            Some(&processor),
+        ))?;
+        state.end()
+    }
+}
+
+/// A serializer for a sequence of reflected values, identifying their types through a table of
+/// [`StableTypeId`]s written once in a header.
+///
+/// This is the serializer counterpart to [`CompactReflectSeqDeserializer`].
+///
+/// # Output
+///
+/// This serializer will output a tuple of two elements,
+/// where the first is the table of the [`StableTypeId`]s of the distinct types of the values,
+/// in the order they first appear,
+/// and the second is a sequence containing, for each value, a tuple of the `u32` index of its type
+/// in the table and its serialized data.
+///
+/// The data is serialized like with [`CompactReflectSerializer`], but each type is only identified
+/// once by its 64-bit hash, making this the better choice when serializing many values sharing
+/// a few types, such as the components of a save file or of a network message.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectSeqDeserializer, CompactReflectSeqSerializer}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct Position {
+///   x: f32,
+///   y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Position>();
+///
+/// let first = Position { x: 1.0, y: 2.0 };
+/// let second = Position { x: 3.0, y: 4.0 };
+/// let values: [&dyn PartialReflect; 3] = [&first, &second, &5_u32];
+///
+/// let serializer = CompactReflectSeqSerializer::new(&values, &registry);
+/// let output = rmp_serde::to_vec(&serializer).unwrap();
+///
+/// let mut deserializer = rmp_serde::Deserializer::new(output.as_slice());
+/// let values = CompactReflectSeqDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(values.len(), 3);
+/// assert_eq!(Position::from_reflect(values[1].as_ref()), Some(second));
+/// ```
+///
+/// [`CompactReflectSeqDeserializer`]: crate::serde::CompactReflectSeqDeserializer
+pub struct CompactReflectSeqSerializer<'a, P = ()> {
+    values: &'a [&'a dyn PartialReflect],
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<'a> CompactReflectSeqSerializer<'a, ()> {
+    /// Creates a serializer with no processor.
+    ///
+    /// If you want to add custom logic for serializing certain values, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(values: &'a [&'a dyn PartialReflect], registry: &'a TypeRegistry) -> Self {
+        Self {
+            values,
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> CompactReflectSeqSerializer<'a, P> {
+    /// Creates a serializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain values, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        values: &'a [&'a dyn PartialReflect],
+        registry: &'a TypeRegistry,
+        processor: &'a P,
+    ) -> Self {
+        Self {
+            values,
+            registry,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for CompactReflectSeqSerializer<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut table = Vec::<StableTypeId>::new();
+        let mut indices = Vec::with_capacity(self.values.len());
+        for value in self.values {
+            let stable_id = StableTypeId::of_type_path(represented_type_info(*value)?.type_path());
+            let index = match table.iter().position(|id| *id == stable_id) {
+                Some(index) => index,
+                None => {
+                    table.push(stable_id);
+                    table.len() - 1
+                }
+            };
+            indices.push(u32::try_from(index).map_err(S::Error::custom)?);
+        }
+
+        let processor = CompactProcessor {
+            processor: self.processor,
+        };
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&table)?;
+        state.serialize_element(&CompactSeqValues {
+            values: self.values,
+            indices: &indices,
+            registry: self.registry,
+            processor: &processor,
+        })?;
+        state.end()
+    }
+}
+
+/// The values of a [`CompactReflectSeqSerializer`], each paired with the index of its type.
+struct CompactSeqValues<'a, P> {
+    values: &'a [&'a dyn PartialReflect],
+    indices: &'a [u32],
+    registry: &'a TypeRegistry,
+    processor: &'a CompactProcessor<'a, P>,
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for CompactSeqValues<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for (value, index) in self.values.iter().zip(self.indices) {
+            state.serialize_element(&(
+                index,
+                TypedReflectSerializer::new_internal(*value, self.registry, Some(self.processor)),
+            ))?;
+        }
+        state.end()
+    }
+}
+
+fn represented_type_info<E: Error>(value: &dyn PartialReflect) -> Result<&'static TypeInfo, E> {
+    value.get_represented_type_info().ok_or_else(|| {
+        if value.is_dynamic() {
+            make_custom_error(format_args!(
+                "cannot serialize dynamic value without represented type: `{}`",
+                value.reflect_type_path()
+            ))
+        } else {
+            make_custom_error(format_args!(
+                "cannot get type info for `{}`",
+                value.reflect_type_path()
+            ))
+        }
+    })
+}
+
+/// A processor serializing structs and struct variants as tuples, after giving the user's
+/// processor the chance to serialize each value.
+struct CompactProcessor<'a, P> {
+    processor: Option<&'a P>,
+}
+
+impl<P: ReflectSerializerProcessor> ReflectSerializerProcessor for CompactProcessor<'_, P> {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        let serializer = match self.processor {
+            Some(processor) => match processor.try_serialize(value, registry, serializer)? {
+                Ok(output) => return Ok(Ok(output)),
+                Err(serializer) => serializer,
+            },
+            None => serializer,
+        };
+
+        let Some(type_info) = value.get_represented_type_info() else {
+            return Ok(Err(serializer));
+        };
+        let registration = registry.get(type_info.type_id());
+        if registration.is_some_and(|registration| {
+            registration.contains::<ReflectSerialize>()
+                || registration.contains::<ReflectSerializeWithRegistry>()
+        }) {
+            // Custom serialization takes priority over the compact representation.
+            return Ok(Err(serializer));
+        }
+
+        let output = match (value.reflect_ref(), type_info) {
+            (ReflectRef::Struct(struct_value), TypeInfo::Struct(_)) => {
+                let serialization_data =
+                    registration.and_then(|registration| registration.data::<SerializationData>());
+                let version = registration
+                    .and_then(|registration| registration.data::<TypeMigrations>())
+                    .map(TypeMigrations::version);
+                let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
+
+                let mut state = serializer.serialize_tuple(
+                    struct_value.field_len() - ignored_len + usize::from(version.is_some()),
+                )?;
+                if let Some(version) = version {
+                    state.serialize_element(&version)?;
+                }
+                for (index, field) in struct_value.iter_fields().enumerate() {
+                    if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                        continue;
+                    }
+                    state.serialize_element(&TypedReflectSerializer::new_internal(
+                        field,
+                        registry,
+                        Some(self),
+                    ))?;
+                }
+                state.end()
+            }
+            (ReflectRef::Enum(enum_value), TypeInfo::Enum(enum_info))
+                if enum_value.variant_type() == VariantType::Struct =>
+            {
+                let variant_index = enum_value.variant_index();
+                let variant_info = enum_info.variant_at(variant_index).ok_or_else(|| {
+                    make_custom_error(format_args!(
+                        "variant at index `{variant_index}` does not exist",
+                    ))
+                })?;
+                let mut state = serializer.serialize_tuple_variant(
+                    enum_info.type_path_table().ident().unwrap(),
+                    variant_index as u32,
+                    variant_info.name(),
+                    enum_value.field_len(),
+                )?;
+                for field in enum_value.iter_fields() {
+                    state.serialize_field(&TypedReflectSerializer::new_internal(
+                        field.value(),
+                        registry,
+                        Some(self),
+                    ))?;
+                }
+                state.end()
+            }
+            _ => return Ok(Err(serializer)),
+        };
+
+        // The serializer only pops the type info it pushed when it serializes the value itself.
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+        output.map(Ok)
+    }
+}
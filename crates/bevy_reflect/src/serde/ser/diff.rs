@@ -0,0 +1,239 @@
+use crate::{
+    serde::ReflectSerializer, FieldId, ListDiff, MapDiff, ReflectDiff, SetDiff, TypeRegistry,
+};
+use serde::{
+    ser::{SerializeTuple, SerializeTupleVariant},
+    Serialize, Serializer,
+};
+
+/// A serializer for a [`ReflectDiff`], so it can be stored or sent and applied later.
+///
+/// The values contained in the diff are serialized like with [`ReflectSerializer`], so their types
+/// must be registered in the [`TypeRegistry`].
+/// Use [`ReflectDiffDeserializer`] to deserialize the diff.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{diff, TypeRegistry};
+/// # use bevy_reflect::serde::{ReflectDiffDeserializer, ReflectDiffSerializer};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Settings {
+///     volume: f32,
+///     muted: bool,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Settings>();
+///
+/// let before = Settings { volume: 0.5, muted: false };
+/// let after = Settings { volume: 0.8, muted: false };
+/// let diff = diff(&before, &after);
+///
+/// let serializer = ReflectDiffSerializer::new(&diff, &registry);
+/// let serialized = ron::to_string(&serializer).unwrap();
+/// assert_eq!(serialized, r#"Fields([(Named("volume"),Replaced({"f32":0.8}))])"#);
+///
+/// let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+/// let diff = ReflectDiffDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// let mut patched = before.clone();
+/// diff.apply(&mut patched).unwrap();
+/// assert_eq!(patched, after);
+/// ```
+///
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+pub struct ReflectDiffSerializer<'a> {
+    diff: &'a ReflectDiff,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    /// Creates a serializer for `diff`.
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl Serialize for ReflectDiffSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "ReflectDiff";
+        let registry = self.registry;
+        match self.diff {
+            ReflectDiff::Unchanged => serializer.serialize_unit_variant(NAME, 0, "Unchanged"),
+            ReflectDiff::Replaced(value) => serializer.serialize_newtype_variant(
+                NAME,
+                1,
+                "Replaced",
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            ReflectDiff::Variant(value) => serializer.serialize_newtype_variant(
+                NAME,
+                2,
+                "Variant",
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            ReflectDiff::Fields(fields) => serializer.serialize_newtype_variant(
+                NAME,
+                3,
+                "Fields",
+                &Changes {
+                    changes: fields,
+                    registry,
+                },
+            ),
+            ReflectDiff::List(changes) => serializer.serialize_newtype_variant(
+                NAME,
+                4,
+                "List",
+                &Changes { changes, registry },
+            ),
+            ReflectDiff::Map(changes) => {
+                serializer.serialize_newtype_variant(NAME, 5, "Map", &Changes { changes, registry })
+            }
+            ReflectDiff::Set(changes) => {
+                serializer.serialize_newtype_variant(NAME, 6, "Set", &Changes { changes, registry })
+            }
+        }
+    }
+}
+
+/// Serializes a sequence of changes of a [`ReflectDiff`].
+struct Changes<'a, T> {
+    changes: &'a [T],
+    registry: &'a TypeRegistry,
+}
+
+/// Serializes a single change of a [`ReflectDiff`].
+struct Change<'a, T> {
+    change: &'a T,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, T> Serialize for Changes<'a, T>
+where
+    Change<'a, T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.changes.iter().map(|change| Change {
+            change,
+            registry: self.registry,
+        }))
+    }
+}
+
+impl Serialize for Change<'_, (FieldId, ReflectDiff)> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (id, diff) = self.change;
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&SerializableFieldId(id))?;
+        tuple.serialize_element(&ReflectDiffSerializer::new(diff, self.registry))?;
+        tuple.end()
+    }
+}
+
+impl Serialize for Change<'_, ListDiff> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "ListDiff";
+        match self.change {
+            ListDiff::Insert(index, value) => {
+                let mut variant = serializer.serialize_tuple_variant(NAME, 0, "Insert", 2)?;
+                variant.serialize_field(index)?;
+                variant.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                variant.end()
+            }
+            ListDiff::Remove(index) => {
+                serializer.serialize_newtype_variant(NAME, 1, "Remove", index)
+            }
+            ListDiff::Modify(index, diff) => {
+                let mut variant = serializer.serialize_tuple_variant(NAME, 2, "Modify", 2)?;
+                variant.serialize_field(index)?;
+                variant.serialize_field(&ReflectDiffSerializer::new(diff, self.registry))?;
+                variant.end()
+            }
+        }
+    }
+}
+
+impl Serialize for Change<'_, MapDiff> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "MapDiff";
+        match self.change {
+            MapDiff::Insert(key, value) => {
+                let mut variant = serializer.serialize_tuple_variant(NAME, 0, "Insert", 2)?;
+                variant.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                variant.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                variant.end()
+            }
+            MapDiff::Remove(key) => serializer.serialize_newtype_variant(
+                NAME,
+                1,
+                "Remove",
+                &ReflectSerializer::new(key.as_ref(), self.registry),
+            ),
+            MapDiff::Modify(key, diff) => {
+                let mut variant = serializer.serialize_tuple_variant(NAME, 2, "Modify", 2)?;
+                variant.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                variant.serialize_field(&ReflectDiffSerializer::new(diff, self.registry))?;
+                variant.end()
+            }
+        }
+    }
+}
+
+impl Serialize for Change<'_, SetDiff> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "SetDiff";
+        let (index, variant, value) = match self.change {
+            SetDiff::Insert(value) => (0, "Insert", value),
+            SetDiff::Remove(value) => (1, "Remove", value),
+        };
+        serializer.serialize_newtype_variant(
+            NAME,
+            index,
+            variant,
+            &ReflectSerializer::new(value.as_ref(), self.registry),
+        )
+    }
+}
+
+struct SerializableFieldId<'a>(&'a FieldId);
+
+impl Serialize for SerializableFieldId<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "FieldId";
+        match self.0 {
+            FieldId::Named(name) => {
+                serializer.serialize_newtype_variant(NAME, 0, "Named", name.as_ref())
+            }
+            FieldId::Unnamed(index) => {
+                serializer.serialize_newtype_variant(NAME, 1, "Unnamed", index)
+            }
+        }
+    }
+}
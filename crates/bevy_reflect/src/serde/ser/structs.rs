@@ -1,5 +1,8 @@
 use crate::{
-    serde::{ser::error_utils::make_custom_error, SerializationData, TypedReflectSerializer},
+    serde::{
+        ser::error_utils::make_custom_error, SerializationData, TypeMigrations,
+        TypedReflectSerializer,
+    },
     Struct, TypeInfo, TypeRegistry,
 };
 use serde::{ser::SerializeStruct, Serialize};
@@ -37,16 +40,22 @@ impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
             }
         };
 
-        let serialization_data = self
-            .registry
-            .get(type_info.type_id())
-            .and_then(|registration| registration.data::<SerializationData>());
+        let registration = self.registry.get(type_info.type_id());
+        let serialization_data =
+            registration.and_then(|registration| registration.data::<SerializationData>());
+        let version = registration
+            .and_then(|registration| registration.data::<TypeMigrations>())
+            .map(TypeMigrations::version);
         let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
         let mut state = serializer.serialize_struct(
             struct_info.type_path_table().ident().unwrap(),
-            self.struct_value.field_len() - ignored_len,
+            self.struct_value.field_len() - ignored_len + usize::from(version.is_some()),
         )?;
 
+        if let Some(version) = version {
+            state.serialize_field(TypeMigrations::VERSION_FIELD, &version)?;
+        }
+
         for (index, value) in self.struct_value.iter_fields().enumerate() {
             if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
                 continue;
@@ -0,0 +1,83 @@
+use crate::TypePath;
+use core::fmt;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A compact identifier of a type that is stable across runs, builds and platforms.
+///
+/// It is a 64-bit [FNV-1a] hash of the [type path] of the type, so it only changes when the type path
+/// does. It's used by [`CompactReflectSerializer`] to identify types without writing their full type
+/// path, and registered types can be looked up with [`TypeRegistry::get_with_stable_id`].
+///
+/// If two registered types have the same stable identifier, neither can be looked up with it.
+///
+/// ```
+/// # use bevy_reflect::serde::StableTypeId;
+/// assert_eq!(StableTypeId::of::<u32>(), StableTypeId::of_type_path("u32"));
+/// assert_ne!(StableTypeId::of::<u32>(), StableTypeId::of::<i32>());
+/// ```
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+/// [type path]: TypePath::type_path
+/// [`CompactReflectSerializer`]: crate::serde::CompactReflectSerializer
+/// [`TypeRegistry::get_with_stable_id`]: crate::TypeRegistry::get_with_stable_id
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StableTypeId(u64);
+
+impl StableTypeId {
+    /// Returns the stable identifier of the type `T`.
+    pub fn of<T: TypePath + ?Sized>() -> Self {
+        Self::of_type_path(T::type_path())
+    }
+
+    /// Returns the stable identifier of the type with the given [type path].
+    ///
+    /// [type path]: TypePath::type_path
+    pub const fn of_type_path(type_path: &str) -> Self {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let bytes = type_path.as_bytes();
+        let mut hash = OFFSET_BASIS;
+        let mut index = 0;
+        while index < bytes.len() {
+            hash ^= bytes[index] as u64;
+            hash = hash.wrapping_mul(PRIME);
+            index += 1;
+        }
+        Self(hash)
+    }
+
+    /// Creates a stable identifier from its bits, as returned by [`to_bits`](Self::to_bits).
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bits of this identifier.
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Debug for StableTypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "StableTypeId({:#018x})", self.0)
+    }
+}
+
+impl Serialize for StableTypeId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for StableTypeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(Self)
+    }
+}
@@ -1,6 +1,10 @@
-use crate::Reflect;
-use alloc::boxed::Box;
-use bevy_platform::collections::{hash_map::Iter, HashMap};
+use crate::{DynamicStruct, Reflect, StructInfo};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_platform::{
+    collections::{hash_map::Iter, HashMap},
+    sync::{OnceLock, PoisonError, RwLock},
+};
+use bevy_utils::TypeIdMap;
 
 /// Contains data relevant to the automatic reflect powered (de)serialization of a type.
 #[derive(Debug, Clone)]
@@ -134,3 +138,164 @@ impl SkippedField {
         (self.default_fn)()
     }
 }
+
+/// A function updating the fields of a struct from one version of its type to the next.
+///
+/// It returns an error describing why the data can't be migrated, such as a missing field or a
+/// field of an unexpected type, which fails the deserialization.
+pub type MigrationFn = fn(&mut DynamicStruct) -> Result<(), String>;
+
+/// Contains the version of a struct type, and the migrations updating data serialized with its
+/// older versions.
+///
+/// This is inserted with the `#[reflect(version = N)]` attribute when deriving [`Reflect`], and
+/// migrations are added with [`TypeRegistry::register_migration`].
+///
+/// The version is serialized with the fields of the struct, as a field named
+/// [`VERSION_FIELD`](Self::VERSION_FIELD). Data without it, such as data serialized before the type
+/// had a version, is considered to be of version 0.
+///
+/// When data of an older version is deserialized from a self-describing format like RON, the fields
+/// it contains are collected into a [`DynamicStruct`], then each migration from the data version up
+/// to the current one is applied to it in order. The fields that are no longer part of the type
+/// are deserialized as dynamic values:
+/// - integers as `u64` or `i64`, and floats as `f64`,
+/// - strings as [`String`], booleans as `bool`, and units as `()`,
+/// - sequences as [`DynamicList`] and options as [`DynamicEnum`],
+/// - maps as [`DynamicStruct`] if all their keys are strings, and [`DynamicMap`] otherwise.
+///
+/// A field that keeps its name is deserialized as its current type, so changing its type requires
+/// renaming it.
+/// Formats that don't store field names, such as bincode, can only deserialize data of the current
+/// version.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{serde::TypedReflectDeserializer, DynamicStruct, FromReflect, GetField, Reflect, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(version = 1)]
+/// struct Health {
+///     // Used to be called `hp`.
+///     #[reflect(alias = "hp")]
+///     current: f32,
+///     // Used to be stored as the percentage `current` is of it, called `percent`.
+///     max: f32,
+/// }
+///
+/// fn migrate_to_v1(health: &mut DynamicStruct) -> Result<(), String> {
+///     let current = *health.get_field::<f32>("current").ok_or("missing `current`")?;
+///     let percent = health.remove("percent").ok_or("missing `percent`")?;
+///     let percent = percent
+///         .try_downcast_ref::<f64>()
+///         .ok_or("`percent` is not a number")?;
+///     health.insert("max", current * 100.0 / *percent as f32);
+///     Ok(())
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+/// registry.register_migration::<Health>(0, migrate_to_v1);
+///
+/// let mut deserializer = ron::Deserializer::from_str("(hp: 50.0, percent: 25.0)").unwrap();
+/// let health = TypedReflectDeserializer::of::<Health>(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// let health = Health::from_reflect(health.as_ref()).unwrap();
+/// assert_eq!(health, Health { current: 50.0, max: 200.0 });
+/// ```
+///
+/// [`TypeRegistry::register_migration`]: crate::TypeRegistry::register_migration
+/// [`String`]: alloc::string::String
+/// [`DynamicList`]: crate::DynamicList
+/// [`DynamicEnum`]: crate::DynamicEnum
+/// [`DynamicMap`]: crate::DynamicMap
+#[derive(Debug, Clone)]
+pub struct TypeMigrations {
+    version: u32,
+    migrations: Vec<(u32, MigrationFn)>,
+}
+
+impl TypeMigrations {
+    /// The name of the field the version of a type is serialized as.
+    pub const VERSION_FIELD: &'static str = "__version";
+
+    /// Creates a new `TypeMigrations` for the given current version of a type, without any
+    /// migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// The current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Adds a migration updating data from version `from` to version `from + 1`.
+    ///
+    /// Versions without a migration are updated without changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` is not older than the current version, or if a migration from this version
+    /// already exists.
+    pub fn add(&mut self, from: u32, migration: MigrationFn) {
+        assert!(
+            from < self.version,
+            "cannot migrate from version {from}, as the current version is {}",
+            self.version
+        );
+        let Err(index) = self
+            .migrations
+            .binary_search_by_key(&from, |(from, _)| *from)
+        else {
+            panic!("a migration from version {from} already exists");
+        };
+        self.migrations.insert(index, (from, migration));
+    }
+
+    /// Applies the migrations from version `from` to the current version to `data`, in order.
+    ///
+    /// Stops at the first migration that fails, returning its error.
+    pub fn migrate(&self, from: u32, data: &mut DynamicStruct) -> Result<(), String> {
+        for (_, migration) in self
+            .migrations
+            .iter()
+            .filter(|(version, _)| *version >= from)
+        {
+            migration(data)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the names of the serialized fields of the struct described by `info`, including
+    /// [`VERSION_FIELD`](Self::VERSION_FIELD).
+    pub(crate) fn field_names(&self, info: &StructInfo) -> &'static [&'static str] {
+        // Deserializers expect a static slice, so the names are leaked once per type and cached.
+        static FIELD_NAMES: OnceLock<RwLock<TypeIdMap<&'static [&'static str]>>> = OnceLock::new();
+
+        let cache = FIELD_NAMES.get_or_init(Default::default);
+        if let Some(&field_names) = cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&info.type_id())
+        {
+            return field_names;
+        }
+
+        cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(info.type_id())
+            .or_insert_with(|| {
+                let field_names = core::iter::once(Self::VERSION_FIELD)
+                    .chain(info.field_names().iter().copied())
+                    .collect::<Vec<_>>();
+                Box::leak(field_names.into_boxed_slice())
+            })
+    }
+}
@@ -325,6 +325,20 @@ impl DynamicStruct {
         self.insert_boxed(name, Box::new(value));
     }
 
+    /// Removes the field named `name` from the struct, returning its value.
+    ///
+    /// The fields after it are shifted down by one index.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn PartialReflect>> {
+        let index = self.field_indices.remove(name)?;
+        self.field_names.remove(index);
+        for field_index in self.field_indices.values_mut() {
+            if *field_index > index {
+                *field_index -= 1;
+            }
+        }
+        Some(self.fields.remove(index))
+    }
+
     /// Gets the index of the field with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
@@ -594,4 +608,19 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(prev_index, iter.index);
     }
+
+    #[test]
+    fn dynamic_struct_remove() {
+        let mut dynamic_struct = DynamicStruct::default();
+        dynamic_struct.insert("a", 1u32);
+        dynamic_struct.insert("b", 2u32);
+        dynamic_struct.insert("c", 3u32);
+
+        let b = dynamic_struct.remove("b").unwrap();
+        assert_eq!(b.try_downcast_ref::<u32>(), Some(&2));
+        assert!(dynamic_struct.remove("b").is_none());
+        assert_eq!(dynamic_struct.field_len(), 2);
+        assert_eq!(dynamic_struct.name_at(1), Some("c"));
+        assert_eq!(dynamic_struct.get_field::<u32>("c"), Some(&3));
+    }
 }
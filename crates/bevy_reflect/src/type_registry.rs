@@ -1,4 +1,7 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::{MigrationFn, Serializable, StableTypeId, TypeMigrations},
+    FromReflect, Reflect, TypeInfo, TypePath, Typed,
+};
 use alloc::{boxed::Box, string::String};
 use bevy_platform::{
     collections::{HashMap, HashSet},
@@ -31,6 +34,8 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    stable_id_to_id: HashMap<StableTypeId, TypeId>,
+    ambiguous_stable_ids: HashSet<StableTypeId>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -93,6 +98,8 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            stable_id_to_id: Default::default(),
+            ambiguous_stable_ids: Default::default(),
         }
     }
 
@@ -233,6 +240,8 @@ impl TypeRegistry {
             &mut self.short_path_to_id,
             &mut self.type_path_to_id,
             &mut self.ambiguous_names,
+            &mut self.stable_id_to_id,
+            &mut self.ambiguous_stable_ids,
         );
         self.registrations
             .insert(registration.type_id(), registration);
@@ -261,6 +270,8 @@ impl TypeRegistry {
                     &mut self.short_path_to_id,
                     &mut self.type_path_to_id,
                     &mut self.ambiguous_names,
+                    &mut self.stable_id_to_id,
+                    &mut self.ambiguous_stable_ids,
                 );
                 entry.insert(registration);
                 true
@@ -274,6 +285,8 @@ impl TypeRegistry {
         short_path_to_id: &mut HashMap<&'static str, TypeId>,
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
+        stable_id_to_id: &mut HashMap<StableTypeId, TypeId>,
+        ambiguous_stable_ids: &mut HashSet<StableTypeId>,
     ) {
         let short_name = registration.type_info().type_path_table().short_path();
         if short_path_to_id.contains_key(short_name) || ambiguous_names.contains(short_name) {
@@ -284,6 +297,17 @@ impl TypeRegistry {
             short_path_to_id.insert(short_name, registration.type_id());
         }
         type_path_to_id.insert(registration.type_info().type_path(), registration.type_id());
+
+        let stable_id = StableTypeId::of_type_path(registration.type_info().type_path());
+        let collides = stable_id_to_id
+            .get(&stable_id)
+            .is_some_and(|type_id| *type_id != registration.type_id());
+        if collides || ambiguous_stable_ids.contains(&stable_id) {
+            stable_id_to_id.remove(&stable_id);
+            ambiguous_stable_ids.insert(stable_id);
+        } else {
+            stable_id_to_id.insert(stable_id, registration.type_id());
+        }
     }
 
     /// Registers the type data `D` for type `T`.
@@ -313,6 +337,34 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers a migration updating serialized data of type `T` from version `from` to version
+    /// `from + 1`.
+    ///
+    /// See [`TypeMigrations`] for how migrations are applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not registered, if it doesn't have a version (set with the
+    /// `#[reflect(version = N)]` attribute), if `from` is not older than its version, or if a
+    /// migration from `from` is already registered.
+    pub fn register_migration<T: Reflect + TypePath>(&mut self, from: u32, migration: MigrationFn) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_migration` for type `{}` without registering it first",
+                T::type_path(),
+            )
+        });
+        let migrations = registration
+            .data_mut::<TypeMigrations>()
+            .unwrap_or_else(|| {
+                panic!(
+                    "attempted to register a migration for type `{}`, which doesn't have a version",
+                    T::type_path(),
+                )
+            });
+        migrations.add(from, migration);
+    }
+
     pub fn contains(&self, type_id: TypeId) -> bool {
         self.registrations.contains_key(&type_id)
     }
@@ -359,6 +411,17 @@ impl TypeRegistry {
             .and_then(move |id| self.get_mut(id))
     }
 
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [`StableTypeId`].
+    ///
+    /// If several registered types have this identifier, or if no type with it
+    /// has been registered, returns `None`.
+    pub fn get_with_stable_id(&self, stable_id: StableTypeId) -> Option<&TypeRegistration> {
+        self.stable_id_to_id
+            .get(&stable_id)
+            .and_then(|id| self.get(*id))
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with
     /// the given [short type path].
     ///
@@ -985,4 +1048,21 @@ mod test {
         let data = registration.data::<DataA>().unwrap();
         assert_eq!(data.0, 456);
     }
+
+    #[test]
+    fn get_with_stable_id() {
+        #[derive(Reflect)]
+        struct Foo;
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+
+        let registration = registry
+            .get_with_stable_id(StableTypeId::of::<Foo>())
+            .unwrap();
+        assert_eq!(registration.type_id(), TypeId::of::<Foo>());
+        assert!(registry
+            .get_with_stable_id(StableTypeId::of::<u32>())
+            .is_none());
+    }
 }
@@ -0,0 +1,543 @@
+//! Authentication and access control for the Bevy Remote Protocol.
+//!
+//! By default, any client able to reach a BRP transport can call every method. To restrict this,
+//! give the [`RemotePlugin`](crate::RemotePlugin) a [`RemoteAccessControl`] with
+//! [`with_access_control`](crate::RemotePlugin::with_access_control), or modify the resource while
+//! the app is running.
+//!
+//! Clients authenticate by sending a token with each request, as a bearer token in the
+//! `Authorization` header: `Authorization: Bearer <token>`. The WebSocket transport reads this
+//! header from the handshake request, so it applies to every request sent over the connection,
+//! and refuses the connection if the token isn't accepted.
+//!
+//! The token identifies the [`RemotePermissions`] of the client, which are checked before the
+//! handler of the method runs:
+//! - Clients without a valid token get an [`UNAUTHORIZED`] error.
+//! - Calling a method that is not allowed returns a [`METHOD_NOT_ALLOWED`] error.
+//! - Naming a denied component in the params of one of the built-in methods returns a
+//!   [`COMPONENT_NOT_ALLOWED`] error, and denied components are left out of the results of
+//!   `bevy/list` and `bevy/list+watch`.
+//!
+//! [`UNAUTHORIZED`]: crate::error_codes::UNAUTHORIZED
+//! [`METHOD_NOT_ALLOWED`]: crate::error_codes::METHOD_NOT_ALLOWED
+//! [`COMPONENT_NOT_ALLOWED`]: crate::error_codes::COMPONENT_NOT_ALLOWED
+
+use alloc::sync::Arc;
+use bevy_ecs::resource::Resource;
+use bevy_platform::collections::HashSet;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{
+    builtin_methods::{
+        BrpGetParams, BrpInsertParams, BrpMutateComponentParams, BrpQueryParams, BrpRemoveParams,
+        BrpSpawnParams, BRP_GET_AND_WATCH_METHOD, BRP_GET_METHOD, BRP_GET_RESOURCE_METHOD,
+        BRP_INSERT_METHOD, BRP_LIST_AND_WATCH_METHOD, BRP_LIST_METHOD, BRP_LIST_RESOURCES_METHOD,
+        BRP_MUTATE_COMPONENT_METHOD, BRP_QUERY_METHOD, BRP_REGISTRY_SCHEMA_METHOD,
+        BRP_REMOVE_METHOD, BRP_SPAWN_METHOD, RPC_DISCOVER_METHOD,
+    },
+    BrpError,
+};
+
+/// The methods allowed by [`RemotePermissions::read_only`].
+pub const READ_ONLY_METHODS: &[&str] = &[
+    BRP_GET_METHOD,
+    BRP_QUERY_METHOD,
+    BRP_LIST_METHOD,
+    BRP_GET_AND_WATCH_METHOD,
+    BRP_LIST_AND_WATCH_METHOD,
+    BRP_GET_RESOURCE_METHOD,
+    BRP_LIST_RESOURCES_METHOD,
+    BRP_REGISTRY_SCHEMA_METHOD,
+    RPC_DISCOVER_METHOD,
+];
+
+/// A resource deciding which clients can use the Bevy Remote Protocol, and what they are allowed
+/// to do.
+///
+/// The default value lets every client call every method without authenticating.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_remote::{access::{RemoteAccessControl, RemotePermissions}, RemotePlugin};
+/// let access_control = RemoteAccessControl::authenticated()
+///     // The editor can do anything.
+///     .with_token("editor-secret", RemotePermissions::default())
+///     // The profiler can only read, and never sees the player's inventory.
+///     .with_token(
+///         "profiler-secret",
+///         RemotePermissions::read_only().with_denied_component("my_game::Inventory"),
+///     );
+///
+/// let plugin = RemotePlugin::default().with_access_control(access_control);
+/// ```
+///
+/// Clones share the same rules, so that transports can keep a clone to authenticate clients when
+/// they connect, and still see the changes made to the resource while the app is running.
+#[derive(Debug, Resource, Clone)]
+pub struct RemoteAccessControl {
+    rules: Arc<RwLock<AccessRules>>,
+}
+
+#[derive(Debug)]
+struct AccessRules {
+    /// The permissions of clients that don't send a token, or [`None`] if they are rejected.
+    anonymous: Option<RemotePermissions>,
+    /// The accepted tokens, and the permissions of the clients sending them.
+    tokens: Vec<(String, RemotePermissions)>,
+}
+
+impl Default for RemoteAccessControl {
+    fn default() -> Self {
+        Self::new(Some(RemotePermissions::default()))
+    }
+}
+
+impl RemoteAccessControl {
+    fn new(anonymous: Option<RemotePermissions>) -> Self {
+        Self {
+            rules: Arc::new(RwLock::new(AccessRules {
+                anonymous,
+                tokens: Vec::new(),
+            })),
+        }
+    }
+
+    /// Creates a [`RemoteAccessControl`] rejecting every client until tokens are added with
+    /// [`with_token`](Self::with_token).
+    pub fn authenticated() -> Self {
+        Self::new(None)
+    }
+
+    /// Accepts clients sending `token`, giving them the given `permissions`.
+    ///
+    /// Giving the same token to every client makes it a shared secret.
+    /// If the token was already accepted, its permissions are replaced.
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>, permissions: RemotePermissions) -> Self {
+        self.insert_token(token, permissions);
+        self
+    }
+
+    /// Sets the permissions of clients that don't send a token, or rejects them if [`None`].
+    #[must_use]
+    pub fn with_anonymous_permissions(self, permissions: Option<RemotePermissions>) -> Self {
+        self.write().anonymous = permissions;
+        self
+    }
+
+    /// Accepts clients sending `token`, giving them the given `permissions`.
+    ///
+    /// If the token was already accepted, its permissions are replaced.
+    pub fn insert_token(&mut self, token: impl Into<String>, permissions: RemotePermissions) {
+        let token = token.into();
+        let mut rules = self.write();
+        match rules
+            .tokens
+            .iter_mut()
+            .find(|(existing, _)| *existing == token)
+        {
+            Some((_, existing)) => *existing = permissions,
+            None => rules.tokens.push((token, permissions)),
+        }
+    }
+
+    /// Stops accepting `token`, returning the permissions it gave.
+    pub fn remove_token(&mut self, token: &str) -> Option<RemotePermissions> {
+        let mut rules = self.write();
+        let index = rules
+            .tokens
+            .iter()
+            .position(|(existing, _)| existing == token)?;
+        Some(rules.tokens.swap_remove(index).1)
+    }
+
+    /// Checks that a client sending the given `token` is accepted.
+    ///
+    /// Fails with [`BrpError::unauthorized`] if it isn't, including when it sends an unknown token
+    /// while anonymous clients are accepted.
+    pub fn authenticate(&self, token: Option<&str>) -> Result<(), BrpError> {
+        self.read().permissions(token).map(|_| ())
+    }
+
+    /// Checks that a client sending `token` is allowed to call `method` with `params`.
+    pub fn authorize(
+        &self,
+        token: Option<&str>,
+        method: &str,
+        params: Option<&Value>,
+    ) -> Result<(), BrpError> {
+        let rules = self.read();
+        let permissions = rules.permissions(token)?;
+        if !permissions.is_method_allowed(method) {
+            return Err(BrpError::method_not_allowed(method));
+        }
+        permissions.check_components(method, params)
+    }
+
+    /// Removes the components denied to a client sending `token` from the `result` of a
+    /// built-in method listing components.
+    ///
+    /// Returns `false` if the result of a watching method only listed denied components,
+    /// in which case it shouldn't be sent.
+    pub(crate) fn filter_result(
+        &self,
+        token: Option<&str>,
+        method: &str,
+        result: &mut Value,
+    ) -> bool {
+        let rules = self.read();
+        let Ok(permissions) = rules.permissions(token) else {
+            return true;
+        };
+        permissions.filter_components(method, result)
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, AccessRules> {
+        self.rules.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, AccessRules> {
+        self.rules.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl AccessRules {
+    /// Returns the permissions of a client sending the given `token`.
+    fn permissions(&self, token: Option<&str>) -> Result<&RemotePermissions, BrpError> {
+        let permissions = match token {
+            // Compare every token without stopping early, to not leak how much of it matched.
+            Some(token) => self
+                .tokens
+                .iter()
+                .fold(None, |found, (accepted, permissions)| {
+                    if constant_time_eq(accepted.as_bytes(), token.as_bytes()) {
+                        Some(permissions)
+                    } else {
+                        found
+                    }
+                }),
+            None => self.anonymous.as_ref(),
+        };
+        permissions.ok_or_else(BrpError::unauthorized)
+    }
+}
+
+/// What a client of the Bevy Remote Protocol is allowed to do.
+///
+/// The default value allows calling every method, with any component.
+#[derive(Debug, Clone, Default)]
+pub struct RemotePermissions {
+    /// The methods that can be called, or [`None`] if all methods can be.
+    allowed_methods: Option<HashSet<String>>,
+    /// The full type paths of the components that can't be named in requests.
+    denied_components: HashSet<String>,
+}
+
+impl RemotePermissions {
+    /// Creates permissions only allowing the methods reading the world,
+    /// listed in [`READ_ONLY_METHODS`].
+    pub fn read_only() -> Self {
+        Self::default().with_allowed_methods(READ_ONLY_METHODS.iter().copied())
+    }
+
+    /// Only allows calling the given methods.
+    #[must_use]
+    pub fn with_allowed_methods(
+        mut self,
+        methods: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.allowed_methods = Some(methods.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Denies naming the component with the given [full type path] in requests.
+    ///
+    /// This is checked for the params of the built-in component methods, such as `bevy/get`,
+    /// `bevy/query` or `bevy/insert`, and the component is left out of the results of
+    /// `bevy/list` and `bevy/list+watch`. Custom methods have to check it themselves.
+    ///
+    /// [full type path]: bevy_reflect::TypePath::type_path
+    #[must_use]
+    pub fn with_denied_component(mut self, type_path: impl Into<String>) -> Self {
+        self.denied_components.insert(type_path.into());
+        self
+    }
+
+    /// Denies naming the components with the given [full type paths] in requests.
+    ///
+    /// See [`with_denied_component`](Self::with_denied_component) for details.
+    ///
+    /// [full type paths]: bevy_reflect::TypePath::type_path
+    #[must_use]
+    pub fn with_denied_components(
+        mut self,
+        type_paths: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.denied_components
+            .extend(type_paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns true if the client can call `method`.
+    pub fn is_method_allowed(&self, method: &str) -> bool {
+        self.allowed_methods
+            .as_ref()
+            .is_none_or(|methods| methods.contains(method))
+    }
+
+    /// Returns true if the component with the given full type path can't be named in requests.
+    pub fn is_component_denied(&self, type_path: &str) -> bool {
+        self.denied_components.contains(type_path)
+    }
+
+    /// Checks that the params of a built-in method don't name a denied component.
+    ///
+    /// Params that fail to parse are let through, so that the method reports the error itself.
+    fn check_components(&self, method: &str, params: Option<&Value>) -> Result<(), BrpError> {
+        if self.denied_components.is_empty() {
+            return Ok(());
+        }
+        let Some(params) = params else {
+            return Ok(());
+        };
+
+        let components: Vec<String> = match method {
+            BRP_GET_METHOD | BRP_GET_AND_WATCH_METHOD => parse::<BrpGetParams>(params)
+                .map(|params| params.components)
+                .unwrap_or_default(),
+            BRP_QUERY_METHOD => parse::<BrpQueryParams>(params)
+                .map(|params| {
+                    let data = params.data;
+                    let filter = params.filter;
+                    data.components
+                        .into_iter()
+                        .chain(data.option)
+                        .chain(data.has)
+                        .chain(filter.with)
+                        .chain(filter.without)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            BRP_SPAWN_METHOD => parse::<BrpSpawnParams>(params)
+                .map(|params| params.components.into_keys().collect())
+                .unwrap_or_default(),
+            BRP_INSERT_METHOD => parse::<BrpInsertParams>(params)
+                .map(|params| params.components.into_keys().collect())
+                .unwrap_or_default(),
+            BRP_REMOVE_METHOD => parse::<BrpRemoveParams>(params)
+                .map(|params| params.components)
+                .unwrap_or_default(),
+            BRP_MUTATE_COMPONENT_METHOD => parse::<BrpMutateComponentParams>(params)
+                .map(|params| vec![params.component])
+                .unwrap_or_default(),
+            _ => return Ok(()),
+        };
+
+        match components
+            .iter()
+            .find(|component| self.is_component_denied(component))
+        {
+            Some(component) => Err(BrpError::component_not_allowed(component)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl RemotePermissions {
+    /// Removes the denied components from the `result` of `bevy/list` or `bevy/list+watch`.
+    ///
+    /// Returns `false` if only denied components were added or removed for `bevy/list+watch`.
+    fn filter_components(&self, method: &str, result: &mut Value) -> bool {
+        if self.denied_components.is_empty() {
+            return true;
+        }
+        let retain = |components: Option<&mut Value>| {
+            let Some(Value::Array(components)) = components else {
+                return false;
+            };
+            components.retain(|component| {
+                component
+                    .as_str()
+                    .is_none_or(|component| !self.is_component_denied(component))
+            });
+            !components.is_empty()
+        };
+
+        match method {
+            BRP_LIST_METHOD => {
+                retain(Some(result));
+                true
+            }
+            BRP_LIST_AND_WATCH_METHOD => {
+                let added = retain(result.get_mut("added"));
+                let removed = retain(result.get_mut("removed"));
+                added || removed
+            }
+            _ => true,
+        }
+    }
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(value: &Value) -> Option<T> {
+    T::deserialize(value).ok()
+}
+
+/// Returns the token of an `Authorization` header value using the `Bearer` scheme.
+///
+/// Custom transports can use this to fill [`BrpMessage::token`](crate::BrpMessage::token).
+pub fn bearer_token(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    scheme
+        .eq_ignore_ascii_case("bearer")
+        .then(|| token.trim_start())
+}
+
+/// Compares two byte strings in a time depending only on their lengths.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::error_codes;
+
+    fn access_control() -> RemoteAccessControl {
+        RemoteAccessControl::authenticated()
+            .with_token("editor", RemotePermissions::default())
+            .with_token(
+                "viewer",
+                RemotePermissions::read_only().with_denied_component("game::Secret"),
+            )
+    }
+
+    #[test]
+    fn authenticate_tokens() {
+        let access_control = access_control();
+        let code = |token| {
+            access_control
+                .authorize(token, BRP_GET_METHOD, None)
+                .err()
+                .map(|error| error.code)
+        };
+
+        assert_eq!(code(Some("editor")), None);
+        assert_eq!(code(Some("viewer")), None);
+        assert_eq!(code(Some("edito")), Some(error_codes::UNAUTHORIZED));
+        assert_eq!(code(None), Some(error_codes::UNAUTHORIZED));
+
+        let access_control =
+            access_control.with_anonymous_permissions(Some(RemotePermissions::read_only()));
+        assert!(access_control.authorize(None, BRP_GET_METHOD, None).is_ok());
+        assert_eq!(
+            access_control
+                .authorize(Some("unknown"), BRP_GET_METHOD, None)
+                .unwrap_err()
+                .code,
+            error_codes::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn allowed_methods() {
+        let access_control = access_control();
+
+        assert!(access_control
+            .authorize(Some("editor"), BRP_SPAWN_METHOD, None)
+            .is_ok());
+        assert!(access_control
+            .authorize(Some("viewer"), BRP_QUERY_METHOD, None)
+            .is_ok());
+        assert_eq!(
+            access_control
+                .authorize(Some("viewer"), BRP_SPAWN_METHOD, None)
+                .unwrap_err()
+                .code,
+            error_codes::METHOD_NOT_ALLOWED
+        );
+    }
+
+    #[test]
+    fn denied_components() {
+        let access_control = access_control().with_token(
+            "writer",
+            RemotePermissions::default().with_denied_component("game::Secret"),
+        );
+        let code = |token, method, params| {
+            access_control
+                .authorize(Some(token), method, Some(&params))
+                .err()
+                .map(|error| error.code)
+        };
+
+        let get =
+            json!({ "entity": 4294967298u64, "components": ["game::Public", "game::Secret"] });
+        assert_eq!(code("editor", BRP_GET_METHOD, get.clone()), None);
+        assert_eq!(
+            code("viewer", BRP_GET_METHOD, get),
+            Some(error_codes::COMPONENT_NOT_ALLOWED)
+        );
+
+        let query = json!({
+            "data": { "components": ["game::Public"] },
+            "filter": { "without": ["game::Secret"] }
+        });
+        assert_eq!(
+            code("viewer", BRP_QUERY_METHOD, query),
+            Some(error_codes::COMPONENT_NOT_ALLOWED)
+        );
+
+        let insert = json!({ "entity": 4294967298u64, "components": { "game::Secret": {} } });
+        assert_eq!(
+            code("writer", BRP_INSERT_METHOD, insert),
+            Some(error_codes::COMPONENT_NOT_ALLOWED)
+        );
+        let insert = json!({ "entity": 4294967298u64, "components": { "game::Public": {} } });
+        assert_eq!(code("writer", BRP_INSERT_METHOD, insert), None);
+    }
+
+    #[test]
+    fn filter_denied_components() {
+        let access_control = access_control();
+        let filter = |method, mut result| {
+            let keep = access_control.filter_result(Some("viewer"), method, &mut result);
+            (keep, result)
+        };
+
+        assert_eq!(
+            filter(BRP_LIST_METHOD, json!(["game::Public", "game::Secret"])),
+            (true, json!(["game::Public"]))
+        );
+        assert_eq!(
+            filter(
+                BRP_LIST_AND_WATCH_METHOD,
+                json!({ "added": ["game::Public"], "removed": ["game::Secret"] })
+            ),
+            (true, json!({ "added": ["game::Public"], "removed": [] }))
+        );
+        assert_eq!(
+            filter(
+                BRP_LIST_AND_WATCH_METHOD,
+                json!({ "added": [], "removed": ["game::Secret"] })
+            ),
+            (false, json!({ "added": [], "removed": [] }))
+        );
+
+        let mut result = json!(["game::Secret"]);
+        assert!(access_control.filter_result(Some("editor"), BRP_LIST_METHOD, &mut result));
+        assert_eq!(result, json!(["game::Secret"]));
+    }
+
+    #[test]
+    fn parse_bearer_token() {
+        assert_eq!(bearer_token("Bearer abc"), Some("abc"));
+        assert_eq!(bearer_token("bearer  abc"), Some("abc"));
+        assert_eq!(bearer_token("Basic abc"), None);
+        assert_eq!(bearer_token("abc"), None);
+    }
+}
@@ -1,23 +1,24 @@
 //! Built-in verbs for the Bevy Remote Protocol.
 
-use core::any::TypeId;
+use core::any::{type_name, TypeId};
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
-    component::ComponentId,
+    component::{Component, ComponentId},
     entity::Entity,
     event::EventCursor,
     hierarchy::ChildOf,
+    name::Name,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
     removal_detection::RemovedComponentEntity,
-    system::{In, Local},
+    system::{In, Local, RegisteredSystem, SystemId},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{
-    serde::{ReflectSerializer, TypedReflectDeserializer},
-    GetPath, PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
+    GetPath, PartialReflect, TypeInfo, TypeRegistration, TypeRegistry,
 };
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -79,6 +80,15 @@ pub const BRP_MUTATE_RESOURCE_METHOD: &str = "bevy/mutate_resource";
 /// The method path for a `bevy/list_resources` request.
 pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
 
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger_event` request.
+pub const BRP_TRIGGER_EVENT_METHOD: &str = "bevy/trigger_event";
+
+/// The method path for a `bevy/run_system` request.
+pub const BRP_RUN_SYSTEM_METHOD: &str = "bevy/run_system";
+
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
@@ -295,6 +305,55 @@ pub struct BrpMutateResourceParams {
     pub value: Value,
 }
 
+/// `bevy/send_event`: Sends an event to its [`Events`] resource.
+///
+/// The server responds with a null.
+///
+/// [`Events`]: bevy_ecs::event::Events
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    ///
+    /// This can be omitted for events without fields.
+    #[serde(default)]
+    pub value: Value,
+}
+
+/// `bevy/trigger_event`: Triggers an event, running the observers watching for it.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpTriggerEventParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    ///
+    /// This can be omitted for events without fields.
+    #[serde(default)]
+    pub value: Value,
+
+    /// The entity targeted by the event, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Entity>,
+}
+
+/// `bevy/run_system`: Runs a one-shot system marked with [`RemoteSystem`], by name.
+///
+/// The server responds with a [`BrpRunSystemResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRunSystemParams {
+    /// The [`Name`] of the entity of the system.
+    pub name: String,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -403,6 +462,13 @@ pub struct BrpGetResourceResponse {
     pub value: Value,
 }
 
+/// The response to a `bevy/run_system` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRunSystemResponse {
+    /// The serialized output of the system, or null if it has no output.
+    pub value: Value,
+}
+
 /// A single response from a `bevy/get+watch` request.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(untagged)]
@@ -458,6 +524,92 @@ pub struct BrpQueryRow {
     pub has: HashMap<String, Value>,
 }
 
+/// A component allowing clients to run the one-shot system of its entity with `bevy/run_system`.
+///
+/// The system is identified by the [`Name`] of its entity, and must not take any input.
+/// Systems registered with [`World::register_system`] are not exposed to clients unless this
+/// component is added to them.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::{name::Name, world::World};
+/// # use bevy_remote::builtin_methods::RemoteSystem;
+/// fn spawn_wave() {
+///     // ...
+/// }
+///
+/// fn count_enemies() -> u32 {
+///     // ...
+/// #   0
+/// }
+///
+/// let mut world = World::new();
+///
+/// let id = world.register_system(spawn_wave);
+/// world
+///     .entity_mut(id.entity())
+///     .insert((Name::new("spawn_wave"), RemoteSystem::default()));
+///
+/// // Systems with an output need to name its type, which must be reflectable.
+/// let id = world.register_system(count_enemies);
+/// world
+///     .entity_mut(id.entity())
+///     .insert((Name::new("count_enemies"), RemoteSystem::with_output::<u32>()));
+/// ```
+#[derive(Component, Clone, Copy)]
+pub struct RemoteSystem {
+    run: fn(&mut World, Entity) -> BrpResult,
+}
+
+impl RemoteSystem {
+    /// Creates a [`RemoteSystem`] for a system returning a value of type `O`, which is serialized
+    /// in the response.
+    ///
+    /// `O` must be registered in the [`AppTypeRegistry`] for the value to be serialized.
+    ///
+    /// `O` isn't checked against the output of the system when the component is inserted: if they
+    /// don't match, running the system fails with a [`SYSTEM_ERROR`](error_codes::SYSTEM_ERROR)
+    /// saying so, without running it.
+    pub fn with_output<O: PartialReflect>() -> Self {
+        Self {
+            run: |world, entity| {
+                let output = run_remote_system::<O>(world, entity)?;
+
+                let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+                let type_registry = app_type_registry.read();
+                serde_json::to_value(TypedReflectSerializer::new(&output, &type_registry))
+                    .map_err(BrpError::system_error)
+            },
+        }
+    }
+}
+
+impl Default for RemoteSystem {
+    /// Creates a [`RemoteSystem`] for a system without output.
+    fn default() -> Self {
+        Self {
+            run: |world, entity| {
+                run_remote_system::<()>(world, entity)?;
+                Ok(Value::Null)
+            },
+        }
+    }
+}
+
+/// Runs the system of the [`RemoteSystem`] on `entity`, expecting it to return an `O`.
+fn run_remote_system<O: 'static>(world: &mut World, entity: Entity) -> Result<O, BrpError> {
+    if !world.entity(entity).contains::<RegisteredSystem<(), O>>() {
+        return Err(BrpError::system_error(anyhow!(
+            "The system doesn't return a `{}`, as its `RemoteSystem` expects",
+            type_name::<O>()
+        )));
+    }
+    world
+        .run_system(SystemId::<(), O>::from_entity(entity))
+        .map_err(BrpError::system_error)
+}
+
 /// A helper function used to parse a `serde_json::Value`.
 fn parse<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, BrpError> {
     serde_json::from_value(value).map_err(|err| BrpError {
@@ -1170,6 +1322,89 @@ pub fn process_remote_list_resources_request(
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams {
+        event: event_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+    let reflected_event =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+
+    if !reflect_event.send(world, &*reflected_event, &type_registry) {
+        return Err(BrpError::event_error(anyhow!(
+            "Event `{}` can't be sent, as its `Events` resource doesn't exist",
+            event_path
+        )));
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/trigger_event` request coming from a client.
+pub fn process_remote_trigger_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerEventParams {
+        event: event_path,
+        value,
+        entity,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+    let reflected_event =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+
+    if let Some(entity) = entity {
+        get_entity(world, entity)?;
+    }
+    reflect_event.trigger(world, &*reflected_event, &type_registry, entity.as_slice());
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/run_system` request coming from a client.
+pub fn process_remote_run_system_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRunSystemParams { name } = parse_some(params)?;
+
+    let systems = world
+        .query::<(Entity, &Name, &RemoteSystem)>()
+        .iter(world)
+        .filter(|(_, system_name, _)| system_name.as_str() == name)
+        .map(|(entity, _, remote_system)| (entity, *remote_system))
+        .collect::<Vec<_>>();
+    let (entity, remote_system) = match systems.as_slice() {
+        [] => return Err(BrpError::system_not_found(&name)),
+        [system] => *system,
+        _ => {
+            return Err(BrpError::system_error(anyhow!(
+                "Several remote systems are named `{}`",
+                name
+            )))
+        }
+    };
+
+    let value = (remote_system.run)(world, entity)?;
+    serde_json::to_value(BrpRunSystemResponse { value }).map_err(BrpError::internal)
+}
+
 /// Handles a `bevy/list+watch` request coming from a client.
 pub fn process_remote_list_watching_request(
     In(params): In<Option<Value>>,
@@ -1441,6 +1676,29 @@ fn deserialize_resource(
     Ok(reflected)
 }
 
+/// Given an event path and an associated serialized value (`value`), return the
+/// deserialized value.
+fn deserialize_event(
+    type_registry: &TypeRegistry,
+    event_path: &str,
+    value: Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let event_type = get_event_type_registration(type_registry, event_path)?;
+    // An omitted value stands for an event without fields.
+    let value = match (value, event_type.type_info()) {
+        (Value::Null, TypeInfo::Struct(info)) if info.field_len() == 0 => Value::Object(Map::new()),
+        (Value::Null, TypeInfo::TupleStruct(info)) if info.field_len() == 0 => {
+            Value::Array(Vec::new())
+        }
+        (value, _) => value,
+    };
+    let reflected: Box<dyn PartialReflect> =
+        TypedReflectDeserializer::new(event_type, type_registry)
+            .deserialize(&value)
+            .map_err(|err| anyhow!("{event_path} is invalid: {err}"))?;
+    Ok(reflected)
+}
+
 /// Given a collection `reflect_components` of reflected component values, insert them into
 /// the given entity (`entity_world_mut`).
 fn insert_reflected_components(
@@ -1505,6 +1763,30 @@ fn get_resource_type_registration<'r>(
         .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))
 }
 
+/// Given an event's type path, return the associated [`ReflectEvent`] from the given
+/// `type_registry` if possible.
+fn get_reflect_event<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r ReflectEvent> {
+    let event_registration = get_event_type_registration(type_registry, event_path)?;
+
+    event_registration
+        .data::<ReflectEvent>()
+        .ok_or_else(|| anyhow!("Event `{}` isn't reflectable", event_path))
+}
+
+/// Given an event's type path, return the associated [`TypeRegistration`] from the given
+/// `type_registry` if possible.
+fn get_event_type_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r TypeRegistration> {
+    type_registry
+        .get_with_type_path(event_path)
+        .ok_or_else(|| anyhow!("Unknown event type: `{}`", event_path))
+}
+
 #[cfg(test)]
 mod tests {
     /// A generic function that tests serialization and deserialization of any type
@@ -1527,6 +1809,8 @@ mod tests {
     }
 
     use super::*;
+    use bevy_ecs::event::{Event, Events};
+    use bevy_reflect::{Reflect, TypePath};
 
     #[test]
     fn serialization_tests() {
@@ -1549,4 +1833,154 @@ mod tests {
             entity: Entity::from_raw_u32(0).unwrap(),
         });
     }
+
+    #[derive(Event, Reflect)]
+    #[reflect(Event)]
+    struct Ping;
+
+    fn world_with_ping() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Ping>();
+        world
+    }
+
+    #[test]
+    fn event_errors() {
+        let mut world = world_with_ping();
+        let ping = Ping::type_path();
+
+        let mut send = |params| {
+            world
+                .run_system_cached_with(process_remote_send_event_request, params)
+                .unwrap()
+        };
+        assert_eq!(send(None).unwrap_err().code, error_codes::INVALID_PARAMS);
+        assert_eq!(
+            send(Some(serde_json::json!({ "value": null })))
+                .unwrap_err()
+                .code,
+            error_codes::INVALID_PARAMS
+        );
+        assert_eq!(
+            send(Some(serde_json::json!({ "event": "unknown::Event" })))
+                .unwrap_err()
+                .code,
+            error_codes::EVENT_ERROR
+        );
+        // `Events<Ping>` doesn't exist.
+        assert_eq!(
+            send(Some(serde_json::json!({ "event": ping, "value": {} })))
+                .unwrap_err()
+                .code,
+            error_codes::EVENT_ERROR
+        );
+
+        let mut trigger = |params| {
+            world
+                .run_system_cached_with(process_remote_trigger_event_request, Some(params))
+                .unwrap()
+        };
+        assert_eq!(
+            trigger(serde_json::json!({ "event": ping, "value": {}, "entity": 4294967298u64 }))
+                .unwrap_err()
+                .code,
+            error_codes::ENTITY_NOT_FOUND
+        );
+        assert!(trigger(serde_json::json!({ "event": ping, "value": {} })).is_ok());
+    }
+
+    #[test]
+    fn omit_values_of_events_without_fields() {
+        #[derive(Event, Reflect)]
+        #[reflect(Event)]
+        struct Empty();
+
+        let mut world = world_with_ping();
+        world.init_resource::<Events<Ping>>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Empty>();
+
+        let send = serde_json::json!({ "event": Ping::type_path() });
+        world
+            .run_system_cached_with(process_remote_send_event_request, Some(send))
+            .unwrap()
+            .unwrap();
+        assert_eq!(world.resource::<Events<Ping>>().len(), 1);
+
+        let trigger = serde_json::json!({ "event": Empty::type_path() });
+        world
+            .run_system_cached_with(process_remote_trigger_event_request, Some(trigger))
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn run_remote_systems() {
+        let mut world = world_with_ping();
+
+        let id = world.register_system(|| {});
+        world
+            .entity_mut(id.entity())
+            .insert((Name::new("nothing"), RemoteSystem::default()));
+        let id = world.register_system(|| 7_u32);
+        world
+            .entity_mut(id.entity())
+            .insert((Name::new("seven"), RemoteSystem::with_output::<u32>()));
+        let id = world.register_system(|| 7_u32);
+        world
+            .entity_mut(id.entity())
+            .insert((Name::new("wrong_output"), RemoteSystem::default()));
+        // Systems without a `RemoteSystem` can't be run by clients.
+        let id = world.register_system(|| {});
+        world.entity_mut(id.entity()).insert(Name::new("hidden"));
+        for _ in 0..2 {
+            let id = world.register_system(|| {});
+            world
+                .entity_mut(id.entity())
+                .insert((Name::new("twice"), RemoteSystem::default()));
+        }
+
+        let mut run = |params| {
+            world
+                .run_system_cached_with(process_remote_run_system_request, Some(params))
+                .unwrap()
+        };
+        assert_eq!(
+            run(serde_json::json!({ "name": "nothing" })).unwrap(),
+            serde_json::json!({ "value": null })
+        );
+        assert_eq!(
+            run(serde_json::json!({ "name": "seven" })).unwrap(),
+            serde_json::json!({ "value": 7 })
+        );
+        assert_eq!(
+            run(serde_json::json!({})).unwrap_err().code,
+            error_codes::INVALID_PARAMS
+        );
+        assert_eq!(
+            run(serde_json::json!({ "name": "hidden" }))
+                .unwrap_err()
+                .code,
+            error_codes::SYSTEM_NOT_FOUND
+        );
+        assert_eq!(
+            run(serde_json::json!({ "name": "twice" }))
+                .unwrap_err()
+                .code,
+            error_codes::SYSTEM_ERROR
+        );
+        let error = run(serde_json::json!({ "name": "wrong_output" })).unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_ERROR);
+        assert!(
+            error.message.contains("doesn't return a `()`"),
+            "{}",
+            error.message
+        );
+    }
 }
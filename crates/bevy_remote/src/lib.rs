@@ -3,9 +3,13 @@
 //! Adding the [`RemotePlugin`] to your [`App`] will setup everything needed without
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
-//! over HTTP. These *remote clients* can inspect and alter the state of the
+//! over HTTP, or the `RemoteWebSocketPlugin` (behind the `websocket` feature) to enable communication
+//! over a single WebSocket connection. These *remote clients* can inspect and alter the state of the
 //! entity-component system.
 //!
+//! By default, any client that can reach a transport can call every method. See the [`access`]
+//! module to require clients to authenticate, and to restrict what they are allowed to do.
+//!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
 //! ## Request objects
@@ -321,6 +325,40 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ### `bevy/send_event`
+//!
+//! Send an event to its `Events` resource, to be read by the systems of the app.
+//! The event type must be registered with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value`: The value of the event. This can be omitted for events without fields.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/trigger_event`
+//!
+//! Trigger an event, running the observers watching for it.
+//! The event type must be registered with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value`: The value of the event. This can be omitted for events without fields.
+//! - `entity` (optional): The ID of the entity targeted by the event.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/run_system`
+//!
+//! Run a one-shot system registered with `World::register_system`, and exposed to clients by
+//! adding a [`RemoteSystem`](builtin_methods::RemoteSystem) component to its entity.
+//!
+//! `params`:
+//! - `name`: The `Name` of the entity of the system.
+//!
+//! `result`:
+//! - `value`: The serialized output of the system, or null if it has no output.
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -364,6 +402,9 @@
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
 
+extern crate alloc;
+
+use access::RemoteAccessControl;
 use async_channel::{Receiver, Sender};
 use bevy_app::{prelude::*, MainScheduleOrder};
 use bevy_derive::{Deref, DerefMut};
@@ -380,10 +421,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::RwLock;
 
+pub mod access;
 pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod schemas;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -396,6 +440,8 @@ const CHANNEL_SIZE: usize = 16;
 pub struct RemotePlugin {
     /// The verbs that the server will recognize and respond to.
     methods: RwLock<Vec<(String, RemoteMethodHandler)>>,
+    /// Which clients are accepted, and what they are allowed to do.
+    access_control: RemoteAccessControl,
 }
 
 impl RemotePlugin {
@@ -404,6 +450,7 @@ impl RemotePlugin {
     fn empty() -> Self {
         Self {
             methods: RwLock::new(vec![]),
+            access_control: RemoteAccessControl::default(),
         }
     }
 
@@ -434,6 +481,16 @@ impl RemotePlugin {
         ));
         self
     }
+
+    /// Set the [`RemoteAccessControl`] deciding which clients are accepted, and what they are
+    /// allowed to do.
+    ///
+    /// By default, every client can call every method.
+    #[must_use]
+    pub fn with_access_control(mut self, access_control: RemoteAccessControl) -> Self {
+        self.access_control = access_control;
+        self
+    }
 }
 
 impl Default for RemotePlugin {
@@ -507,6 +564,18 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_RESOURCES_METHOD,
                 builtin_methods::process_remote_list_resources_request,
             )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_EVENT_METHOD,
+                builtin_methods::process_remote_trigger_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_RUN_SYSTEM_METHOD,
+                builtin_methods::process_remote_run_system_request,
+            )
             .with_method(
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
@@ -539,6 +608,7 @@ impl Plugin for RemotePlugin {
             .insert_after(Last, RemoteLast);
 
         app.insert_resource(remote_methods)
+            .insert_resource(self.access_control.clone())
             .init_resource::<RemoteWatchingRequests>()
             .add_systems(PreStartup, setup_mailbox_channel)
             .configure_sets(
@@ -736,6 +806,36 @@ impl BrpResponse {
     }
 }
 
+/// A notification sent by the server to a client, without a preceding request.
+///
+/// Notifications have no `id`, and clients must not respond to them. They can only be sent over
+/// transports keeping a connection open, like the
+/// `RemoteWebSocketPlugin`.
+#[derive(Debug, Serialize, Clone)]
+pub struct BrpNotification {
+    /// This field is mandatory and must be set to `"2.0"`.
+    pub jsonrpc: &'static str,
+
+    /// The name of the notification.
+    pub method: String,
+
+    /// The data of the notification, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl BrpNotification {
+    /// Generates a [`BrpNotification`] from a method name and its params.
+    #[must_use]
+    pub fn new(method: impl Into<String>, params: Option<Value>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            method: method.into(),
+            params,
+        }
+    }
+}
+
 /// A result/error payload present in every response.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -828,6 +928,66 @@ impl BrpError {
         }
     }
 
+    /// The client did not send a valid authentication token.
+    #[must_use]
+    pub fn unauthorized() -> Self {
+        Self {
+            code: error_codes::UNAUTHORIZED,
+            message: String::from("Missing or invalid authentication token"),
+            data: None,
+        }
+    }
+
+    /// The client is not allowed to call the method.
+    #[must_use]
+    pub fn method_not_allowed(method: &str) -> Self {
+        Self {
+            code: error_codes::METHOD_NOT_ALLOWED,
+            message: format!("Method `{method}` is not allowed for this client"),
+            data: None,
+        }
+    }
+
+    /// The client is not allowed to access the component.
+    #[must_use]
+    pub fn component_not_allowed(component: &str) -> Self {
+        Self {
+            code: error_codes::COMPONENT_NOT_ALLOWED,
+            message: format!("Component `{component}` is not allowed for this client"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// No system runnable by clients has the given name.
+    #[must_use]
+    pub fn system_not_found(name: &str) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("No remote system is named `{name}`"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary system error, such as the system failing to run.
+    #[must_use]
+    pub fn system_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::SYSTEM_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
     /// Attempt to reparent an entity to itself.
     #[must_use]
     pub fn self_reparent(entity: Entity) -> Self {
@@ -878,6 +1038,24 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// The client did not send a valid authentication token.
+    pub const UNAUTHORIZED: i16 = -23601;
+
+    /// The client is not allowed to call the method.
+    pub const METHOD_NOT_ALLOWED: i16 = -23602;
+
+    /// The client is not allowed to access the component.
+    pub const COMPONENT_NOT_ALLOWED: i16 = -23603;
+
+    /// Could not reflect, find or send event.
+    pub const EVENT_ERROR: i16 = -23701;
+
+    /// Could not find a remote system with the given name.
+    pub const SYSTEM_NOT_FOUND: i16 = -23801;
+
+    /// Could not run system, or serialize its output.
+    pub const SYSTEM_ERROR: i16 = -23802;
 }
 
 /// The result of a request.
@@ -906,6 +1084,11 @@ pub struct BrpMessage {
     /// The request params.
     pub params: Option<Value>,
 
+    /// The authentication token sent by the client, if any.
+    ///
+    /// This is checked against the [`RemoteAccessControl`] before the request is processed.
+    pub token: Option<String>,
+
     /// The channel on which the response is to be sent.
     ///
     /// The value sent here is serialized and sent back to the client.
@@ -941,6 +1124,17 @@ fn process_remote_requests(world: &mut World) {
     }
 
     while let Ok(message) = world.resource_mut::<BrpReceiver>().try_recv() {
+        // Check that the client is allowed to make this request before anything else, so that
+        // rejected clients can't even find out which methods exist.
+        if let Err(error) = world.resource::<RemoteAccessControl>().authorize(
+            message.token.as_deref(),
+            &message.method,
+            message.params.as_ref(),
+        ) {
+            let _ = message.sender.force_send(Err(error));
+            continue;
+        }
+
         // Fetch the handler for the method. If there's no such handler
         // registered, return an error.
         let Some(&handler) = world.resource::<RemoteMethods>().get(&message.method) else {
@@ -954,7 +1148,7 @@ fn process_remote_requests(world: &mut World) {
 
         match handler {
             RemoteMethodSystemId::Instant(id) => {
-                let result = match world.run_system_with(id, message.params) {
+                let mut result = match world.run_system_with(id, message.params) {
                     Ok(result) => result,
                     Err(error) => {
                         let _ = message.sender.force_send(Err(BrpError {
@@ -966,6 +1160,13 @@ fn process_remote_requests(world: &mut World) {
                     }
                 };
 
+                if let Ok(value) = &mut result {
+                    world.resource::<RemoteAccessControl>().filter_result(
+                        message.token.as_deref(),
+                        &message.method,
+                        value,
+                    );
+                }
                 let _ = message.sender.force_send(result);
             }
             RemoteMethodSystemId::Watching(id) => {
@@ -985,7 +1186,16 @@ fn process_ongoing_watching_requests(world: &mut World) {
         for (message, system_id) in requests.0.iter() {
             let handler_result = process_single_ongoing_watching_request(world, message, system_id);
             let sender_result = match handler_result {
-                Ok(Some(value)) => message.sender.try_send(Ok(value)),
+                Ok(Some(mut value)) => {
+                    if !world.resource::<RemoteAccessControl>().filter_result(
+                        message.token.as_deref(),
+                        &message.method,
+                        &mut value,
+                    ) {
+                        continue;
+                    }
+                    message.sender.try_send(Ok(value))
+                }
                 Err(err) => message.sender.try_send(Err(err)),
                 Ok(None) => continue,
             };
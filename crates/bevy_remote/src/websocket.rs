@@ -0,0 +1,825 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//!
+//! Unlike the HTTP transport, a single connection can carry any number of requests,
+//! whose responses are sent back as soon as they are ready, possibly out of order:
+//! clients should match responses to requests using their `id`.
+//!
+//! - Each text message sent by a client is a JSON-RPC request, or a batch of requests.
+//! - Watching requests, such as `bevy/get+watch`, are answered with a response carrying
+//!   the `id` of the request every time the watched data changes, until the client sends
+//!   a [`rpc.unwatch`](RPC_UNWATCH_METHOD) request or closes the connection.
+//! - The app can send notifications to every connected client with the
+//!   [`WebSocketNotifier`] resource.
+//!
+//! Clients that don't keep up with the messages sent to them, letting too many of them pile up
+//! or taking too long to receive one, are disconnected.
+//!
+//! Clients authenticate by sending their token in the `Authorization` header of the handshake
+//! request, as described in the [`access`](crate::access) module. Clients that aren't accepted by
+//! the [`RemoteAccessControl`] are refused with a `401 Unauthorized` response, and never receive
+//! notifications.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    access::{bearer_token, RemoteAccessControl},
+    error_codes, BrpBatch, BrpError, BrpMessage, BrpNotification, BrpRequest, BrpResponse,
+    BrpResult, BrpSender,
+};
+use alloc::sync::Arc;
+use anyhow::Result as AnyhowResult;
+use async_channel::{Receiver, Sender, TrySendError};
+use async_io::{Async, Timer};
+use async_tungstenite::{
+    tungstenite::{
+        handshake::server::{ErrorResponse, Request, Response},
+        http::StatusCode,
+        Message,
+    },
+    WebSocketSender, WebSocketStream,
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::{resource::Resource, system::Res};
+use bevy_tasks::{
+    futures_lite::{future, StreamExt},
+    IoTaskPool,
+};
+use core::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    ops::ControlFlow,
+    time::Duration,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    net::{TcpListener, TcpStream},
+    sync::{Mutex, PoisonError},
+};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is the port following the default HTTP port, so both transports can be used at once.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The method a client calls to stop a watching request.
+///
+/// This is handled by the WebSocket transport itself, and is not part of the [`RemoteMethods`].
+///
+/// `params`:
+/// - `id`: The `id` of the watching request to stop.
+///
+/// `result`: null.
+///
+/// [`RemoteMethods`]: crate::RemoteMethods
+pub const RPC_UNWATCH_METHOD: &str = "rpc.unwatch";
+
+/// The number of messages that can wait to be sent to a client before it is disconnected.
+const CLIENT_QUEUE_SIZE: usize = 64;
+
+/// How long sending a single message to a client can take before it is disconnected.
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and modify entities.
+/// It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHost(SocketAddr::new(self.address, self.port)))
+            .init_resource::<WebSocketNotifier>()
+            .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+/// A resource containing the address that the WebSocket server will listen on.
+#[derive(Debug, Resource)]
+struct WebSocketHost(SocketAddr);
+
+/// A resource used to send [notifications](BrpNotification) to every client connected
+/// through the [`RemoteWebSocketPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::system::Res;
+/// # use bevy_remote::websocket::WebSocketNotifier;
+/// # use serde_json::json;
+/// fn notify_level_loaded(notifier: Res<WebSocketNotifier>) {
+///     notifier.notify("my_game/level_loaded", Some(json!({ "level": 2 })));
+/// }
+/// ```
+#[derive(Debug, Resource, Clone, Default)]
+pub struct WebSocketNotifier {
+    clients: Arc<Mutex<Vec<Sender<String>>>>,
+}
+
+impl WebSocketNotifier {
+    /// Sends a notification with the given method and params to every connected client.
+    pub fn notify(&self, method: impl Into<String>, params: Option<Value>) {
+        let notification = BrpNotification::new(method, params);
+        let Ok(serialized) = serde_json::to_string(&notification) else {
+            return;
+        };
+
+        let mut clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        clients.retain(|client| queue_message(client, serialized.clone()));
+    }
+
+    /// Returns the number of connected clients.
+    pub fn client_count(&self) -> usize {
+        let mut clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        clients.retain(|client| !client.is_closed());
+        clients.len()
+    }
+
+    fn add_client(&self, client: Sender<String>) {
+        self.clients
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(client);
+    }
+}
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(
+    request_sender: Res<BrpSender>,
+    host: Res<WebSocketHost>,
+    notifier: Res<WebSocketNotifier>,
+    access_control: Res<RemoteAccessControl>,
+) {
+    IoTaskPool::get()
+        .spawn(server_main(
+            host.0,
+            request_sender.clone(),
+            notifier.clone(),
+            access_control.clone(),
+        ))
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: SocketAddr,
+    request_sender: Sender<BrpMessage>,
+    notifier: WebSocketNotifier,
+    access_control: RemoteAccessControl,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind(address)?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        let notifier = notifier.clone();
+        let access_control = access_control.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender, notifier, access_control).await;
+            })
+            .detach();
+    }
+}
+
+/// Performs the WebSocket handshake with a new client, refusing it if its token isn't accepted.
+///
+/// Returns the socket and the token sent by the client.
+#[expect(
+    clippy::result_large_err,
+    reason = "The handshake callback of `async_tungstenite` returns its `ErrorResponse` by value."
+)]
+async fn accept_client(
+    client: Async<TcpStream>,
+    access_control: &RemoteAccessControl,
+) -> AnyhowResult<(WebSocketStream<Async<TcpStream>>, Option<String>)> {
+    let mut token = None;
+    let socket =
+        async_tungstenite::accept_hdr_async(client, |request: &Request, response: Response| {
+            token = request
+                .headers()
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+                .and_then(bearer_token)
+                .map(ToOwned::to_owned);
+            match access_control.authenticate(token.as_deref()) {
+                Ok(()) => Ok(response),
+                Err(error) => {
+                    let mut response = ErrorResponse::new(Some(error.message));
+                    *response.status_mut() = StatusCode::UNAUTHORIZED;
+                    Err(response)
+                }
+            }
+        })
+        .await?;
+    Ok((socket, token))
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+    notifier: WebSocketNotifier,
+    access_control: RemoteAccessControl,
+) -> AnyhowResult<()> {
+    let (socket, token) = accept_client(client, &access_control).await?;
+    let (socket_sender, mut socket_receiver) = socket.split();
+
+    // Everything sent to the client goes through this channel, so that the responses
+    // to requests being processed concurrently and notifications can be interleaved.
+    let (message_sender, message_receiver) = async_channel::bounded(CLIENT_QUEUE_SIZE);
+    notifier.add_client(message_sender.clone());
+    let writer = IoTaskPool::get().spawn(write_messages(socket_sender, message_receiver));
+
+    let mut client = ClientConnection::new(request_sender, token, message_sender);
+    // Stop reading as soon as the writer stops, so that slow clients are disconnected.
+    let result = future::or(
+        async {
+            while let Some(message) = socket_receiver.next().await {
+                if client.process_message(message?).await.is_break() {
+                    break;
+                }
+            }
+            Ok(())
+        },
+        async {
+            writer.await;
+            Ok(())
+        },
+    )
+    .await;
+
+    client.close();
+    result
+}
+
+/// Sends the messages queued for a client through its socket, until the client disconnects
+/// or doesn't keep up with them.
+async fn write_messages(
+    mut socket_sender: WebSocketSender<Async<TcpStream>>,
+    message_receiver: Receiver<String>,
+) {
+    while let Ok(message) = message_receiver.recv().await {
+        // The channel is closed when the client can't keep up, in which case the remaining
+        // messages are dropped.
+        if message_receiver.is_closed() {
+            break;
+        }
+        let sent = future::or(
+            async { socket_sender.send(Message::text(message)).await.is_ok() },
+            async {
+                Timer::after(SEND_TIMEOUT).await;
+                false
+            },
+        )
+        .await;
+        if !sent {
+            break;
+        }
+    }
+}
+
+/// Queues a message to be sent to a client, returning `false` if the client is gone.
+///
+/// A client whose queue is full is too slow to keep up, so it is disconnected.
+fn queue_message(message_sender: &Sender<String>, message: String) -> bool {
+    match message_sender.try_send(message) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) => {
+            message_sender.close();
+            false
+        }
+        Err(TrySendError::Closed(_)) => false,
+    }
+}
+
+/// The state of a single WebSocket connection.
+struct ClientConnection {
+    request_sender: Sender<BrpMessage>,
+    /// The authentication token sent in the handshake request.
+    token: Option<String>,
+    /// The channel on which messages are sent to the client.
+    message_sender: Sender<String>,
+    /// The receivers of the ongoing watching requests, by serialized request `id`.
+    watching_requests: HashMap<String, Receiver<BrpResult>>,
+}
+
+impl ClientConnection {
+    fn new(
+        request_sender: Sender<BrpMessage>,
+        token: Option<String>,
+        message_sender: Sender<String>,
+    ) -> Self {
+        Self {
+            request_sender,
+            token,
+            message_sender,
+            watching_requests: HashMap::new(),
+        }
+    }
+
+    /// Processes a message sent by the client, breaking if the client closes the connection.
+    async fn process_message(&mut self, message: Message) -> ControlFlow<()> {
+        match message {
+            Message::Text(text) => self.process_request_batch(text.as_str()).await,
+            Message::Binary(_) => self.send(&BrpResponse::new(
+                None,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: String::from("Binary messages are not supported"),
+                    data: None,
+                }),
+            )),
+            Message::Close(_) => return ControlFlow::Break(()),
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Processes a text message sent by the client, containing one or several requests.
+    ///
+    /// This waits for the app to have room for the requests, so a client sending more requests
+    /// than the app can take in a frame isn't read from until it catches up.
+    async fn process_request_batch(&mut self, text: &str) {
+        match serde_json::from_str::<BrpBatch>(text) {
+            Ok(BrpBatch::Single(request)) => {
+                if let Some(response) = self.process_single_request(request).await {
+                    IoTaskPool::get()
+                        .spawn(send_response(response, self.message_sender.clone()))
+                        .detach();
+                }
+            }
+            Ok(BrpBatch::Batch(requests)) => {
+                let mut responses = Vec::with_capacity(requests.len());
+                for request in requests {
+                    responses.extend(self.process_single_request(request).await);
+                }
+                let message_sender = self.message_sender.clone();
+                IoTaskPool::get()
+                    .spawn(async move {
+                        let mut batch = Vec::with_capacity(responses.len());
+                        for response in responses {
+                            batch.push(response.await_response().await);
+                        }
+                        if let Ok(serialized) = serde_json::to_string(&batch) {
+                            queue_message(&message_sender, serialized);
+                        }
+                    })
+                    .detach();
+            }
+            Err(err) => self.send(&BrpResponse::new(
+                None,
+                Err(BrpError {
+                    code: error_codes::PARSE_ERROR,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            )),
+        }
+    }
+
+    /// Processes a single request.
+    ///
+    /// Returns the response to send for requests that have a single response, while watching
+    /// requests start sending their responses as they come.
+    async fn process_single_request(&mut self, request: Value) -> Option<PendingResponse> {
+        // Reach in and get the request ID early so that we can report it even when parsing fails.
+        let id = request.as_object().and_then(|map| map.get("id")).cloned();
+        let error = |code, message: &str| {
+            Some(PendingResponse::Ready(BrpResponse::new(
+                id.clone(),
+                Err(BrpError {
+                    code,
+                    message: message.to_string(),
+                    data: None,
+                }),
+            )))
+        };
+
+        let request: BrpRequest = match serde_json::from_value(request) {
+            Ok(v) => v,
+            Err(err) => return error(error_codes::INVALID_REQUEST, &err.to_string()),
+        };
+
+        if request.jsonrpc != "2.0" {
+            return error(
+                error_codes::INVALID_REQUEST,
+                "JSON-RPC request requires `\"jsonrpc\": \"2.0\"`",
+            );
+        }
+
+        if request.method == RPC_UNWATCH_METHOD {
+            return Some(PendingResponse::Ready(BrpResponse::new(
+                request.id,
+                self.unwatch(request.params),
+            )));
+        }
+
+        let watch = request.method.contains("+watch");
+        let watch_key = if watch {
+            let Some(id) = &request.id else {
+                return error(
+                    error_codes::INVALID_REQUEST,
+                    "Watching requests require an `id`",
+                );
+            };
+            let key = id.to_string();
+            if self
+                .watching_requests
+                .get(&key)
+                .is_some_and(|receiver| !receiver.is_closed())
+            {
+                return error(
+                    error_codes::INVALID_REQUEST,
+                    &format!("A watching request with the id {key} is already ongoing"),
+                );
+            }
+            Some(key)
+        } else {
+            None
+        };
+
+        let size = if watch { 8 } else { 1 };
+        let (result_sender, result_receiver) = async_channel::bounded(size);
+
+        if self
+            .request_sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                token: self.token.clone(),
+                sender: result_sender,
+            })
+            .await
+            .is_err()
+        {
+            return error(
+                error_codes::INTERNAL_ERROR,
+                "The app is no longer processing requests",
+            );
+        }
+
+        if let Some(key) = watch_key {
+            self.watching_requests.insert(key, result_receiver.clone());
+            IoTaskPool::get()
+                .spawn(forward_watching_responses(
+                    request.id,
+                    result_receiver,
+                    self.message_sender.clone(),
+                ))
+                .detach();
+            None
+        } else {
+            Some(PendingResponse::Waiting(request.id, result_receiver))
+        }
+    }
+
+    /// Stops the watching request with the `id` given in `params`.
+    fn unwatch(&mut self, params: Option<Value>) -> BrpResult {
+        #[derive(Deserialize)]
+        struct UnwatchParams {
+            id: Value,
+        }
+
+        let UnwatchParams { id } = params
+            .and_then(|params| serde_json::from_value(params).ok())
+            .ok_or_else(|| BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: String::from("Expected the `id` of a watching request"),
+                data: None,
+            })?;
+
+        match self.watching_requests.remove(&id.to_string()) {
+            Some(receiver) if !receiver.is_closed() => {
+                // Closing the channel makes the app stop running the watching request.
+                receiver.close();
+                Ok(Value::Null)
+            }
+            _ => Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("No watching request with the id {id} is ongoing"),
+                data: None,
+            }),
+        }
+    }
+
+    /// Sends a message to the client, without waiting for it to be sent.
+    fn send(&self, response: &BrpResponse) {
+        if let Ok(serialized) = serde_json::to_string(response) {
+            queue_message(&self.message_sender, serialized);
+        }
+    }
+
+    /// Stops all the ongoing watching requests of this connection.
+    fn close(self) {
+        for receiver in self.watching_requests.values() {
+            receiver.close();
+        }
+        self.message_sender.close();
+    }
+}
+
+/// The response to a request with a single response.
+enum PendingResponse {
+    /// The response is already known, usually because the request is invalid.
+    Ready(BrpResponse),
+    /// The response will be sent by the app on the channel.
+    Waiting(Option<Value>, Receiver<BrpResult>),
+}
+
+impl PendingResponse {
+    async fn await_response(self) -> BrpResponse {
+        match self {
+            PendingResponse::Ready(response) => response,
+            PendingResponse::Waiting(id, receiver) => {
+                let result = receiver.recv().await.unwrap_or_else(|_| {
+                    Err(BrpError::internal("The request was dropped by the app"))
+                });
+                BrpResponse::new(id, result)
+            }
+        }
+    }
+}
+
+/// Sends the response to a request with a single response once it is ready.
+async fn send_response(response: PendingResponse, message_sender: Sender<String>) {
+    if let Ok(serialized) = serde_json::to_string(&response.await_response().await) {
+        queue_message(&message_sender, serialized);
+    }
+}
+
+/// Sends each response to a watching request as it comes, until the request is stopped.
+async fn forward_watching_responses(
+    id: Option<Value>,
+    result_receiver: Receiver<BrpResult>,
+    message_sender: Sender<String>,
+) {
+    while let Ok(result) = result_receiver.recv().await {
+        let Ok(serialized) = serde_json::to_string(&BrpResponse::new(id.clone(), result)) else {
+            continue;
+        };
+        if !queue_message(&message_sender, serialized) {
+            // The client is gone, so stop watching.
+            result_receiver.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_tungstenite::tungstenite::{
+        client::IntoClientRequest, http::header::AUTHORIZATION, Error,
+    };
+    use bevy_tasks::{
+        block_on, tick_global_task_pools_on_main_thread, AsyncComputeTaskPool, ComputeTaskPool,
+        TaskPool,
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::access::RemotePermissions;
+
+    /// Runs the handshake of a client sending `token`, returning whether the server accepted it
+    /// and the status of the response the client got.
+    fn handshake(access_control: &RemoteAccessControl, token: Option<&str>) -> (bool, StatusCode) {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let mut request = format!("ws://{address}").into_client_request().unwrap();
+        if let Some(token) = token {
+            let header = format!("Bearer {token}").parse().unwrap();
+            request.headers_mut().insert(AUTHORIZATION, header);
+        }
+
+        block_on(future::zip(
+            async {
+                let (client, _) = listener.accept().await.unwrap();
+                accept_client(client, access_control).await.is_ok()
+            },
+            async {
+                let stream = Async::<TcpStream>::connect(address).await.unwrap();
+                match async_tungstenite::client_async(request, stream).await {
+                    Ok((_, response)) => response.status(),
+                    Err(Error::Http(response)) => response.status(),
+                    Err(error) => panic!("unexpected handshake error: {error}"),
+                }
+            },
+        ))
+    }
+
+    #[test]
+    fn refuse_unauthenticated_handshake() {
+        let access_control =
+            RemoteAccessControl::authenticated().with_token("secret", RemotePermissions::default());
+
+        assert_eq!(
+            handshake(&access_control, Some("secret")),
+            (true, StatusCode::SWITCHING_PROTOCOLS)
+        );
+        assert_eq!(
+            handshake(&access_control, None),
+            (false, StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            handshake(&access_control, Some("guess")),
+            (false, StatusCode::UNAUTHORIZED)
+        );
+
+        // Changes made through a clone, like the resource, apply to new connections.
+        access_control.clone().remove_token("secret");
+        assert_eq!(
+            handshake(&access_control, Some("secret")),
+            (false, StatusCode::UNAUTHORIZED)
+        );
+    }
+
+    /// Creates a connection, along with the channels on which the app receives its requests
+    /// and the client receives its messages.
+    fn connection() -> (ClientConnection, Receiver<BrpMessage>, Receiver<String>) {
+        ComputeTaskPool::get_or_init(TaskPool::new);
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        IoTaskPool::get_or_init(TaskPool::new);
+
+        let (request_sender, request_receiver) = async_channel::bounded(16);
+        let (message_sender, message_receiver) = async_channel::bounded(CLIENT_QUEUE_SIZE);
+        let connection = ClientConnection::new(request_sender, None, message_sender);
+        (connection, request_receiver, message_receiver)
+    }
+
+    /// Waits for the next message sent to the client.
+    fn next_message(messages: &Receiver<String>) -> Value {
+        for _ in 0..1000 {
+            tick_global_task_pools_on_main_thread();
+            if let Ok(message) = messages.try_recv() {
+                return serde_json::from_str(&message).unwrap();
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("no message was sent to the client");
+    }
+
+    #[test]
+    fn process_request_batches() {
+        let (mut connection, requests, messages) = connection();
+
+        block_on(connection.process_request_batch(
+            r#"[
+                { "jsonrpc": "2.0", "id": 1, "method": "bevy/list" },
+                { "jsonrpc": "1.0", "id": 2, "method": "bevy/list" }
+            ]"#,
+        ));
+        let request = requests.try_recv().unwrap();
+        assert_eq!(request.method, "bevy/list");
+        assert!(requests.try_recv().is_err());
+        request.sender.force_send(Ok(json!(["a"]))).unwrap();
+
+        let batch = next_message(&messages);
+        assert_eq!(batch[0]["id"], 1);
+        assert_eq!(batch[0]["result"], json!(["a"]));
+        assert_eq!(batch[1]["id"], 2);
+        assert_eq!(batch[1]["error"]["code"], error_codes::INVALID_REQUEST);
+
+        block_on(connection.process_request_batch("{"));
+        let response = next_message(&messages);
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], error_codes::PARSE_ERROR);
+    }
+
+    #[test]
+    fn process_batches_larger_than_the_app_queue() {
+        let (mut connection, requests, messages) = connection();
+        let batch = (0..40)
+            .map(|id| json!({ "jsonrpc": "2.0", "id": id, "method": "bevy/list" }))
+            .collect::<Vec<_>>();
+
+        // The requests that don't fit in the queue wait for the app to take the previous ones.
+        block_on(future::zip(
+            connection.process_request_batch(&serde_json::to_string(&batch).unwrap()),
+            async {
+                for _ in 0..batch.len() {
+                    let request = requests.recv().await.unwrap();
+                    request.sender.force_send(Ok(json!([]))).unwrap();
+                }
+            },
+        ));
+
+        let batch = next_message(&messages);
+        let responses = batch.as_array().unwrap();
+        assert_eq!(responses.len(), 40);
+        assert!(responses
+            .iter()
+            .all(|response| response["result"] == json!([])));
+    }
+
+    #[test]
+    fn watch_and_unwatch() {
+        let (mut connection, requests, messages) = connection();
+        let watch = r#"{ "jsonrpc": "2.0", "id": "w", "method": "bevy/get+watch" }"#;
+
+        block_on(
+            connection.process_request_batch(r#"{ "jsonrpc": "2.0", "method": "bevy/get+watch" }"#),
+        );
+        assert_eq!(
+            next_message(&messages)["error"]["code"],
+            error_codes::INVALID_REQUEST
+        );
+
+        block_on(connection.process_request_batch(watch));
+        let request = requests.try_recv().unwrap();
+        for value in 0..2 {
+            request.sender.force_send(Ok(json!(value))).unwrap();
+            let response = next_message(&messages);
+            assert_eq!(response["id"], "w");
+            assert_eq!(response["result"], value);
+        }
+
+        // The same `id` can't be watched twice at once.
+        block_on(connection.process_request_batch(watch));
+        assert_eq!(
+            next_message(&messages)["error"]["code"],
+            error_codes::INVALID_REQUEST
+        );
+
+        let unwatch =
+            r#"{ "jsonrpc": "2.0", "id": 3, "method": "rpc.unwatch", "params": { "id": "w" } }"#;
+        block_on(connection.process_request_batch(unwatch));
+        let response = next_message(&messages);
+        assert_eq!(response["id"], 3);
+        assert_eq!(response["result"], Value::Null);
+        assert!(request.sender.is_closed());
+
+        block_on(connection.process_request_batch(unwatch));
+        assert_eq!(
+            next_message(&messages)["error"]["code"],
+            error_codes::INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn reject_binary_messages() {
+        let (mut connection, requests, messages) = connection();
+
+        let flow = block_on(connection.process_message(Message::binary(vec![0, 1])));
+        assert!(flow.is_continue());
+        assert!(requests.try_recv().is_err());
+        let response = next_message(&messages);
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], error_codes::INVALID_REQUEST);
+
+        assert!(block_on(connection.process_message(Message::Close(None))).is_break());
+    }
+
+    #[test]
+    fn disconnect_slow_clients() {
+        let notifier = WebSocketNotifier::default();
+        let (message_sender, message_receiver) = async_channel::bounded(CLIENT_QUEUE_SIZE);
+        notifier.add_client(message_sender);
+
+        for _ in 0..CLIENT_QUEUE_SIZE {
+            notifier.notify("test/notification", None);
+        }
+        assert_eq!(notifier.client_count(), 1);
+
+        notifier.notify("test/notification", None);
+        assert!(message_receiver.is_closed());
+        assert_eq!(notifier.client_count(), 0);
+    }
+}
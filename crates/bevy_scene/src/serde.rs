@@ -726,6 +726,74 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_migrate_outdated_components() {
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component, version = 2)]
+        struct Stamina {
+            #[reflect(alias = "energy")]
+            amount: u32,
+            regen_per_second: u32,
+        }
+
+        let world = create_world();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Stamina>();
+            registry.register_migration::<Stamina>(0, |_| {
+                Err("the data is already at version 1".into())
+            });
+            registry.register_migration::<Stamina>(1, |stamina| {
+                let regen = stamina
+                    .remove("regen_per_minute")
+                    .ok_or("missing `regen_per_minute`")?;
+                let regen = *regen
+                    .try_downcast_ref::<u64>()
+                    .ok_or("`regen_per_minute` is not an integer")?;
+                stamina.insert("regen_per_second", (regen / 60) as u32);
+                Ok(())
+            });
+        }
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    8589934591: (
+      components: {
+        "bevy_scene::serde::tests::Stamina": (
+          __version: 1,
+          energy: 30,
+          regen_per_minute: 120,
+        ),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut dst_world = create_world();
+        dst_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Stamina>();
+        scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let stamina = dst_world.query::<&Stamina>().single(&dst_world).unwrap();
+        assert_eq!(
+            &Stamina {
+                amount: 30,
+                regen_per_second: 2,
+            },
+            stamina
+        );
+    }
+
     fn roundtrip_ron(world: &World) -> (DynamicScene, DynamicScene) {
         let scene = DynamicScene::from_world(world);
         let registry = world.resource::<AppTypeRegistry>().read();